@@ -0,0 +1,15 @@
+//! Field and scalar arithmetic shared by brainpoolP256r1 and brainpoolP256t1.
+//!
+//! The twisted curve brainpoolP256t1 is isomorphic to brainpoolP256r1, so both
+//! curves are defined over the same base field and have the same order.
+//!
+//! Curve parameters can be found in [RFC 5639 § 3.4](https://datatracker.ietf.org/doc/html/rfc5639#section-3.4).
+
+pub(crate) mod field;
+pub(crate) mod scalar;
+
+/// Scalar blinded with a randomly generated masking value.
+pub type BlindedScalar = weierstrass::BlindedScalar<scalar::Scalar>;
+
+/// Serialized field element: identical for both curves.
+type FieldBytes = crate::r1::FieldBytes;
@@ -0,0 +1,204 @@
+//! Field arithmetic modulo
+//! p = 0xa9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377
+
+use super::FieldBytes;
+use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use elliptic_curve::{
+    bigint::{Word, U256},
+    ff::PrimeField,
+    subtle::{Choice, ConstantTimeEq, CtOption},
+};
+use weierstrass::montgomery;
+
+/// Constant representing the modulus
+/// p = 0xa9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377
+pub(crate) const MODULUS: U256 =
+    U256::from_be_hex("a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377");
+
+/// R^2 = 2^512 mod p
+const R_2: U256 =
+    U256::from_be_hex("4717aa21e5957fa8a1ecdacd6b1ac8075cce4c26614d4f4d8cfedf7ba6465b6c");
+
+/// -p^{-1} mod 2^w, where w is the limb size in bits.
+const P_INV: Word = montgomery::neg_inv(MODULUS.as_words());
+
+/// Raw field element.
+type Fe = [Word; U256::LIMBS];
+
+/// An element in the finite field modulo
+/// p = 0xa9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377.
+///
+/// The internal representation is in little-endian order. Elements are always in
+/// Montgomery form; i.e., FieldElement(a) = aR mod p, with R = 2^256.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldElement(pub(crate) U256);
+
+weierstrass::impl_field_element!(
+    FieldElement,
+    FieldBytes,
+    U256,
+    MODULUS,
+    Fe,
+    fe_from_montgomery,
+    fe_to_montgomery,
+    fe_add,
+    fe_sub,
+    fe_mul,
+    fe_neg,
+    fe_square,
+    invert,
+    sqrt
+);
+
+impl FieldElement {
+    /// Parse the given byte array as an SEC1-encoded field element.
+    ///
+    /// Returns `None` if the byte array does not contain a big-endian integer in
+    /// the range `[0, p)`.
+    pub fn from_sec1(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Returns the SEC1 encoding of this field element.
+    pub fn to_sec1(self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self::from_uint_unchecked(U256::from(n))
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const NUM_BITS: u32 = 256;
+    const CAPACITY: u32 = 255;
+    const S: u32 = 1;
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn multiplicative_generator() -> Self {
+        11.into()
+    }
+
+    fn root_of_unity() -> Self {
+        Self::from_be_hex("a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5376")
+    }
+}
+
+const fn fe_from_montgomery(w: &Fe) -> Fe {
+    montgomery::from_montgomery(w, MODULUS.as_words(), P_INV)
+}
+
+const fn fe_to_montgomery(w: &Fe) -> Fe {
+    montgomery::to_montgomery(w, R_2.as_words(), MODULUS.as_words(), P_INV)
+}
+
+const fn fe_add(a: &Fe, b: &Fe) -> Fe {
+    montgomery::add(a, b, MODULUS.as_words())
+}
+
+const fn fe_sub(a: &Fe, b: &Fe) -> Fe {
+    montgomery::sub(a, b, MODULUS.as_words())
+}
+
+const fn fe_mul(a: &Fe, b: &Fe) -> Fe {
+    montgomery::mul(a, b, MODULUS.as_words(), P_INV)
+}
+
+const fn fe_neg(w: &Fe) -> Fe {
+    montgomery::neg(w, MODULUS.as_words())
+}
+
+const fn fe_square(w: &Fe) -> Fe {
+    montgomery::square(w, MODULUS.as_words(), P_INV)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, MODULUS};
+    use elliptic_curve::{bigint::ArrayEncoding, ff::PrimeField};
+
+    #[test]
+    fn from_to_bytes_roundtrip() {
+        let mut bytes = super::FieldBytes::default();
+        bytes[24..].copy_from_slice(&0x0123_4567_89ab_cdefu64.to_be_bytes());
+
+        let fe = FieldElement::from_repr(bytes).unwrap();
+        assert_eq!(bytes, fe.to_repr());
+        assert_eq!(fe, FieldElement::from(0x0123_4567_89ab_cdef));
+    }
+
+    #[test]
+    fn overflow_rejected() {
+        assert!(bool::from(
+            FieldElement::from_repr(MODULUS.to_be_byte_array()).is_none()
+        ));
+    }
+
+    /// Basic tests that multiplication works.
+    #[test]
+    fn multiply() {
+        let one = FieldElement::ONE;
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
+
+        let minus_two = -two;
+        let minus_three = -three;
+        assert_eq!(two, -minus_two);
+        assert_eq!(six, minus_two * minus_three);
+        assert_eq!(minus_two + two, FieldElement::ZERO);
+    }
+
+    /// Basic tests that field inversion works.
+    #[test]
+    fn invert() {
+        let one = FieldElement::ONE;
+        assert_eq!(one.invert().unwrap(), one);
+
+        let three = one + one + one;
+        let inv_three = three.invert().unwrap();
+        assert_eq!(three * inv_three, one);
+
+        let minus_three = -three;
+        let inv_minus_three = minus_three.invert().unwrap();
+        assert_eq!(inv_minus_three, -inv_three);
+        assert_eq!(three * inv_minus_three, -one);
+
+        assert!(bool::from(FieldElement::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn sqrt() {
+        let one = FieldElement::ONE;
+        let two = one + one;
+        let four = two.square();
+        let sqrt = four.sqrt().unwrap();
+        assert!(sqrt == two || sqrt == -two);
+    }
+
+    #[test]
+    fn root_of_unity() {
+        // With S = 1 the root of unity is -1, which requires the generator to
+        // be a quadratic non-residue.
+        assert_eq!(FieldElement::root_of_unity(), -FieldElement::ONE);
+        assert!(bool::from(
+            FieldElement::multiplicative_generator().sqrt().is_none()
+        ));
+    }
+}
@@ -0,0 +1,340 @@
+//! Scalar field elements shared by brainpoolP256r1 and brainpoolP256t1.
+
+use super::FieldBytes;
+use crate::{BrainpoolP256r1, BrainpoolP256t1};
+use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use elliptic_curve::{
+    bigint::{Limb, Word, U256},
+    ff::PrimeField,
+    ops::Reduce,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
+    Curve as _, Error, IsHigh, Result, ScalarCore,
+};
+use weierstrass::montgomery;
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+#[cfg(doc)]
+use core::ops::{Add, Mul, Sub};
+
+/// Order of the brainpoolP256r1 and brainpoolP256t1 groups.
+const ORDER: U256 = BrainpoolP256r1::ORDER;
+
+/// R^2 = 2^512 mod n
+const R_2: U256 =
+    U256::from_be_hex("0b25f1b9c32367629b7f25e76c815cb0f35d176a1134e4a0e1d8d8de3312fca6");
+
+/// -n^{-1} mod 2^w, where w is the limb size in bits.
+const N_INV: Word = montgomery::neg_inv(ORDER.as_words());
+
+/// Raw scalar.
+type Sc = [Word; U256::LIMBS];
+
+/// Scalars are elements in the finite field modulo `n`.
+///
+/// The same type is used for both brainpoolP256r1 and brainpoolP256t1, which
+/// have the same group order.
+///
+/// # Trait impls
+///
+/// Much of the important functionality of scalars is provided by traits from
+/// the [`ff`](https://docs.rs/ff/) crate, which is re-exported as
+/// `bp256::elliptic_curve::ff`:
+///
+/// - [`Field`](https://docs.rs/ff/latest/ff/trait.Field.html) -
+///   represents elements of finite fields and provides:
+///   - [`Field::random`](https://docs.rs/ff/latest/ff/trait.Field.html#tymethod.random) -
+///     generate a random scalar
+///   - `double`, `square`, and `invert` operations
+///   - Bounds for [`Add`], [`Sub`], [`Mul`], and [`Neg`] (as well as `*Assign` equivalents)
+///   - Bounds for [`ConditionallySelectable`] from the `subtle` crate
+/// - [`PrimeField`](https://docs.rs/ff/latest/ff/trait.PrimeField.html) -
+///   represents elements of prime fields and provides:
+///   - `from_repr`/`to_repr` for converting field elements from/to big integers.
+///   - `multiplicative_generator` and `root_of_unity` constants.
+///
+/// Please see the documentation for the relevant traits for more information.
+///
+/// # `serde` support
+///
+/// When the `serde` feature of this crate is enabled, the `Serialize` and
+/// `Deserialize` traits are impl'd for this type.
+///
+/// The serialization is a fixed-width big endian encoding. When used with
+/// textual formats, the binary data is encoded as hexadecimal.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub struct Scalar(U256);
+
+weierstrass::impl_field_element!(
+    Scalar,
+    FieldBytes,
+    U256,
+    ORDER,
+    Sc,
+    sc_from_montgomery,
+    sc_to_montgomery,
+    sc_add,
+    sc_sub,
+    sc_mul,
+    sc_neg,
+    sc_square,
+    invert,
+    sqrt
+);
+
+impl Scalar {
+    /// `2^s` root of unity.
+    pub const ROOT_OF_UNITY: Self =
+        Self::from_be_hex("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a6");
+}
+
+impl IsHigh for Scalar {
+    fn is_high(&self) -> Choice {
+        const MODULUS_SHR1: U256 = ORDER.shr_vartime(1);
+        self.to_canonical().ct_gt(&MODULUS_SHR1)
+    }
+}
+
+impl PrimeField for Scalar {
+    type Repr = FieldBytes;
+
+    const CAPACITY: u32 = 255;
+    const NUM_BITS: u32 = 256;
+    const S: u32 = 1;
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn multiplicative_generator() -> Self {
+        3u64.into()
+    }
+
+    fn root_of_unity() -> Self {
+        Self::ROOT_OF_UNITY
+    }
+}
+
+impl Reduce<U256> for Scalar {
+    fn from_uint_reduced(w: U256) -> Self {
+        let (r, underflow) = w.sbb(&ORDER, Limb::ZERO);
+        let underflow = Choice::from((underflow.0 >> (Limb::BIT_SIZE - 1)) as u8);
+        Self::from_uint_unchecked(U256::conditional_select(&w, &r, !underflow))
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Scalar {
+        Self::from_uint_unchecked(U256::from(n))
+    }
+}
+
+impl From<Scalar> for FieldBytes {
+    fn from(scalar: Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl From<&Scalar> for FieldBytes {
+    fn from(scalar: &Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl From<Scalar> for U256 {
+    fn from(scalar: Scalar) -> U256 {
+        U256::from(&scalar)
+    }
+}
+
+impl From<&Scalar> for U256 {
+    fn from(scalar: &Scalar) -> U256 {
+        scalar.to_canonical()
+    }
+}
+
+impl TryFrom<U256> for Scalar {
+    type Error = Error;
+
+    fn try_from(w: U256) -> Result<Self> {
+        Option::from(Self::from_uint(w)).ok_or(Error)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        ScalarCore::<BrainpoolP256r1>::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Ok(ScalarCore::<BrainpoolP256r1>::deserialize(deserializer)?.into())
+    }
+}
+
+/// Impl conversions between [`Scalar`] and the given curve's [`ScalarCore`]
+/// and `SecretKey` types.
+macro_rules! impl_curve_conversions {
+    ($curve:ident, $secret_key:ty) => {
+        impl From<ScalarCore<$curve>> for Scalar {
+            fn from(w: ScalarCore<$curve>) -> Self {
+                Scalar::from(&w)
+            }
+        }
+
+        impl From<&ScalarCore<$curve>> for Scalar {
+            fn from(w: &ScalarCore<$curve>) -> Scalar {
+                Scalar::from_uint_unchecked(*w.as_uint())
+            }
+        }
+
+        impl From<Scalar> for ScalarCore<$curve> {
+            fn from(scalar: Scalar) -> ScalarCore<$curve> {
+                ScalarCore::from(&scalar)
+            }
+        }
+
+        impl From<&Scalar> for ScalarCore<$curve> {
+            fn from(scalar: &Scalar) -> ScalarCore<$curve> {
+                ScalarCore::new(scalar.into()).unwrap()
+            }
+        }
+
+        impl From<&$secret_key> for Scalar {
+            fn from(secret_key: &$secret_key) -> Scalar {
+                *secret_key.to_nonzero_scalar()
+            }
+        }
+    };
+}
+
+impl_curve_conversions!(BrainpoolP256r1, crate::r1::SecretKey);
+impl_curve_conversions!(BrainpoolP256t1, crate::t1::SecretKey);
+
+const fn sc_from_montgomery(w: &Sc) -> Sc {
+    montgomery::from_montgomery(w, ORDER.as_words(), N_INV)
+}
+
+const fn sc_to_montgomery(w: &Sc) -> Sc {
+    montgomery::to_montgomery(w, R_2.as_words(), ORDER.as_words(), N_INV)
+}
+
+const fn sc_add(a: &Sc, b: &Sc) -> Sc {
+    montgomery::add(a, b, ORDER.as_words())
+}
+
+const fn sc_sub(a: &Sc, b: &Sc) -> Sc {
+    montgomery::sub(a, b, ORDER.as_words())
+}
+
+const fn sc_mul(a: &Sc, b: &Sc) -> Sc {
+    montgomery::mul(a, b, ORDER.as_words(), N_INV)
+}
+
+const fn sc_neg(w: &Sc) -> Sc {
+    montgomery::neg(w, ORDER.as_words())
+}
+
+const fn sc_square(w: &Sc) -> Sc {
+    montgomery::square(w, ORDER.as_words(), N_INV)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scalar, ORDER};
+    use elliptic_curve::{
+        bigint::U256,
+        ff::{Field, PrimeField},
+        ops::Reduce,
+        IsHigh,
+    };
+
+    #[test]
+    fn from_to_bytes_roundtrip() {
+        let k: u64 = 42;
+        let mut bytes = super::FieldBytes::default();
+        bytes[24..].copy_from_slice(k.to_be_bytes().as_ref());
+
+        let scalar = Scalar::from_repr(bytes).unwrap();
+        assert_eq!(bytes, scalar.to_be_bytes());
+        assert_eq!(scalar, Scalar::from(k));
+    }
+
+    /// Basic tests that multiplication works.
+    #[test]
+    fn multiply() {
+        let one = Scalar::one();
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
+
+        let minus_two = -two;
+        let minus_three = -three;
+        assert_eq!(two, -minus_two);
+
+        assert_eq!(minus_three * minus_two, minus_two * minus_three);
+        assert_eq!(six, minus_two * minus_three);
+    }
+
+    /// Basic tests that scalar inversion works.
+    #[test]
+    fn invert() {
+        let one = Scalar::one();
+        let three = one + one + one;
+        let inv_three = three.invert().unwrap();
+        assert_eq!(three * inv_three, one);
+
+        let minus_three = -three;
+        let inv_minus_three = minus_three.invert().unwrap();
+        assert_eq!(inv_minus_three, -inv_three);
+        assert_eq!(three * inv_minus_three, -one);
+    }
+
+    /// Basic tests that sqrt works.
+    #[test]
+    fn sqrt() {
+        for &n in &[1u64, 4, 9, 16, 25, 36, 49, 64] {
+            let scalar = Scalar::from(n);
+            let sqrt = scalar.sqrt().unwrap();
+            assert_eq!(sqrt.square(), scalar);
+        }
+    }
+
+    #[test]
+    fn reduce() {
+        assert_eq!(Scalar::from_uint_reduced(ORDER), Scalar::zero());
+        assert_eq!(
+            Scalar::from_uint_reduced(ORDER.wrapping_add(&U256::ONE)),
+            Scalar::one()
+        );
+        assert_eq!(Scalar::from_uint_reduced(U256::from(5u64)), Scalar::from(5));
+    }
+
+    #[test]
+    fn is_high() {
+        assert!(!bool::from(Scalar::one().is_high()));
+        assert!(bool::from((-Scalar::one()).is_high()));
+    }
+}
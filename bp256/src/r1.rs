@@ -1,9 +1,19 @@
 //! brainpoolP256r1 elliptic curve: verifiably pseudo-random variant
 
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
 pub mod ecdsa;
 
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "arithmetic")]
+pub use self::arithmetic::{AffinePoint, ProjectivePoint};
+
 use elliptic_curve::bigint::U256;
 
 #[cfg(feature = "pkcs8")]
@@ -25,9 +35,21 @@ impl elliptic_curve::Curve for BrainpoolP256r1 {
 impl elliptic_curve::PrimeCurve for BrainpoolP256r1 {}
 
 impl elliptic_curve::PointCompression for BrainpoolP256r1 {
+    /// Brainpool points are typically uncompressed.
     const COMPRESS_POINTS: bool = false;
 }
 
+impl elliptic_curve::PointCompaction for BrainpoolP256r1 {
+    /// Brainpool points are typically uncompressed.
+    const COMPACT_POINTS: bool = false;
+}
+
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+impl elliptic_curve::JwkParameters for BrainpoolP256r1 {
+    const CRV: &'static str = "BP-256";
+}
+
 #[cfg(feature = "pkcs8")]
 impl pkcs8::AssociatedOid for BrainpoolP256r1 {
     const OID: pkcs8::ObjectIdentifier =
@@ -42,7 +64,16 @@ pub type FieldBytes = elliptic_curve::FieldBytes<BrainpoolP256r1>;
 /// brainpoolP256r1 SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<BrainpoolP256r1>;
 
+/// Non-zero brainpoolP256r1 scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP256r1>;
+
+/// brainpoolP256r1 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP256r1>;
+
 /// brainpoolP256r1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP256r1>;
 
+#[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for BrainpoolP256r1 {}
@@ -0,0 +1,67 @@
+//! brainpoolP256r1 curve arithmetic.
+
+use super::BrainpoolP256r1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{
+    AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
+};
+use weierstrass::WeierstrassCurve;
+
+/// brainpoolP256r1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP256r1>;
+
+/// brainpoolP256r1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP256r1>;
+
+impl WeierstrassCurve for BrainpoolP256r1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = 7d5a0975 fc2c3057 eef67530 417affe7 fb8055c1 26dc5c6c e94a4b44 f330b5d9
+    const EQUATION_A: FieldElement = FieldElement::from_be_hex(
+        "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+    );
+
+    /// b = 26dc5c6c e94a4b44 f330b5d9 bbd77cbf 95841629 5cf7e1ce 6bccdc18 ff8c07b6
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+    );
+
+    /// Base point of brainpoolP256r1.
+    ///
+    /// Defined in RFC 5639 § 3.4:
+    ///
+    /// ```text
+    /// Gₓ = 8bd2aeb9 cb7e57cb 2c4b482f fc81b7af b9de27e1 e3bd23c2 3a4453bd 9ace3262
+    /// Gᵧ = 547ef835 c3dac4fd 97f8461a 14611dc9 c2774513 2ded8e54 5c1d54c7 2f046997
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+        ),
+        FieldElement::from_be_hex(
+            "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP256r1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP256r1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl PrimeCurveArithmetic for BrainpoolP256r1 {
+    type CurveGroup = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP256r1 {
+    type Scalar = Scalar;
+}
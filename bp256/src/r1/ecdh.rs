@@ -0,0 +1,119 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP256r1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp256::r1::{EncodedPoint, PublicKey, ecdh::EphemeralSecret};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret from it
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP256r1;
+
+/// brainpoolP256r1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP256r1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP256r1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::r1::{PublicKey, SecretKey};
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Test vector from RFC 7027 Appendix A.1
+    #[test]
+    fn rfc7027() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!(
+            "81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"
+        ))
+        .unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!(
+            "55e40bc41e37e3e2ad25c3c6654511ffa8474a91a0032087593852d3e7d76bd3"
+        ))
+        .unwrap();
+        let alice_public = PublicKey::from_sec1_bytes(&hex!("0444106e913f92bc02a1705d9953a8414db95e1aaa49e81d9e85f929a8e3100be58ab4846f11caccb73ce49cbdd120f5a900a69fd32c272223f789ef10eb089bdc")).unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!("048d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7b990c57520812be512641e47034832106bc7d3e8dd0e4c7f1136d7006547cec6a")).unwrap();
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected = hex!("89afc39d41d3b327814b80940b042590f96556ec91e6ae7939bce31f3a18bf2b");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+
+    #[test]
+    fn reject_off_curve_point() {
+        let public_key = EphemeralSecret::random(&mut OsRng).public_key();
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(public_key.to_encoded_point(false).as_bytes());
+
+        // Flip the low bit of the y-coordinate, moving the point off the curve
+        bytes[64] ^= 1;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+    }
+}
@@ -1,6 +1,33 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP256r1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP256r1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha256` feature.
 
 pub use super::BrainpoolP256r1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U256},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
 
 /// ECDSA/brainpoolP256r1 signature (fixed-size)
 pub type Signature = ecdsa::Signature<BrainpoolP256r1>;
@@ -8,8 +35,200 @@ pub type Signature = ecdsa::Signature<BrainpoolP256r1>;
 /// ECDSA/brainpoolP256r1 signature (ASN.1 DER encoded)
 pub type DerSignature = ecdsa::der::Signature<BrainpoolP256r1>;
 
+/// ECDSA/brainpoolP256r1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP256r1>;
+
+/// ECDSA/brainpoolP256r1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP256r1>;
+
 #[cfg(feature = "sha256")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
 impl ecdsa::hazmat::DigestPrimitive for BrainpoolP256r1 {
     type Digest = sha2::Sha256;
 }
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP256r1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP256r1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP256r1>>,
+        U256: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP256r1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP256r1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP256r1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha256"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP256r1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-256 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "sample" as its
+    /// SHA-256 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("9576a01b97233a96cecd6e6f88b3ac064e5bb6460d76112b40a180af6bc0b0c9");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "20c762f72691f2d3a67cc0ed18a8ea251047a7d139748c8ad67bffd95859c248
+                a5aa2d3909449f2429473746b348a00d55acfa981617aa4da165707aecf62f67"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "a896034dff59f4358c7f36d57171643888031808a1ecea14f070318221fb09d4
+                28106b1a59793f14f2c0d1a2150e5f7dd86953973dacd9408fefa4dde9d99029"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!(
+            "9576a01b97233a96cecd6e6f88b3ac064e5bb6460d76112b40a180af6bc0b0c9"
+        ))
+        .unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP256r1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-256 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("26873938e9784d6b24bcf11238dac318ea3cc84fd6b495a361965799b78992a4"),
+            q_x: &hex!("9807edf33455e680a2937551a68fdbb51ce7577adbabea0da67f5b199e8da441"),
+            q_y: &hex!("93b47198980805eebe08c8ae29c06b12a6773a66318bbb50c0656fa9f7ed8534"),
+            k: &hex!("5caf5ff9e787967df7c73f6146a7f5f68ccf74173d71d9a82fa15e1ebd84dd49"),
+            m: &hex!("f416041bd1ede59c3b9ed7a90f440fbce40c9d10d4e33742bece9a91072d39f8"),
+            r: &hex!("5948b9218b576ea23216eaf8bc3bd3e7bc9af9230959c71d242609d94c6897e8"),
+            s: &hex!("1b22a3e041ad073c8fec83e57019aba82f5a9634bd393e6b0a5d81cf833b8b73"),
+        },
+        TestVector {
+            d: &hex!("15876c5bc4b08cfe6cd5cba2d6538c0b1d7c6bb7c40a4a46cb44d267f5cfe9d5"),
+            q_x: &hex!("7e119894e6d6c3474b52643b7ab17d97908d199256a3333007d29a74c9406956"),
+            q_y: &hex!("0e326a932db9968e3e2a805f332b846a641514fea6cc8982441f9523fb653311"),
+            k: &hex!("a9b8d3a9787682967a49b9077377491a3af78eafd3e62fa405b3a3866108b338"),
+            m: &hex!("645772f582e79d35010fae00d53ea283e0e4b2497520277a18e88754f23a4673"),
+            r: &hex!("5230c450288377a3d40af49f8436d8e78ca75b5aac0eda8e1cbf731d0dc07b4b"),
+            s: &hex!("36caf4a5bb762679e9c1c2be0155f753957b04705bab1577cc7ea4f66260e7c6"),
+        },
+        TestVector {
+            d: &hex!("74fc8c27a2ff8c752ff0c9667fd5883c6bec40eb9a1e0ac49ca1ba16ab3228ad"),
+            q_x: &hex!("40973e4936662726b84703813f02857585a11662ed47093e9e435f9a495e6f8d"),
+            q_y: &hex!("9f68e788bc6e11ad2318f488b0fe799c229689d52955b536db5878de9f25d9cd"),
+            k: &hex!("2d2573665e18a01005f90380d395c30c421fc133f957a5c13de3c60831600d51"),
+            m: &hex!("094874068fcd1946af147e9bc69d685aa2d505589da2af7ec1d402c879a401c8"),
+            r: &hex!("5eec2e62d7b9bf5de137f2190256d70cdaebb5fb18a64988555c8b5e8606043f"),
+            s: &hex!("987adaaa7687e7bc7e039618eb1e1ee9a37c06a7b718b3230cc4bac2d8968cd6"),
+        },
+        TestVector {
+            d: &hex!("5aeccaccb3b2e5218f4bcd8ecea7d28aeb32dc9397345f9ccdb220f203c9da5b"),
+            q_x: &hex!("9997813dc0d18402200fc3140342bd6db63a14230b9085498f4d9f990a7077eb"),
+            q_y: &hex!("063a0962c3e6bf4392ede336aa4c97a098de82f83fb3051c4e32686989d803df"),
+            k: &hex!("6e7deac5934d135ca39d11056175842e7a2b38eb83de9629e14065eee94c19a4"),
+            m: &hex!("c44b9f1808f05d1983b41a75a58ee46a00ab1868eb597ccecba085fd93ba181b"),
+            r: &hex!("8cfa64c7bdf58640df7bdb40fc58bce45ad67117755c5d83976df8bac40baf40"),
+            s: &hex!("9696cc51f0df9b12fc4f425fee3f6c1eba191103bba99dc199e27b88d79f8361"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP256t1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP256r1, ECDSA_TEST_VECTORS};
+        use crate::{r1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP256r1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    mod verify {
+        use super::{BrainpoolP256r1, ECDSA_TEST_VECTORS};
+        ecdsa::new_verification_test!(BrainpoolP256r1, ECDSA_TEST_VECTORS);
+    }
+
+    /// ECDSA/brainpoolP256r1/SHA-256 verification vectors in the Wycheproof `EcdsaVerify`
+    /// layout, including invalid signatures: modified `r`/`s`, values not
+    /// reduced modulo `n`, zero/`n`, and non-DER or truncated encodings.
+    ///
+    /// These were generated with random keys and nonces, and every row was
+    /// checked against OpenSSL.
+    mod verify_vectors {
+        use super::BrainpoolP256r1;
+        ecdsa::new_wycheproof_test!(ecdsa_verify_vectors, "ecdsa_verify", BrainpoolP256r1);
+    }
+}
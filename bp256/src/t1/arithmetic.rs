@@ -0,0 +1,69 @@
+//! brainpoolP256t1 curve arithmetic.
+
+use super::BrainpoolP256t1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{
+    AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
+};
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+/// brainpoolP256t1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP256t1>;
+
+/// brainpoolP256t1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP256t1>;
+
+impl WeierstrassCurve for BrainpoolP256t1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = -3 (a9fb57db a1eea9bc 3e660a90 9d838d72 6e3bf623 d5262028 2013481d 1f6e5374)
+    const EQUATION_A: FieldElement = FieldElement::ZERO
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
+
+    /// b = 662c61c4 30d84ea4 fe66a773 3d0b76b7 bf93ebc4 af2f4925 6ae58101 fee92b04
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "662c61c430d84ea4fe66a7733d0b76b7bf93ebc4af2f49256ae58101fee92b04",
+    );
+
+    /// Base point of brainpoolP256t1.
+    ///
+    /// Defined in RFC 5639 § 3.4:
+    ///
+    /// ```text
+    /// Gₓ = a3e8eb3c c1cfe7b7 732213b2 3a656149 afa142c4 7aafbc2b 79a19156 2e1305f4
+    /// Gᵧ = 2d996c82 3439c56d 7f7b22e1 4644417e 69bcb6de 39d02700 1dabe8f3 5b25c9be
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4",
+        ),
+        FieldElement::from_be_hex(
+            "2d996c823439c56d7f7b22e14644417e69bcb6de39d027001dabe8f35b25c9be",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP256t1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP256t1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl PrimeCurveArithmetic for BrainpoolP256t1 {
+    type CurveGroup = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP256t1 {
+    type Scalar = Scalar;
+}
@@ -0,0 +1,121 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP256t1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp256::t1::{EncodedPoint, PublicKey, ecdh::EphemeralSecret};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret from it
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP256t1;
+
+/// brainpoolP256t1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP256t1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP256t1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::t1::{PublicKey, SecretKey};
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// The RFC 7027 Appendix A.1 private keys on brainpoolP256t1.
+    ///
+    /// The shared secret was cross-checked with OpenSSL.
+    #[test]
+    fn known_answer() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!(
+            "81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"
+        ))
+        .unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!(
+            "55e40bc41e37e3e2ad25c3c6654511ffa8474a91a0032087593852d3e7d76bd3"
+        ))
+        .unwrap();
+        let alice_public = PublicKey::from_sec1_bytes(&hex!("04a5f81698aeee80c41a71796fc5e51fcc09b679bb3076c78e9af22f8373dc503ea59f316bb8d9acad8ac8467bd94878b6f294306e70034575fe6bf8d2eadd58cb")).unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!("04a935a9a9fe5c7bdb914f4e49d37255887b7122339b37f179a668a4666298693f004e506e16438ea28b534c1782917137ecf99face2f24a7a54f763d90fccfb60")).unwrap();
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected = hex!("12cc403912543a9131162f3fc604046f2deb3501ddab82d17ffff7566270b22c");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+
+    #[test]
+    fn reject_off_curve_point() {
+        let public_key = EphemeralSecret::random(&mut OsRng).public_key();
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(public_key.to_encoded_point(false).as_bytes());
+
+        // Flip the low bit of the y-coordinate, moving the point off the curve
+        bytes[64] ^= 1;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+    }
+}
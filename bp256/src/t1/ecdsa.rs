@@ -1,6 +1,33 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP256t1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP256t1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha256` feature.
 
 pub use super::BrainpoolP256t1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U256},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
 
 /// ECDSA/brainpoolP256t1 signature (fixed-size)
 pub type Signature = ecdsa::Signature<BrainpoolP256t1>;
@@ -8,8 +35,200 @@ pub type Signature = ecdsa::Signature<BrainpoolP256t1>;
 /// ECDSA/brainpoolP256t1 signature (ASN.1 DER encoded)
 pub type DerSignature = ecdsa::der::Signature<BrainpoolP256t1>;
 
+/// ECDSA/brainpoolP256t1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP256t1>;
+
+/// ECDSA/brainpoolP256t1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP256t1>;
+
 #[cfg(feature = "sha256")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
 impl ecdsa::hazmat::DigestPrimitive for BrainpoolP256t1 {
     type Digest = sha2::Sha256;
 }
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP256t1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP256t1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP256t1>>,
+        U256: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP256t1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP256t1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP256t1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha256"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP256t1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-256 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "sample" as its
+    /// SHA-256 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("3eca8fc3971463175857b3b3730157bcbf0e65f91ecdd9b350d37191da14eeb5");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "62f2840b369d5abd2ecf46b3a971eefffb906032e85d08d239dd59b7a77ed5c2
+                9d3bdacb6c76fa230f13898ef97d8337a83c577f76731ef8aa47511fdbac4cdf"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "39c944528767975b945e952397b8566ddc59f4c47c729e7ec35750c94804e5b3
+                913361d22ae50c85575f3b97f6b7e4ff7669a23b0dfc0248b3e2dc6d028cae3e"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!(
+            "3eca8fc3971463175857b3b3730157bcbf0e65f91ecdd9b350d37191da14eeb5"
+        ))
+        .unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP256t1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-256 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("7aa16a68bccab6ab90b7cc183538894c0b22e311a0cf7f8e726ad121ca41fba6"),
+            q_x: &hex!("82f2af994c74133b124d4d961f6899ac4c5f681d8d551e299326d8758d300509"),
+            q_y: &hex!("02b0e27b51503665825a7ed40bd010b9e8e979ee45ac9aef19f424dd108fa8a8"),
+            k: &hex!("20808a062b82b0dbca34fe613e70f001354d95c7c6f9ab2b8cb313c30e48c365"),
+            m: &hex!("8fa88780fcd7c809d2bf0435ea19a827654f25e218f37d2ee7b2bb44212c1b77"),
+            r: &hex!("19076a1db6c298161ae4a0956624b9acca2c4a92a8ccafd2d1a95dacf579a667"),
+            s: &hex!("4f31523c20e6ccf2a2c90cc13ab1b25d9aa9d9ba382bcebacd46ea1fa1e2ac16"),
+        },
+        TestVector {
+            d: &hex!("2e0f727b26deecb525c7b384b8369c23227d1eff94e53152cacf761c40c708d3"),
+            q_x: &hex!("0107b446bcc05976f8fc2dacfe2d9d54bbb6e1a297edc7e8b50049049a11f449"),
+            q_y: &hex!("77ba181df57ea5861225a5d83b73544ec2b09d19d854d8cf8edd968791c8da97"),
+            k: &hex!("1288350adbb0d0ad93567e893f12af18ccd5f18b704ecafbdf6ab3d23ed6546b"),
+            m: &hex!("1cdb4e4a7d4d2d7d470e9534947dbd5d379e8d5f4a743b84281d413239d1a665"),
+            r: &hex!("966f6f4532196ed3c2eaa38fb0456beb201b1bbd9359e88e917890dc0d77e687"),
+            s: &hex!("2be37a3c7bc98164076d6789ef45be0a8eeda8004f8ed0ce9c521277db97514a"),
+        },
+        TestVector {
+            d: &hex!("436a2cd4a47ac32d329333703e8c9c799ec25bb58647b9f79f0b5948d4dfa6e3"),
+            q_x: &hex!("9ab6d9cdf096d313d883b83b23bbed23b2e2b28ab66df4ded849833db631ce6f"),
+            q_y: &hex!("5a7bf745345e1fb575ad10eccf135882dd25c8e9036ff2c23699abad18789daa"),
+            k: &hex!("25bce4a616a0665183b532dd3018a227c9558d05f0f680aa42c77ed5d99958c3"),
+            m: &hex!("e84ae037040de45b1bfd6090b9100e5c80c2103169e50938c72946e983053287"),
+            r: &hex!("3bcc85f1429f213f33324b5f86bd72d61e80b366e0437c4d5d428e426959593f"),
+            s: &hex!("15747ac408616cc0b330b7c3c9f3e55c4d634e7e68839e3670a60ec222cc9d79"),
+        },
+        TestVector {
+            d: &hex!("30ec9f6cc6c5c6c772227b4412c50045b9ef087e21fa16317a12a282112747a7"),
+            q_x: &hex!("662c1c9d1974cb641a66ef3b1868b709d2a139abd8bdc178b281ec5bc58b243f"),
+            q_y: &hex!("6b4dfc5108f4f227e183aec88cae2d7713ad9f9385ae4fb70f012d9991324732"),
+            k: &hex!("8e22f522f6eb1301497544f8356bae759429514960987801edbdf0ec020c897b"),
+            m: &hex!("54a7714e3552b117eed243ab26522ec985eb14c5d852919d0f5e859f853f3739"),
+            r: &hex!("a98bb73c8635f99f68600397f95c8bdfb3711e0dc4303cfdd032374785dafaf8"),
+            s: &hex!("a72c80a416fbe0d2f123acd7e570a428d2c5d00f3704430e9ecafaa0f7fe008c"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP256r1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP256t1, ECDSA_TEST_VECTORS};
+        use crate::{t1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP256t1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    mod verify {
+        use super::{BrainpoolP256t1, ECDSA_TEST_VECTORS};
+        ecdsa::new_verification_test!(BrainpoolP256t1, ECDSA_TEST_VECTORS);
+    }
+
+    /// ECDSA/brainpoolP256t1/SHA-256 verification vectors in the Wycheproof `EcdsaVerify`
+    /// layout, including invalid signatures: modified `r`/`s`, values not
+    /// reduced modulo `n`, zero/`n`, and non-DER or truncated encodings.
+    ///
+    /// These were generated with random keys and nonces, and every row was
+    /// checked against OpenSSL.
+    mod verify_vectors {
+        use super::BrainpoolP256t1;
+        ecdsa::new_wycheproof_test!(ecdsa_verify_vectors, "ecdsa_verify", BrainpoolP256t1);
+    }
+}
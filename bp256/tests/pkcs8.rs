@@ -0,0 +1,142 @@
+//! PKCS#8 tests
+
+#![cfg(feature = "pkcs8")]
+
+/// Generate PKCS#8 tests for the given curve module using the example keys
+/// in `tests/examples`.
+macro_rules! pkcs8_tests {
+    ($curve:ident, $priv_der:expr, $pub_der:expr, $priv_pem:expr, $pub_pem:expr, $scalar:expr, $point:expr) => {
+        mod $curve {
+            use bp256::{
+                elliptic_curve::sec1::ToEncodedPoint,
+                pkcs8::{DecodePrivateKey, DecodePublicKey},
+                $curve::{PublicKey, SecretKey},
+            };
+            use hex_literal::hex;
+
+            #[cfg(feature = "pem")]
+            use bp256::pkcs8::{EncodePrivateKey, EncodePublicKey};
+
+            /// DER-encoded PKCS#8 private key
+            const PKCS8_PRIVATE_KEY_DER: &[u8] = include_bytes!($priv_der);
+
+            /// DER-encoded PKCS#8 public key
+            const PKCS8_PUBLIC_KEY_DER: &[u8] = include_bytes!($pub_der);
+
+            /// PEM-encoded PKCS#8 private key
+            #[cfg(feature = "pem")]
+            const PKCS8_PRIVATE_KEY_PEM: &str = include_str!($priv_pem);
+
+            /// PEM-encoded PKCS#8 public key
+            #[cfg(feature = "pem")]
+            const PKCS8_PUBLIC_KEY_PEM: &str = include_str!($pub_pem);
+
+            #[test]
+            fn decode_pkcs8_private_key_from_der() {
+                let secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                assert_eq!(secret_key.to_be_bytes().as_slice(), &$scalar[..]);
+            }
+
+            #[test]
+            fn decode_pkcs8_public_key_from_der() {
+                let public_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(public_key.to_encoded_point(false).as_bytes(), &$point[..]);
+            }
+
+            #[test]
+            fn public_key_matches_private_key() {
+                let secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let public_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(secret_key.public_key(), public_key);
+            }
+
+            #[test]
+            #[cfg(feature = "ecdsa")]
+            fn decode_ecdsa_keys_from_der() {
+                use bp256::$curve::ecdsa::{SigningKey, VerifyingKey};
+
+                let signing_key = SigningKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let verifying_key =
+                    VerifyingKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(signing_key.to_bytes().as_slice(), &$scalar[..]);
+                assert_eq!(signing_key.verifying_key(), verifying_key);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn decode_pkcs8_private_key_from_pem() {
+                let secret_key = PKCS8_PRIVATE_KEY_PEM.parse::<SecretKey>().unwrap();
+
+                // Ensure key parses equivalently to DER
+                let der_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                assert_eq!(secret_key.to_be_bytes(), der_key.to_be_bytes());
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn decode_pkcs8_public_key_from_pem() {
+                let public_key = PKCS8_PUBLIC_KEY_PEM.parse::<PublicKey>().unwrap();
+
+                // Ensure key parses equivalently to DER
+                let der_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(public_key, der_key);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_private_key_to_der() {
+                let original_secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let reencoded_secret_key = original_secret_key.to_pkcs8_der().unwrap();
+                assert_eq!(reencoded_secret_key.as_bytes(), PKCS8_PRIVATE_KEY_DER);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_public_key_to_der() {
+                let original_public_key =
+                    PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                let reencoded_public_key = original_public_key.to_public_key_der().unwrap();
+                assert_eq!(reencoded_public_key.as_ref(), PKCS8_PUBLIC_KEY_DER);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_private_key_to_pem() {
+                let original_secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let reencoded_secret_key = original_secret_key
+                    .to_pkcs8_pem(Default::default())
+                    .unwrap();
+                assert_eq!(reencoded_secret_key.as_str(), PKCS8_PRIVATE_KEY_PEM);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_public_key_to_pem() {
+                let original_public_key =
+                    PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                let reencoded_public_key = original_public_key.to_string();
+                assert_eq!(reencoded_public_key.as_str(), PKCS8_PUBLIC_KEY_PEM);
+            }
+        }
+    };
+}
+
+pkcs8_tests!(
+    r1,
+    "examples/r1-pkcs8-private-key.der",
+    "examples/r1-pkcs8-public-key.der",
+    "examples/r1-pkcs8-private-key.pem",
+    "examples/r1-pkcs8-public-key.pem",
+    hex!("9576a01b97233a96cecd6e6f88b3ac064e5bb6460d76112b40a180af6bc0b0c9"),
+    hex!("049804a5e1b1ca3d59e9cdeba173c4b07a1d960ff356f8897c65deabcd8a6b64760132d963963c279eb7f188d36f4a9bc250e596ba80cc1b5400b9460f4df7dc94")
+);
+
+pkcs8_tests!(
+    t1,
+    "examples/t1-pkcs8-private-key.der",
+    "examples/t1-pkcs8-public-key.der",
+    "examples/t1-pkcs8-private-key.pem",
+    "examples/t1-pkcs8-public-key.pem",
+    hex!("3eca8fc3971463175857b3b3730157bcbf0e65f91ecdd9b350d37191da14eeb5"),
+    hex!("04461a6dcedd37efbb578c649cf9da3c4799fcc9a4071d8f9ad1b8cce98f5d9c64755772c2ed3818579a650fce3a484efff2c0060ac05cfde5900996ef7871f7dc")
+);
@@ -0,0 +1,162 @@
+//! brainpoolP256r1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp256::{
+    r1::{AffinePoint, EncodedPoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::{prime::PrimeCurveAffine, Group},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
+    "04 8bd2aeb9 cb7e57cb 2c4b482f fc81b7af b9de27e1 e3bd23c2 3a4453bd 9ace3262
+        547ef835 c3dac4fd 97f8461a 14611dc9 c2774513 2ded8e54 5c1d54c7 2f046997"
+);
+
+const COMPRESSED_BASEPOINT: &[u8] =
+    &hex!("03 8bd2aeb9 cb7e57cb 2c4b482f fc81b7af b9de27e1 e3bd23c2 3a4453bd 9ace3262");
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+const MUL_TEST_VECTORS: &[([u8; 32], [u8; 65])] = &[
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 743cf1b8b5cd4f2eb55f8aa369593ac436ef044166699e37d51a14c2ce13ea0e 36ed163337deba9c946fe0bb776529da38df059f69249406892ada097eeb7cd4"),
+    ),
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 a8f217b77338f1d4d6624c3ab4f6cc16d2aa843d0c0fca016b91e2ad25cae39d 4b49cafc7dac26bb0aa2a6850a1b40f5fac10e4589348fb77e65cc5602b74f9d"),
+    ),
+    // RFC 7027 Appendix A.1
+    (
+        hex!("81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"),
+        hex!("04 44106e913f92bc02a1705d9953a8414db95e1aaa49e81d9e85f929a8e3100be5 8ab4846f11caccb73ce49cbdd120f5a900a69fd32c272223f789ef10eb089bdc"),
+    ),
+    (
+        hex!("55e40bc41e37e3e2ad25c3c6654511ffa8474a91a0032087593852d3e7d76bd3"),
+        hex!("04 8d2d688c6cf93e1160ad04cc4429117dc2c41825e1e9fca0addd34e6f1b39f7b 990c57520812be512641e47034832106bc7d3e8dd0e4c7f1136d7006547cec6a"),
+    ),
+    // n - 1
+    (
+        hex!("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a6"),
+        hex!("04 8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262 557c5fa5de13e4bea66dc47689226fa8abc4b110a73891d3c3f5f355f069e9e0"),
+    ),
+];
+
+#[test]
+fn uncompressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(false);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn compressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(true);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn uncompressed_to_compressed() {
+    let encoded = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(true);
+    assert_eq!(res.as_bytes(), COMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn compressed_to_uncompressed() {
+    let encoded = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(false);
+    assert_eq!(res.as_bytes(), UNCOMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    let mut bytes = [0u8; 65];
+    bytes.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    bytes[64] ^= 1;
+
+    let pubkey = EncodedPoint::from_bytes(bytes).unwrap();
+    assert!(bool::from(
+        AffinePoint::from_encoded_point(&pubkey).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(point.to_encoded_point(false).as_bytes(), &expected[..]);
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(public_key.to_encoded_point(false).as_bytes(), &expected[..]);
+    assert_eq!(
+        PublicKey::from_sec1_bytes(&expected[..]).unwrap(),
+        public_key
+    );
+}
+
+#[cfg(feature = "jwk")]
+#[test]
+fn jwk_round_trip() {
+    let (k, _) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+
+    let jwk = secret_key.to_jwk();
+    assert_eq!(jwk.crv(), "BP-256");
+    assert_eq!(
+        SecretKey::from_jwk(&jwk).unwrap().to_be_bytes(),
+        secret_key.to_be_bytes()
+    );
+
+    let public_key = secret_key.public_key();
+    let jwk_str = public_key.to_jwk_string();
+    assert_eq!(PublicKey::from_jwk_str(&jwk_str).unwrap(), public_key);
+}
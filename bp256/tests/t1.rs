@@ -0,0 +1,139 @@
+//! brainpoolP256t1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp256::{
+    t1::{AffinePoint, EncodedPoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::{prime::PrimeCurveAffine, Group},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
+    "04 a3e8eb3c c1cfe7b7 732213b2 3a656149 afa142c4 7aafbc2b 79a19156 2e1305f4
+        2d996c82 3439c56d 7f7b22e1 4644417e 69bcb6de 39d02700 1dabe8f3 5b25c9be"
+);
+
+const COMPRESSED_BASEPOINT: &[u8] =
+    &hex!("02 a3e8eb3c c1cfe7b7 732213b2 3a656149 afa142c4 7aafbc2b 79a19156 2e1305f4");
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+const MUL_TEST_VECTORS: &[([u8; 32], [u8; 65])] = &[
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 8338427c7cf4d11cb981d9b18793e3779c494c502c75bd739e578de2a700578d 546b03682557e9f72e9d6ecd39f5bbf241bc1cf07808f04a9948b25bf2378aff"),
+    ),
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 46b2a45fdd881abea0cb4e5fea19c5a72d399245643b06e0fbe24a5e4058d806 4f88cd8d4bc69acc7b7032d98460b2c23160441f40562c00bee2aa7860c19aa8"),
+    ),
+    (
+        hex!("81db1ee100150ff2ea338d708271be38300cb54241d79950f77b063039804f1d"),
+        hex!("04 a5f81698aeee80c41a71796fc5e51fcc09b679bb3076c78e9af22f8373dc503e a59f316bb8d9acad8ac8467bd94878b6f294306e70034575fe6bf8d2eadd58cb"),
+    ),
+    // n - 1
+    (
+        hex!("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a6"),
+        hex!("04 a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4 7c61eb596db4e44ebeeae7af573f4bf4047f3f459b55f92802675f29c44889b9"),
+    ),
+];
+
+#[test]
+fn uncompressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(false);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn compressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(true);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn uncompressed_to_compressed() {
+    let encoded = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(true);
+    assert_eq!(res.as_bytes(), COMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn compressed_to_uncompressed() {
+    let encoded = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(false);
+    assert_eq!(res.as_bytes(), UNCOMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    let mut bytes = [0u8; 65];
+    bytes.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    bytes[64] ^= 1;
+
+    let pubkey = EncodedPoint::from_bytes(bytes).unwrap();
+    assert!(bool::from(
+        AffinePoint::from_encoded_point(&pubkey).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(point.to_encoded_point(false).as_bytes(), &expected[..]);
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(public_key.to_encoded_point(false).as_bytes(), &expected[..]);
+    assert_eq!(
+        PublicKey::from_sec1_bytes(&expected[..]).unwrap(),
+        public_key
+    );
+}
@@ -8,11 +8,34 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+//! ## `serde` support
+//!
+//! When the `serde` feature of this crate is enabled, `Serialize` and
+//! `Deserialize` are impl'd for the following types:
+//!
+//! - [`r1::AffinePoint`] and [`t1::AffinePoint`]
+//! - [`Scalar`]
+//! - [`r1::ecdsa::VerifyingKey`] and [`t1::ecdsa::VerifyingKey`]
+//!
+//! Please see type-specific documentation for more information.
+//!
+//! ## JWK support
+//!
+//! When the `jwk` feature of this crate is enabled, brainpoolP384r1 keys can
+//! be encoded as JSON Web Keys using the `crv` name `BP-384`. There is no
+//! established `crv` name for brainpoolP384t1, so it doesn't support JWK.
+
 pub mod r1;
 pub mod t1;
 
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
 pub use crate::{r1::BrainpoolP384r1, t1::BrainpoolP384t1};
 pub use elliptic_curve::{self, bigint::U384};
 
+#[cfg(feature = "arithmetic")]
+pub use crate::arithmetic::{scalar::Scalar, BlindedScalar};
+
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
@@ -1,9 +1,19 @@
 //! brainpoolP384r1 elliptic curve: verifiably pseudo-random variant
 
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
 pub mod ecdsa;
 
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "arithmetic")]
+pub use self::arithmetic::{AffinePoint, ProjectivePoint};
+
 use elliptic_curve::bigint::U384;
 
 #[cfg(feature = "pkcs8")]
@@ -25,9 +35,21 @@ impl elliptic_curve::Curve for BrainpoolP384r1 {
 impl elliptic_curve::PrimeCurve for BrainpoolP384r1 {}
 
 impl elliptic_curve::PointCompression for BrainpoolP384r1 {
+    /// Brainpool points are typically uncompressed.
     const COMPRESS_POINTS: bool = false;
 }
 
+impl elliptic_curve::PointCompaction for BrainpoolP384r1 {
+    /// Brainpool points are typically uncompressed.
+    const COMPACT_POINTS: bool = false;
+}
+
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+impl elliptic_curve::JwkParameters for BrainpoolP384r1 {
+    const CRV: &'static str = "BP-384";
+}
+
 #[cfg(feature = "pkcs8")]
 impl pkcs8::AssociatedOid for BrainpoolP384r1 {
     const OID: pkcs8::ObjectIdentifier =
@@ -42,7 +64,16 @@ pub type FieldBytes = elliptic_curve::FieldBytes<BrainpoolP384r1>;
 /// brainpoolP384r1 SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<BrainpoolP384r1>;
 
+/// Non-zero brainpoolP384r1 scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP384r1>;
+
+/// brainpoolP384r1 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP384r1>;
+
 /// brainpoolP384r1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP384r1>;
 
+#[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for BrainpoolP384r1 {}
@@ -0,0 +1,71 @@
+//! brainpoolP384r1 curve arithmetic.
+
+use super::BrainpoolP384r1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{
+    AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
+};
+use weierstrass::WeierstrassCurve;
+
+/// brainpoolP384r1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP384r1>;
+
+/// brainpoolP384r1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP384r1>;
+
+impl WeierstrassCurve for BrainpoolP384r1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = 7bc382c6 3d8c150c 3c72080a ce05afa0 c2bea28e 4fb22787
+    ///     139165ef ba91f90f 8aa5814a 503ad4eb 04a8c7dd 22ce2826
+    const EQUATION_A: FieldElement = FieldElement::from_be_hex(
+        "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826",
+    );
+
+    /// b = 04a8c7dd 22ce2826 8b39b554 16f0447c 2fb77de1 07dcd2a6
+    ///     2e880ea5 3eeb62d5 7cb43902 95dbc994 3ab78696 fa504c11
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11",
+    );
+
+    /// Base point of brainpoolP384r1.
+    ///
+    /// Defined in RFC 5639 § 3.6:
+    ///
+    /// ```text
+    /// Gₓ = 1d1c64f0 68cf45ff a2a63a81 b7c13f6b 8847a3e7 7ef14fe3
+    ///      db7fcafe 0cbd10e8 e826e034 36d646aa ef87b2e2 47d4af1e
+    /// Gᵧ = 8abe1d75 20f9c2a4 5cb1eb8e 95cfd552 62b70b29 feec5864
+    ///      e19c054f f9912928 0e464621 77918111 42820341 263c5315
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e",
+        ),
+        FieldElement::from_be_hex(
+            "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP384r1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP384r1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl PrimeCurveArithmetic for BrainpoolP384r1 {
+    type CurveGroup = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP384r1 {
+    type Scalar = Scalar;
+}
@@ -0,0 +1,113 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP384r1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp384::r1::{EncodedPoint, PublicKey, ecdh::EphemeralSecret};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret from it
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP384r1;
+
+/// brainpoolP384r1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP384r1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP384r1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::r1::{PublicKey, SecretKey};
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Test vector from RFC 7027 Appendix A.2
+    #[test]
+    fn rfc7027() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!("1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042")).unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!("032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670")).unwrap();
+        let alice_public = PublicKey::from_sec1_bytes(&hex!("0468b665dd91c195800650cdd363c625f4e742e8134667b767b1b476793588f885ab698c852d4a6e77a252d6380fcaf06855bc91a39c9ec01dee36017b7d673a931236d2f1f5c83942d049e3fa20607493e0d038ff2fd30c2ab67d15c85f7faa59")).unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!("044d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb462d692136de56cbe93bf5fa3188ef58bc8a3a0ec6c1e151a21038a42e9185329b5b275903d192f8d4e1f32fe9cc78c48")).unwrap();
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected = hex!("0bd9d3a7ea0b3d519d09d8e48d0785fb744a6b355e6304bc51c229fbbce239bbadf6403715c35d4fb2a5444f575d4f42");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+
+    #[test]
+    fn reject_off_curve_point() {
+        let public_key = EphemeralSecret::random(&mut OsRng).public_key();
+        let mut bytes = [0u8; 97];
+        bytes.copy_from_slice(public_key.to_encoded_point(false).as_bytes());
+
+        // Flip the low bit of the y-coordinate, moving the point off the curve
+        bytes[96] ^= 1;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+    }
+}
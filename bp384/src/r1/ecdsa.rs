@@ -1,6 +1,33 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP384r1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP384r1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha384` feature.
 
 pub use super::BrainpoolP384r1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U384},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
 
 /// ECDSA/brainpoolP384r1 signature (fixed-size)
 pub type Signature = ecdsa::Signature<BrainpoolP384r1>;
@@ -8,8 +35,197 @@ pub type Signature = ecdsa::Signature<BrainpoolP384r1>;
 /// ECDSA/brainpoolP384r1 signature (ASN.1 DER encoded)
 pub type DerSignature = ecdsa::der::Signature<BrainpoolP384r1>;
 
+/// ECDSA/brainpoolP384r1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP384r1>;
+
+/// ECDSA/brainpoolP384r1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP384r1>;
+
 #[cfg(feature = "sha384")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sha384")))]
 impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384r1 {
     type Digest = sha2::Sha384;
 }
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP384r1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP384r1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP384r1>>,
+        U384: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP384r1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP384r1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP384r1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha384"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP384r1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-384 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "sample" as its
+    /// SHA-384 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("45d6d992808b54e95befe5f668e6418f0713a60aa049bd669d9fc5141e005c6ae3970a21389ab4449cb4e397ebaa08c6");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "652524c9320078f4c0c6176226cac066b17c34a4ed66291f5b414cdcdfe633344f33521514dc075f35e87944aeaf2f33
+                34d82c8c91563cb0fc5d063c1b778e7d334692461acbe2a9652df90efefdaf98fe83dd4d65b590bca3299fa7c45d19c8"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "77bae588690473dad42699f1f7a2c8becf00781fd6f53239ace13ea941c8feae887fa7060360fba6f5dec3f629db17ad
+                25a3396f2d07aa5407216e8d03325b60c3f9ac6dd2dede204e5ae1951367acb0460f293110c64ab0e7550bdd06170dbf"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!("45d6d992808b54e95befe5f668e6418f0713a60aa049bd669d9fc5141e005c6ae3970a21389ab4449cb4e397ebaa08c6")).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP384r1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-384 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("860e5575b9badcd3e8fa0dd113f606947ef34974ec9aff21ef6333bec5b2b6d32025dfcbae974461f9c131d99fb890af"),
+            q_x: &hex!("2a07b3ec969efc9c5250d94c533a789f836e60350294aabf3ddf249ebc01058410ad0c62f5b1942d2566945044cbc51b"),
+            q_y: &hex!("123edc515cdcec34f25018f973e6adcf4eac313447fd5976a3c04c9f617a905c9005857e1e98f6da69f0fe716ee99661"),
+            k: &hex!("38f055bde241f7bd614365461a112f1099f93eec6cced7067ab388c45c42b3cf6b70767e499fee6eccb2c8b14b70b4d5"),
+            m: &hex!("44971e20be1e2c61b2d6431490091a6ea6659ff203fd80d6a862f6584b3af9d8ba35a92d5707acf4e8172a76aa921e65"),
+            r: &hex!("748be420cbe3f9cb578d886a7beebf663d089c3bc0bd7a12ced0842de2d98fe7699eb27095f0389a9ecbb313aa010470"),
+            s: &hex!("1eb05f5978d7bac0175e1aa13b950ec5096fde4c7ddb68ea0adcfac7e5ed4aa6cdc1eccf8d7d7570982bec0d9b36a836"),
+        },
+        TestVector {
+            d: &hex!("8970dcd06197603de6ad7b56e79fa03fb74e50bcdf5f120ccfdb56e68e063b73324a5cc3454d6f3bf3013e1a017142f7"),
+            q_x: &hex!("077342b9abbff74e81cbbaa74496eea9950ce2a248de11c0b8ffaacf9e8b22042716b15e4904217f81a30cd4f0bf5cab"),
+            q_y: &hex!("473f5b7ec7f5e6e5e06ca517e929e4206fd8c37a0d0a42319eb57b557fb8a8fc2a97c904edcd1a06f1870a1f8d1821df"),
+            k: &hex!("61f199e1c71d2c4a781e4d38dda00aec4d2c61f8562cb596a2a77b1d5bfa583fb7df35e172d5cc470e8fd2364bd6f110"),
+            m: &hex!("1898f4e58d8d0ad94f02eafededd18cce452f7f0118c4aac845ae61b261cd2697bb0127520223d3a079141fdca8a330b"),
+            r: &hex!("63a8870b78ae881a98c23857002a64bcd92291c3b7c7d122b12c42fac307735ddfb58632525c729f6e08d97f780295b2"),
+            s: &hex!("16ca5aa310437f8e0510dbb9a2d0b3c47139ac31c5f6b26c260bfc64b202fe033e3a85cc645e414fed4f24c55e866b76"),
+        },
+        TestVector {
+            d: &hex!("35d9fee8b26df4c1787ebc24f3fba2218586e822f24cb5e5693e0cff7f0f42c86d345f946adbb8ac85f6ca848049aea7"),
+            q_x: &hex!("45de2dde2548c4bf89be0c3a29f5c6f50a73608d99bee25281b87c0845f1afa02a8271ebe073dcca609b479d4f4726aa"),
+            q_y: &hex!("3f515008e164bde4aafdabe01b5be0e25be12e776c9b19040e4bea4d3f8beda2615e9b595a3821cc92dbcee7660c7f36"),
+            k: &hex!("079041228ae7ffcf5b83f902914219a76bdd73839bb2383699cc9c1c467b2a215d3935de8dd1cf2d03abf0631668bc4c"),
+            m: &hex!("ee2ea7713594028bf899f71f36fd821629cb5b19e2893cc4b1e7e5a43ce7058fad76059c66ebba14b96e6cdef3fa78b2"),
+            r: &hex!("77a6d5c9566f15a18992e435e04160a44d39fa8eddec4ef13c2b4fc8547b34c0b3a6ad7c3de09d489a56d415dc2ef08b"),
+            s: &hex!("18ba9dc20b1537464804cf1b289a90e7552abb5613f82c8cadb608676c42b291ae313282c13a5759c18ac618614a697c"),
+        },
+        TestVector {
+            d: &hex!("56f23eba03feb42e9ca4de1d3930408e12ab065a8e53fb4c23eec2e0d3af89c2a2979a09614043fa3c6bd289972c08b5"),
+            q_x: &hex!("0884a296bf17abde2c657e645739c23764a3b1ecc61eb163c3b811f8648b37f22a3b074bdbd688513efe5e443dca6f71"),
+            q_y: &hex!("4384fefd0cf46bf7575bf311e97794125485e5d5692a3aadb14e6ffd6b357cc71e39fc1ed073b22b8e4bad284b4ad8fd"),
+            k: &hex!("02a85374428d97841670d631c051d0d479c5c6c204e33fad54c442ca2329280ddbe18e707ac465b432ce16d6caffdbb5"),
+            m: &hex!("87dc790e4a6edd4cf8c14ea7ca560d4e1a7ff83b77e8a2121a0a58861785aefbe7684ef8d90f6fea1a26ab8eb00de97c"),
+            r: &hex!("0fc156e28b49d154c5ec15142bb0432bd32f544715afea97a1e6a7588ab3a402e63d43e0a5abe0d9d139b047cfecb032"),
+            s: &hex!("03f57b0b3c6fce90b572094b518e10a46cc5f1d30a61925be1588f685317ec8dbf7d3a0b6d1923c48fa6f6d454b1297f"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP384t1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP384r1, ECDSA_TEST_VECTORS};
+        use crate::{r1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP384r1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    mod verify {
+        use super::{BrainpoolP384r1, ECDSA_TEST_VECTORS};
+        ecdsa::new_verification_test!(BrainpoolP384r1, ECDSA_TEST_VECTORS);
+    }
+
+    /// ECDSA/brainpoolP384r1/SHA-384 verification vectors in the Wycheproof `EcdsaVerify`
+    /// layout, including invalid signatures: modified `r`/`s`, values not
+    /// reduced modulo `n`, zero/`n`, and non-DER or truncated encodings.
+    ///
+    /// These were generated with random keys and nonces, and every row was
+    /// checked against OpenSSL.
+    mod verify_vectors {
+        use super::BrainpoolP384r1;
+        ecdsa::new_wycheproof_test!(ecdsa_verify_vectors, "ecdsa_verify", BrainpoolP384r1);
+    }
+}
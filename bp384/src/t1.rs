@@ -1,9 +1,19 @@
 //! brainpoolP384t1 elliptic curve: twisted variant
 
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
 pub mod ecdsa;
 
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "arithmetic")]
+pub use self::arithmetic::{AffinePoint, ProjectivePoint};
+
 use elliptic_curve::bigint::U384;
 
 #[cfg(feature = "pkcs8")]
@@ -25,9 +35,15 @@ impl elliptic_curve::Curve for BrainpoolP384t1 {
 impl elliptic_curve::PrimeCurve for BrainpoolP384t1 {}
 
 impl elliptic_curve::PointCompression for BrainpoolP384t1 {
+    /// Brainpool points are typically uncompressed.
     const COMPRESS_POINTS: bool = false;
 }
 
+impl elliptic_curve::PointCompaction for BrainpoolP384t1 {
+    /// Brainpool points are typically uncompressed.
+    const COMPACT_POINTS: bool = false;
+}
+
 #[cfg(feature = "pkcs8")]
 impl pkcs8::AssociatedOid for BrainpoolP384t1 {
     const OID: pkcs8::ObjectIdentifier =
@@ -42,7 +58,16 @@ pub type FieldBytes = elliptic_curve::FieldBytes<BrainpoolP384t1>;
 /// brainpoolP384t1 SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<BrainpoolP384t1>;
 
+/// Non-zero brainpoolP384t1 scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP384t1>;
+
+/// brainpoolP384t1 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP384t1>;
+
 /// brainpoolP384t1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP384t1>;
 
+#[cfg(not(feature = "arithmetic"))]
 impl elliptic_curve::sec1::ValidatePublicKey for BrainpoolP384t1 {}
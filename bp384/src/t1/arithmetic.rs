@@ -0,0 +1,72 @@
+//! brainpoolP384t1 curve arithmetic.
+
+use super::BrainpoolP384t1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{
+    AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
+};
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+/// brainpoolP384t1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP384t1>;
+
+/// brainpoolP384t1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP384t1>;
+
+impl WeierstrassCurve for BrainpoolP384t1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = -3 (0x8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec50)
+    const EQUATION_A: FieldElement = FieldElement::ZERO
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
+
+    /// b = 7f519ead a7bda81b d826dba6 47910f8c 4b9346ed 8ccdc64e
+    ///     4b1abd11 756dce1d 2074aa26 3b88805c ed70355a 33b471ee
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "7f519eada7bda81bd826dba647910f8c4b9346ed8ccdc64e4b1abd11756dce1d2074aa263b88805ced70355a33b471ee",
+    );
+
+    /// Base point of brainpoolP384t1.
+    ///
+    /// Defined in RFC 5639 § 3.6:
+    ///
+    /// ```text
+    /// Gₓ = 18de98b0 2db9a306 f2afcd72 35f72a81 9b80ab12 ebd65317
+    ///      2476fecd 462aabff c4ff191b 946a5f54 d8d0aa2f 418808cc
+    /// Gᵧ = 25ab0569 62d30651 a114afd2 755ad336 747f9347 5b7a1fca
+    ///      3b88f2b6 a208ccfe 46940858 4dc2b291 2675bf5b 9e582928
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "18de98b02db9a306f2afcd7235f72a819b80ab12ebd653172476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc",
+        ),
+        FieldElement::from_be_hex(
+            "25ab056962d30651a114afd2755ad336747f93475b7a1fca3b88f2b6a208ccfe469408584dc2b2912675bf5b9e582928",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP384t1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP384t1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl PrimeCurveArithmetic for BrainpoolP384t1 {
+    type CurveGroup = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP384t1 {
+    type Scalar = Scalar;
+}
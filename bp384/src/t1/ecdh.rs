@@ -0,0 +1,115 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP384t1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp384::t1::{EncodedPoint, PublicKey, ecdh::EphemeralSecret};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret from it
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP384t1;
+
+/// brainpoolP384t1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP384t1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP384t1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::t1::{PublicKey, SecretKey};
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// The RFC 7027 Appendix A.2 private keys on brainpoolP384t1.
+    ///
+    /// The shared secret was cross-checked with OpenSSL.
+    #[test]
+    fn known_answer() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!("1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042")).unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!("032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670")).unwrap();
+        let alice_public = PublicKey::from_sec1_bytes(&hex!("0430883b5f8686f6be6c2f2fdd1d1ad997429887208451fc1f87bdb59c98c12de42dfe6872bf1a987a0e4e74ac54101b6207a9529d9a26174106ca47d66b56696a89c48f54f9489e1dc8c40fbe97e833a104f12b55a728d08e018af2e784c32fcd")).unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!("04346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e5456634266f22aaa0db788c833ad931ca89467aacd700002644b6f49f6cb4a92396d5194692ce03555ceb443f632f5ab5")).unwrap();
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected = hex!("19c8b336f14f547b2389099313a2dfd92f769185866302937a2b53f658b53e601a82459d123b75f6ca5295eed071ec3f");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+
+    #[test]
+    fn reject_off_curve_point() {
+        let public_key = EphemeralSecret::random(&mut OsRng).public_key();
+        let mut bytes = [0u8; 97];
+        bytes.copy_from_slice(public_key.to_encoded_point(false).as_bytes());
+
+        // Flip the low bit of the y-coordinate, moving the point off the curve
+        bytes[96] ^= 1;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+    }
+}
@@ -1,6 +1,33 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP384t1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP384t1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha384` feature.
 
 pub use super::BrainpoolP384t1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U384},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
 
 /// ECDSA/brainpoolP384t1 signature (fixed-size)
 pub type Signature = ecdsa::Signature<BrainpoolP384t1>;
@@ -8,8 +35,197 @@ pub type Signature = ecdsa::Signature<BrainpoolP384t1>;
 /// ECDSA/brainpoolP384t1 signature (ASN.1 DER encoded)
 pub type DerSignature = ecdsa::der::Signature<BrainpoolP384t1>;
 
+/// ECDSA/brainpoolP384t1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP384t1>;
+
+/// ECDSA/brainpoolP384t1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP384t1>;
+
 #[cfg(feature = "sha384")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sha384")))]
 impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384t1 {
     type Digest = sha2::Sha384;
 }
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP384t1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP384t1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP384t1>>,
+        U384: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP384t1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP384t1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP384t1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha384"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP384t1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-384 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "sample" as its
+    /// SHA-384 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("46f332907e4cb6d8cd81782d23e51616c1bfc2bdee74da2dc44d47c6deb19109a884ca8bd3c494c5399f272e85a07f8c");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "4c14e2786106f6ed5ac8cb7eb18584174ad0ac51fdae0a23b2817ddfa33fe2252a10c6d44d8a347782d239655699d394
+                8656ea7e96da06dfdbbb339f930a8829f2a87bf6221b3f88e6065720ac4c11dbeb6945b2d2196ec9751f818a10e3c8c3"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "53b1541fa583d6266ccf50d0ef134adbbcbe44e8b2c7132e3480ea87d122ac7009ec4d04a92a8605be02ca576fef6f1e
+                0973b1c1f9e7956ebde0c84f0434889ffec419728f53e11e8d4b01e7451fb26779f4dac50884aa581f25f9deaecfd48f"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!("46f332907e4cb6d8cd81782d23e51616c1bfc2bdee74da2dc44d47c6deb19109a884ca8bd3c494c5399f272e85a07f8c")).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP384t1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-384 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("59da30d8052debc2e98e72a45173ca08e41383c2c947527b6706c8c6a79fd58918418ae661c4d4068b6354353553bd34"),
+            q_x: &hex!("7552245b849b037ddb28e540b37b311964c93503ab0a0de87c50fff6fae42375ccdac6b1dae5117f895fee485a731994"),
+            q_y: &hex!("6c6ccc4345f48842537ffc680990934c6399ecc7ce367e6c9ae6dfd4cf2511decb0cfef2db294f09e7c3c1c69c6edbb6"),
+            k: &hex!("50aacd6fd7b38450ffb14e1987c8cd0b26597dd32ac5100bc1b1c9252b4e15d63e12ed4b2945e44a640929a96194f137"),
+            m: &hex!("5ae6a5510691d9954ddbc8ea8c433de87c7b8168e2b7f35be7326360aa037bd5b9abed425fc4c9346f678108206cc3a4"),
+            r: &hex!("670b7ee893677e2741d96b45bbbeef51a4d42080bf2a1196a22e5864d788109fa2f97e0713c1065e2142d39d444fdb93"),
+            s: &hex!("513ece91531a2f3674316857778f09b639cc2842811486c0eb9acccd4d0a67d0c943632eb04352c797e521b1329e297d"),
+        },
+        TestVector {
+            d: &hex!("2bf07a9ff798682ed29bbf87fa230de4d90ac47857714edaa1f650421e3da52b58159fd0c2d5ee26a1e65e793297d7a9"),
+            q_x: &hex!("5d1b3ed4c5317ea93b2b3604fc0f604fea876c3bccea07b149f4a6d7dc08494809eb5d96c403b3d284731b2875a3dcd5"),
+            q_y: &hex!("7b1048320f82ff24bcac26329a396d76c94eb5499663efcdd090620c059e9ea79fba53a69794b8297f8b4862582dbf98"),
+            k: &hex!("3a97b321e2c5036fabab967e472b5516674d4154315a92e07ba807ec9efa93d5a9ff8f80e818c7db5de68e2f02124cf6"),
+            m: &hex!("da5164b790164839f3f174ebadad0165119f9868dfbd20dcff15d02027f2daada0d424a651a433f6a5e8c108989da89e"),
+            r: &hex!("624b3b24ca67a6af542a05c3b7cd0c4469e906f6c9cac492dda2ad34eaf947f12e6fc2ea4a23a3b471ea6bbb47040873"),
+            s: &hex!("60582291a3b5c699d4bf71f4871b6f2da6c46a372720ac990f220ada5cc9cee32d1a9f40390fc903cd4999d5407ab786"),
+        },
+        TestVector {
+            d: &hex!("2595212ee95adff899dee4e2c8ee69ae6ccd2a57874030a4cb499d914049e66512892b80564550f9529962c1e58568e8"),
+            q_x: &hex!("07f8792a6974bdab8e80b45f39812b10df5d3977b5f11d521f41dbd17168c14c71eef0fed6a911be0ea403f53fe56316"),
+            q_y: &hex!("129a2c80d4318b99434b282b43eba145799267ff979c3e18d16ead99567c05f3e2c2a20b69cdafef3f9090ce4bc3d04d"),
+            k: &hex!("42be7c69b9b45612b2308851cf59caa1ce8ac13eeb382ae2db3de004c8ca542422b27b8cb7b27ca2f102dc1078a3b3c9"),
+            m: &hex!("b262e8bb531dbb179c969d703fff8f8fa2e7ff461e81bc5aeb2d94697da64214df7cd94df3184d56f9a2a2b7206f6fbf"),
+            r: &hex!("28dda0c9afc1a124f9f28847f99b734502099f9d6c8c47282e35d111608b52ca8aa18544cb4209fa644cd539fe81cc60"),
+            s: &hex!("5989545d929c626eeb3fe8d0621f098b0254217edb294d0b417d30685dea662a7d3b7350d5d2bca96213cd27e8f5bb21"),
+        },
+        TestVector {
+            d: &hex!("469e3fed396c5c2b36dff077331c0689be9a6eeefa0858cbd9ab0532b5f733b9fa2103e3bb93b73414eff1ceda799897"),
+            q_x: &hex!("5efaaf6a7183454d870796d35042671c1dbc1c37a0131ba6301a4f9ac7f731b56f64206717266974fa2a42c6045ec858"),
+            q_y: &hex!("3a8e71d3ee245b0e32ffcaa1657170989e5c459b6b17d924ccfeb75324f069c8369016ff317cadf1d69d999fd6526ebe"),
+            k: &hex!("15488c6ac9672a8c463f916d42fb6615239925479f73f4bc5173c042f2386a6af83313e626c340164b37a5cbb0e99600"),
+            m: &hex!("073c1bdfc58e5d4442dba12e2aa6904be4dbf291827637b9c37850de97478df696d7434887761b6b422d2e3c7b428642"),
+            r: &hex!("76d03c09be669fd8a5ba96bfc7afb2390879212e662d7970acdc067805892918666107688e7c2aa30a84499596307f45"),
+            s: &hex!("671db118f58238ff5d5064d9e513084b01f8319b3f1a1157870d48ffb4852255caa0fd760d1b9b276c77a4cda36c1fe8"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP384r1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP384t1, ECDSA_TEST_VECTORS};
+        use crate::{t1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP384t1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    mod verify {
+        use super::{BrainpoolP384t1, ECDSA_TEST_VECTORS};
+        ecdsa::new_verification_test!(BrainpoolP384t1, ECDSA_TEST_VECTORS);
+    }
+
+    /// ECDSA/brainpoolP384t1/SHA-384 verification vectors in the Wycheproof `EcdsaVerify`
+    /// layout, including invalid signatures: modified `r`/`s`, values not
+    /// reduced modulo `n`, zero/`n`, and non-DER or truncated encodings.
+    ///
+    /// These were generated with random keys and nonces, and every row was
+    /// checked against OpenSSL.
+    mod verify_vectors {
+        use super::BrainpoolP384t1;
+        ecdsa::new_wycheproof_test!(ecdsa_verify_vectors, "ecdsa_verify", BrainpoolP384t1);
+    }
+}
@@ -0,0 +1,142 @@
+//! PKCS#8 tests
+
+#![cfg(feature = "pkcs8")]
+
+/// Generate PKCS#8 tests for the given curve module using the example keys
+/// in `tests/examples`.
+macro_rules! pkcs8_tests {
+    ($curve:ident, $priv_der:expr, $pub_der:expr, $priv_pem:expr, $pub_pem:expr, $scalar:expr, $point:expr) => {
+        mod $curve {
+            use bp384::{
+                elliptic_curve::sec1::ToEncodedPoint,
+                pkcs8::{DecodePrivateKey, DecodePublicKey},
+                $curve::{PublicKey, SecretKey},
+            };
+            use hex_literal::hex;
+
+            #[cfg(feature = "pem")]
+            use bp384::pkcs8::{EncodePrivateKey, EncodePublicKey};
+
+            /// DER-encoded PKCS#8 private key
+            const PKCS8_PRIVATE_KEY_DER: &[u8] = include_bytes!($priv_der);
+
+            /// DER-encoded PKCS#8 public key
+            const PKCS8_PUBLIC_KEY_DER: &[u8] = include_bytes!($pub_der);
+
+            /// PEM-encoded PKCS#8 private key
+            #[cfg(feature = "pem")]
+            const PKCS8_PRIVATE_KEY_PEM: &str = include_str!($priv_pem);
+
+            /// PEM-encoded PKCS#8 public key
+            #[cfg(feature = "pem")]
+            const PKCS8_PUBLIC_KEY_PEM: &str = include_str!($pub_pem);
+
+            #[test]
+            fn decode_pkcs8_private_key_from_der() {
+                let secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                assert_eq!(secret_key.to_be_bytes().as_slice(), &$scalar[..]);
+            }
+
+            #[test]
+            fn decode_pkcs8_public_key_from_der() {
+                let public_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(public_key.to_encoded_point(false).as_bytes(), &$point[..]);
+            }
+
+            #[test]
+            fn public_key_matches_private_key() {
+                let secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let public_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(secret_key.public_key(), public_key);
+            }
+
+            #[test]
+            #[cfg(feature = "ecdsa")]
+            fn decode_ecdsa_keys_from_der() {
+                use bp384::$curve::ecdsa::{SigningKey, VerifyingKey};
+
+                let signing_key = SigningKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let verifying_key =
+                    VerifyingKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(signing_key.to_bytes().as_slice(), &$scalar[..]);
+                assert_eq!(signing_key.verifying_key(), verifying_key);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn decode_pkcs8_private_key_from_pem() {
+                let secret_key = PKCS8_PRIVATE_KEY_PEM.parse::<SecretKey>().unwrap();
+
+                // Ensure key parses equivalently to DER
+                let der_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                assert_eq!(secret_key.to_be_bytes(), der_key.to_be_bytes());
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn decode_pkcs8_public_key_from_pem() {
+                let public_key = PKCS8_PUBLIC_KEY_PEM.parse::<PublicKey>().unwrap();
+
+                // Ensure key parses equivalently to DER
+                let der_key = PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                assert_eq!(public_key, der_key);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_private_key_to_der() {
+                let original_secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let reencoded_secret_key = original_secret_key.to_pkcs8_der().unwrap();
+                assert_eq!(reencoded_secret_key.as_bytes(), PKCS8_PRIVATE_KEY_DER);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_public_key_to_der() {
+                let original_public_key =
+                    PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                let reencoded_public_key = original_public_key.to_public_key_der().unwrap();
+                assert_eq!(reencoded_public_key.as_ref(), PKCS8_PUBLIC_KEY_DER);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_private_key_to_pem() {
+                let original_secret_key = SecretKey::from_pkcs8_der(PKCS8_PRIVATE_KEY_DER).unwrap();
+                let reencoded_secret_key = original_secret_key
+                    .to_pkcs8_pem(Default::default())
+                    .unwrap();
+                assert_eq!(reencoded_secret_key.as_str(), PKCS8_PRIVATE_KEY_PEM);
+            }
+
+            #[test]
+            #[cfg(feature = "pem")]
+            fn encode_pkcs8_public_key_to_pem() {
+                let original_public_key =
+                    PublicKey::from_public_key_der(PKCS8_PUBLIC_KEY_DER).unwrap();
+                let reencoded_public_key = original_public_key.to_string();
+                assert_eq!(reencoded_public_key.as_str(), PKCS8_PUBLIC_KEY_PEM);
+            }
+        }
+    };
+}
+
+pkcs8_tests!(
+    r1,
+    "examples/r1-pkcs8-private-key.der",
+    "examples/r1-pkcs8-public-key.der",
+    "examples/r1-pkcs8-private-key.pem",
+    "examples/r1-pkcs8-public-key.pem",
+    hex!("45d6d992808b54e95befe5f668e6418f0713a60aa049bd669d9fc5141e005c6ae3970a21389ab4449cb4e397ebaa08c6"),
+    hex!("044024affc24a2aa80af65344379076bcb4d96209055be5c62b34eef9b77428d5b812cf26b32312754771e7bed41babad90bd9f308d3ce5f19f526c951898464677d807aa283550885dc66d06b1018b3007916d6257e9449043a234053391cd300")
+);
+
+pkcs8_tests!(
+    t1,
+    "examples/t1-pkcs8-private-key.der",
+    "examples/t1-pkcs8-public-key.der",
+    "examples/t1-pkcs8-private-key.pem",
+    "examples/t1-pkcs8-public-key.pem",
+    hex!("46f332907e4cb6d8cd81782d23e51616c1bfc2bdee74da2dc44d47c6deb19109a884ca8bd3c494c5399f272e85a07f8c"),
+    hex!("046be85778415698512614bdd792ee01b74bd17f46ec79b0859db8eeded57d054142dba39738e20d7a6448cf725b7c45806da00ca7122534464184de6af1374b11f7d35e63b502e44ada29931c47a1e656466cbd672712f1ef1bcb04c7466abbca")
+);
@@ -0,0 +1,162 @@
+//! brainpoolP384r1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp384::{
+    r1::{AffinePoint, EncodedPoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::{prime::PrimeCurveAffine, Group},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
+    "04 1d1c64f0 68cf45ff a2a63a81 b7c13f6b 8847a3e7 7ef14fe3 db7fcafe 0cbd10e8 e826e034 36d646aa ef87b2e2 47d4af1e
+        8abe1d75 20f9c2a4 5cb1eb8e 95cfd552 62b70b29 feec5864 e19c054f f9912928 0e464621 77918111 42820341 263c5315"
+);
+
+const COMPRESSED_BASEPOINT: &[u8] =
+    &hex!("03 1d1c64f0 68cf45ff a2a63a81 b7c13f6b 8847a3e7 7ef14fe3 db7fcafe 0cbd10e8 e826e034 36d646aa ef87b2e2 47d4af1e");
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+const MUL_TEST_VECTORS: &[([u8; 48], [u8; 97])] = &[
+    (
+        hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 2282bc382a2f4dfcb95c3495d7b4fd590ad520b3eb6be4d6ec2f80c4e0f70df87c4ba74a09b553ebb427b58df9d59fca 0edda83773ac68735768d14a24f37a57ce9bedbc170921ce4d89dd051728fc3eb4b4ea69ab64fc288f1b29502b6e1d30"),
+    ),
+    (
+        hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 7b63205bf00ddae73b17452b6a27ebf53df581348c6949f83ee1b6fcc7463bbe3c11ef6596a3b8897d7cc85b3035f11f 761d3a4a5f8093775521a326bc02baaf7b2eb481ead16a5c7b2bd39462363e0373c0edaea3b8f59381d7129d48772eb3"),
+    ),
+    // RFC 7027 Appendix A.2
+    (
+        hex!("1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042"),
+        hex!("04 68b665dd91c195800650cdd363c625f4e742e8134667b767b1b476793588f885ab698c852d4a6e77a252d6380fcaf068 55bc91a39c9ec01dee36017b7d673a931236d2f1f5c83942d049e3fa20607493e0d038ff2fd30c2ab67d15c85f7faa59"),
+    ),
+    (
+        hex!("032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670"),
+        hex!("04 4d44326f269a597a5b58bba565da5556ed7fd9a8a9eb76c25f46db69d19dc8ce6ad18e404b15738b2086df37e71d1eb4 62d692136de56cbe93bf5fa3188ef58bc8a3a0ec6c1e151a21038a42e9185329b5b275903d192f8d4e1f32fe9cc78c48"),
+    ),
+    // n - 1
+    (
+        hex!("8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046564"),
+        hex!("04 1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e 01fb010d823eaa83b2ab83efbb166c8cb27865dfee67fe4f3115d4c98625e7fb9e8d6108188b996044c4fcd20acb993e"),
+    ),
+];
+
+#[test]
+fn uncompressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(false);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn compressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(true);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn uncompressed_to_compressed() {
+    let encoded = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(true);
+    assert_eq!(res.as_bytes(), COMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn compressed_to_uncompressed() {
+    let encoded = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(false);
+    assert_eq!(res.as_bytes(), UNCOMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    let mut bytes = [0u8; 97];
+    bytes.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    bytes[96] ^= 1;
+
+    let pubkey = EncodedPoint::from_bytes(bytes).unwrap();
+    assert!(bool::from(
+        AffinePoint::from_encoded_point(&pubkey).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(point.to_encoded_point(false).as_bytes(), &expected[..]);
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(public_key.to_encoded_point(false).as_bytes(), &expected[..]);
+    assert_eq!(
+        PublicKey::from_sec1_bytes(&expected[..]).unwrap(),
+        public_key
+    );
+}
+
+#[cfg(feature = "jwk")]
+#[test]
+fn jwk_round_trip() {
+    let (k, _) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+
+    let jwk = secret_key.to_jwk();
+    assert_eq!(jwk.crv(), "BP-384");
+    assert_eq!(
+        SecretKey::from_jwk(&jwk).unwrap().to_be_bytes(),
+        secret_key.to_be_bytes()
+    );
+
+    let public_key = secret_key.public_key();
+    let jwk_str = public_key.to_jwk_string();
+    assert_eq!(PublicKey::from_jwk_str(&jwk_str).unwrap(), public_key);
+}
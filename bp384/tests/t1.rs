@@ -0,0 +1,144 @@
+//! brainpoolP384t1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp384::{
+    t1::{AffinePoint, EncodedPoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::{prime::PrimeCurveAffine, Group},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: &[u8] = &hex!(
+    "04 18de98b0 2db9a306 f2afcd72 35f72a81 9b80ab12 ebd65317 2476fecd 462aabff c4ff191b 946a5f54 d8d0aa2f 418808cc
+        25ab0569 62d30651 a114afd2 755ad336 747f9347 5b7a1fca 3b88f2b6 a208ccfe 46940858 4dc2b291 2675bf5b 9e582928"
+);
+
+const COMPRESSED_BASEPOINT: &[u8] =
+    &hex!("02 18de98b0 2db9a306 f2afcd72 35f72a81 9b80ab12 ebd65317 2476fecd 462aabff c4ff191b 946a5f54 d8d0aa2f 418808cc");
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+const MUL_TEST_VECTORS: &[([u8; 48], [u8; 97])] = &[
+    (
+        hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 0b2d196565eacb7879a12eca76e23c6a82036bde5aeea2f27227f117b42cefbd6480396ad5b3e734e93dd1f3fcce7b80 0a7239b0822cebf205ed4f5a8dc4ef53729f32065e7120773ea1e06e9032c9093b632518e5e8aa82137e16078f93d782"),
+    ),
+    (
+        hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 03e7e83b88ba8d99a004f1c92ee361648a922f773f96d64b2bb66d1f3c0eeac30485cfef216f68b596b8861fbc005ea9 298585e0c24722037f09dd015c2fefeec87058d76a07fe43ed52e8641b7248d2c8baba631d9d68adc2bd7e748c753c0d"),
+    ),
+    // RFC 7027 Appendix A.2 private keys
+    (
+        hex!("1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042"),
+        hex!("04 30883b5f8686f6be6c2f2fdd1d1ad997429887208451fc1f87bdb59c98c12de42dfe6872bf1a987a0e4e74ac54101b62 07a9529d9a26174106ca47d66b56696a89c48f54f9489e1dc8c40fbe97e833a104f12b55a728d08e018af2e784c32fcd"),
+    ),
+    (
+        hex!("032640bc6003c59260f7250c3db58ce647f98e1260acce4acda3dd869f74e01f8ba5e0324309db6a9831497abac96670"),
+        hex!("04 346abd196a03555941cfa9cd927d2e5a38a879f22f21ea073b05e9020da8d1270d353e0066492be2009ee84a643036e5 456634266f22aaa0db788c833ad931ca89467aacd700002644b6f49f6cb4a92396d5194692ce03555ceb443f632f5ab5"),
+    ),
+    // n - 1
+    (
+        hex!("8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046564"),
+        hex!("04 18de98b02db9a306f2afcd7235f72a819b80ab12ebd653172476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc 670e1919406566d66e48bfabdb8b6ea8a0afddc291da36e9d728e762ddae4425663f9ed1425a67e060d140b792afc32b"),
+    ),
+];
+
+#[test]
+fn uncompressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(false);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn compressed_round_trip() {
+    let pubkey = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
+    assert_eq!(point, AffinePoint::generator());
+
+    let res: EncodedPoint = point.to_encoded_point(true);
+    assert_eq!(res, pubkey);
+}
+
+#[test]
+fn uncompressed_to_compressed() {
+    let encoded = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(true);
+    assert_eq!(res.as_bytes(), COMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn compressed_to_uncompressed() {
+    let encoded = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let res = AffinePoint::from_encoded_point(&encoded)
+        .unwrap()
+        .to_encoded_point(false);
+    assert_eq!(res.as_bytes(), UNCOMPRESSED_BASEPOINT);
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    let mut bytes = [0u8; 97];
+    bytes.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    bytes[96] ^= 1;
+
+    let pubkey = EncodedPoint::from_bytes(bytes).unwrap();
+    assert!(bool::from(
+        AffinePoint::from_encoded_point(&pubkey).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(point.to_encoded_point(false).as_bytes(), &expected[..]);
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(public_key.to_encoded_point(false).as_bytes(), &expected[..]);
+    assert_eq!(
+        PublicKey::from_sec1_bytes(&expected[..]).unwrap(),
+        public_key
+    );
+}
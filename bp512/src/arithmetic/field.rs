@@ -0,0 +1,208 @@
+//! Field arithmetic modulo
+//! p = 0xaadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3
+
+use super::FieldBytes;
+use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use elliptic_curve::{
+    bigint::{Word, U512},
+    ff::PrimeField,
+    subtle::{Choice, ConstantTimeEq, CtOption},
+};
+use weierstrass::montgomery;
+
+/// Constant representing the modulus
+/// p = 0xaadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3
+pub(crate) const MODULUS: U512 =
+    U512::from_be_hex("aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3");
+
+/// R^2 = 2^1024 mod p
+const R_2: U512 =
+    U512::from_be_hex("3c4c9d05a9ff6450202e19402056eecca16daa5fd42bff8319486fd8d5898057e0c19a7783514a2553b7f9bc905affd3793fb1302715790549ad144a6158f205");
+
+/// -p^{-1} mod 2^w, where w is the limb size in bits.
+const P_INV: Word = montgomery::neg_inv(MODULUS.as_words());
+
+/// Raw field element.
+type Fe = [Word; U512::LIMBS];
+
+/// An element in the finite field modulo
+/// p = 0xaadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3.
+///
+/// The internal representation is in little-endian order. Elements are always in
+/// Montgomery form; i.e., FieldElement(a) = aR mod p, with R = 2^512.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldElement(pub(crate) U512);
+
+weierstrass::impl_field_element!(
+    FieldElement,
+    FieldBytes,
+    U512,
+    MODULUS,
+    Fe,
+    fe_from_montgomery,
+    fe_to_montgomery,
+    fe_add,
+    fe_sub,
+    fe_mul,
+    fe_neg,
+    fe_square,
+    invert,
+    sqrt
+);
+
+impl FieldElement {
+    /// Parse the given byte array as an SEC1-encoded field element.
+    ///
+    /// Returns `None` if the byte array does not contain a big-endian integer in
+    /// the range `[0, p)`.
+    pub fn from_sec1(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Returns the SEC1 encoding of this field element.
+    pub fn to_sec1(self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self::from_uint_unchecked(U512::from(n))
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const NUM_BITS: u32 = 512;
+    const CAPACITY: u32 = 511;
+    const S: u32 = 1;
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn multiplicative_generator() -> Self {
+        // Smallest quadratic non-residue which isn't a `q`-th power residue
+        // for any of the known small prime factors `q` of p - 1. The large
+        // cofactor of p - 1 hasn't been factored, so this can't be confirmed
+        // to be a primitive root.
+        2.into()
+    }
+
+    fn root_of_unity() -> Self {
+        Self::from_be_hex("aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f2")
+    }
+}
+
+const fn fe_from_montgomery(w: &Fe) -> Fe {
+    montgomery::from_montgomery(w, MODULUS.as_words(), P_INV)
+}
+
+const fn fe_to_montgomery(w: &Fe) -> Fe {
+    montgomery::to_montgomery(w, R_2.as_words(), MODULUS.as_words(), P_INV)
+}
+
+const fn fe_add(a: &Fe, b: &Fe) -> Fe {
+    montgomery::add(a, b, MODULUS.as_words())
+}
+
+const fn fe_sub(a: &Fe, b: &Fe) -> Fe {
+    montgomery::sub(a, b, MODULUS.as_words())
+}
+
+const fn fe_mul(a: &Fe, b: &Fe) -> Fe {
+    montgomery::mul(a, b, MODULUS.as_words(), P_INV)
+}
+
+const fn fe_neg(w: &Fe) -> Fe {
+    montgomery::neg(w, MODULUS.as_words())
+}
+
+const fn fe_square(w: &Fe) -> Fe {
+    montgomery::square(w, MODULUS.as_words(), P_INV)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, MODULUS};
+    use elliptic_curve::{bigint::ArrayEncoding, ff::PrimeField};
+
+    #[test]
+    fn from_to_bytes_roundtrip() {
+        let mut bytes = super::FieldBytes::default();
+        bytes[56..].copy_from_slice(&0x0123_4567_89ab_cdefu64.to_be_bytes());
+
+        let fe = FieldElement::from_repr(bytes).unwrap();
+        assert_eq!(bytes, fe.to_repr());
+        assert_eq!(fe, FieldElement::from(0x0123_4567_89ab_cdef));
+    }
+
+    #[test]
+    fn overflow_rejected() {
+        assert!(bool::from(
+            FieldElement::from_repr(MODULUS.to_be_byte_array()).is_none()
+        ));
+    }
+
+    /// Basic tests that multiplication works.
+    #[test]
+    fn multiply() {
+        let one = FieldElement::ONE;
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
+
+        let minus_two = -two;
+        let minus_three = -three;
+        assert_eq!(two, -minus_two);
+        assert_eq!(six, minus_two * minus_three);
+        assert_eq!(minus_two + two, FieldElement::ZERO);
+    }
+
+    /// Basic tests that field inversion works.
+    #[test]
+    fn invert() {
+        let one = FieldElement::ONE;
+        assert_eq!(one.invert().unwrap(), one);
+
+        let three = one + one + one;
+        let inv_three = three.invert().unwrap();
+        assert_eq!(three * inv_three, one);
+
+        let minus_three = -three;
+        let inv_minus_three = minus_three.invert().unwrap();
+        assert_eq!(inv_minus_three, -inv_three);
+        assert_eq!(three * inv_minus_three, -one);
+
+        assert!(bool::from(FieldElement::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn sqrt() {
+        let one = FieldElement::ONE;
+        let two = one + one;
+        let four = two.square();
+        let sqrt = four.sqrt().unwrap();
+        assert!(sqrt == two || sqrt == -two);
+    }
+
+    #[test]
+    fn root_of_unity() {
+        // With S = 1 the root of unity is -1, which requires the generator to
+        // be a quadratic non-residue.
+        assert_eq!(FieldElement::root_of_unity(), -FieldElement::ONE);
+        assert!(bool::from(
+            FieldElement::multiplicative_generator().sqrt().is_none()
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+//! ## `serde` support
+//!
+//! When the `serde` feature of this crate is enabled, `Serialize` and
+//! `Deserialize` are impl'd for [`Scalar`].
+//!
+//! Points and public keys are serialized using their SEC1 encoding, which
+//! `elliptic-curve` doesn't support yet for 64-byte field elements, so they
+//! don't implement these traits.
+//!
+//! ## JWK support
+//!
+//! When the `jwk` feature of this crate is enabled, brainpoolP512r1 uses the
+//! `crv` name `BP-512`. There is no established `crv` name for
+//! brainpoolP512t1, so it doesn't support JWK.
+//!
+//! JWK encoding of keys is also based on SEC1, so it isn't available yet
+//! either.
+
+pub mod r1;
+pub mod t1;
+
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+pub use crate::{r1::BrainpoolP512r1, t1::BrainpoolP512t1};
+pub use elliptic_curve::{self, bigint::U512};
+
+#[cfg(feature = "arithmetic")]
+pub use crate::arithmetic::{scalar::Scalar, BlindedScalar};
+
+#[cfg(feature = "pkcs8")]
+pub use elliptic_curve::pkcs8;
@@ -0,0 +1,65 @@
+//! brainpoolP512r1 curve arithmetic.
+
+use super::BrainpoolP512r1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{AffineArithmetic, ProjectiveArithmetic, ScalarArithmetic};
+use weierstrass::WeierstrassCurve;
+
+/// brainpoolP512r1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP512r1>;
+
+/// brainpoolP512r1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP512r1>;
+
+impl WeierstrassCurve for BrainpoolP512r1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = 7830a331 8b603b89 e2327145 ac234cc5 94cbdd8d 3df91610 a83441ca ea9863bc
+    ///     2ded5d5a a8253aa1 0a2ef1c9 8b9ac8b5 7f1117a7 2bf2c7b9 e7c1ac4d 77fc94ca
+    const EQUATION_A: FieldElement = FieldElement::from_be_hex(
+        "7830a3318b603b89e2327145ac234cc594cbdd8d3df91610a83441caea9863bc2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a72bf2c7b9e7c1ac4d77fc94ca",
+    );
+
+    /// b = 3df91610 a83441ca ea9863bc 2ded5d5a a8253aa1 0a2ef1c9 8b9ac8b5 7f1117a7
+    ///     2bf2c7b9 e7c1ac4d 77fc94ca dc083e67 984050b7 5ebae5dd 2809bd63 8016f723
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "3df91610a83441caea9863bc2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a72bf2c7b9e7c1ac4d77fc94cadc083e67984050b75ebae5dd2809bd638016f723",
+    );
+
+    /// Base point of brainpoolP512r1.
+    ///
+    /// Defined in RFC 5639 § 3.7:
+    ///
+    /// ```text
+    /// Gₓ = 81aee4bd d82ed964 5a21322e 9c4c6a93 85ed9f70 b5d916c1 b43b62ee f4d0098e
+    ///      ff3b1f78 e2d0d48d 50d1687b 93b97d5f 7c6d5047 406a5e68 8b352209 bcb9f822
+    /// Gᵧ = 7dde385d 566332ec c0eabfa9 cf7822fd f209f700 24a57b1a a000c55b 881f8111
+    ///      b2dcde49 4a5f485e 5bca4bd8 8a2763ae d1ca2b2f a8f05406 78cd1e0f 3ad80892
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "81aee4bdd82ed9645a21322e9c4c6a9385ed9f70b5d916c1b43b62eef4d0098eff3b1f78e2d0d48d50d1687b93b97d5f7c6d5047406a5e688b352209bcb9f822",
+        ),
+        FieldElement::from_be_hex(
+            "7dde385d566332ecc0eabfa9cf7822fdf209f70024a57b1aa000c55b881f8111b2dcde494a5f485e5bca4bd88a2763aed1ca2b2fa8f0540678cd1e0f3ad80892",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP512r1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP512r1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP512r1 {
+    type Scalar = Scalar;
+}
@@ -0,0 +1,99 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP512r1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp512::r1::ecdh::EphemeralSecret;
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_public = alice_secret.public_key();
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_public = bob_secret.public_key();
+//!
+//! // Alice computes a shared secret from Bob's public key
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob computes the same shared secret from Alice's public key
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+//!
+//! Note that public keys can't be serialized as SEC1 points yet, as the
+//! `sec1` crate doesn't support 64-byte field elements.
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP512r1;
+
+/// brainpoolP512r1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP512r1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP512r1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::r1::SecretKey;
+    use elliptic_curve::AffineXCoordinate;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Test vector from RFC 7027 Appendix A.3
+    #[test]
+    fn rfc7027() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!("16302ff0dbbb5a8d733dab7141c1b45acbc8715939677f6a56850a38bd87bd59b09e80279609ff333eb9d4c061231fb26f92eeb04982a5f1d1764cad57665422")).unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!("230e18e1bcc88a362fa54e4ea3902009292f7f8033624fd471b5d8ace49d12cfabbc19963dab8e2f1eba00bffb29e4d72d13f2224562f405cb80503666b25429")).unwrap();
+        let alice_public = alice_secret.public_key();
+        assert_eq!(alice_public.as_affine().x().as_slice(), &hex!("0a420517e406aac0acdce90fcd71487718d3b953efd7fbec5f7f27e28c6149999397e91e029e06457db2d3e640668b392c2a7e737a7f0bf04436d11640fd09fd"));
+        let bob_public = bob_secret.public_key();
+        assert_eq!(bob_public.as_affine().x().as_slice(), &hex!("9d45f66de5d67e2e6db6e93a59ce0bb48106097ff78a081de781cdb31fce8ccbaaea8dd4320c4119f1e9cd437a2eab3731fa9668ab268d871deda55a5473199f"));
+
+        let expected = hex!("a7927098655f1f9976fa50a9d566865dc530331846381c87256baf3226244b76d36403c024d7bbf0aa0803eaff405d3d24f11a9b5c0bef679fe1454b21c4cd1f");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+}
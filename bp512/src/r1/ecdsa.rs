@@ -0,0 +1,264 @@
+//! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP512r1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP512r1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha512` feature.
+
+pub use super::BrainpoolP512r1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U512},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
+
+/// ECDSA/brainpoolP512r1 signature (fixed-size)
+pub type Signature = ecdsa::Signature<BrainpoolP512r1>;
+
+/// ECDSA/brainpoolP512r1 signature (ASN.1 DER encoded)
+pub type DerSignature = ecdsa::der::Signature<BrainpoolP512r1>;
+
+/// ECDSA/brainpoolP512r1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP512r1>;
+
+/// ECDSA/brainpoolP512r1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP512r1>;
+
+#[cfg(feature = "sha512")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha512")))]
+impl ecdsa::hazmat::DigestPrimitive for BrainpoolP512r1 {
+    type Digest = sha2::Sha512;
+}
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP512r1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP512r1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP512r1>>,
+        U512: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP512r1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP512r1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP512r1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha512"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP512r1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-512 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "test" as its
+    /// SHA-512 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("0c3952063ab360b2241c315b277ec1f077e5d70d53bfd1b883016eba55d84e65e09e469cf31259a08698871ed23bd14cf74c6823741171cca7d02583e5a60c6e");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "61fbba32465adac2e0e653370b0e7ea576f2525af45265b42bfa669679f35fd7c010a5f53cf48087646462bd06d313ae3b607ee02eb2aab6f0b6f988e352b700
+                5267593efabb72bcc272a32e0f345e00025a3eb1d4527e3c83c6ca1e9eefed2e817dd5542217446ce48d0f0648fe689d4e1199a5245f22cfb15eb1c0d8f2ec9b"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "a22c943c5b9d3f442386e8ca0768274cd097d39723b1aee8a9285170ba944966b93d57443da40c8121eaa5f98bf0aaf82d2128b9b82c1d4f3cbf4ad3c947ed60
+                0c4dcee3f1817725caa84d14c4edd3aea6a61e5766f315d9534f2887d52a6dc3076fe56fed07fe737058f404369eed90916fd330cd858300fddfac6c202f5c65"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!("0c3952063ab360b2241c315b277ec1f077e5d70d53bfd1b883016eba55d84e65e09e469cf31259a08698871ed23bd14cf74c6823741171cca7d02583e5a60c6e")).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP512r1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-512 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("730370e36d79ad5544c9d81778ab3297869757240ca146358cb2caced7409e7c5caf5ff9e787967df7c73f6146a7f5f68ccf74173d71d9a82fa15e1ebd84dd49"),
+            q_x: &hex!("1c1d9e499821d43a8e87a2a629d92f44ec5b61e16d823e1ce61e21d1f8d5e874e1a6ca62f03c5f48b1b1d960e13c0b96ec0cefdfb063e04cffa354220c63dddf"),
+            q_y: &hex!("92982795e9d53dab08c4c2760ae6cc2f591953fa6987a3f7b9604588851e4bb6034c3798729c6a74de478ed0521cdb8777f43bbefa770eaf00204c1b4007204a"),
+            k: &hex!("15876c5bc4b08cfe6cd5cba2d6538c0b1d7c6bb7c40a4a46cb44d267f5cfe9d4b701765fdfc144ad2a331357b612c966517345783a2860b2a8638e6fc6b7a0a9"),
+            m: &hex!("3e97b2265fcbc8edb14c520d2ea6954832f6dffd14485202e3bbde7b9548ac6d1b215e7540818be8af8c57d1dee79080e8b923a08f23f9cd39e4fc610b878aca"),
+            r: &hex!("18f324a8421e228289bf4135659de5543dd8d22e28a6e241cc9de9605b41d2e5f03184a580c3c1e184b54e624fd614904a7c3537c3136f6ca8feac4c687768d0"),
+            s: &hex!("4beee28cf42c108e2ce04f7b5890dc0afc2bc269c6ee0f86083611dcc6c2f5c6e8e9df806af3abc9fbcf4c18ae30598b3f22b14c306fb191f0d6686a38c22b8f"),
+        },
+        TestVector {
+            d: &hex!("74fc8c27a2ff8c752ff0c9667fd5883c6bec40eb9a1e0ac49ca1ba16ab3228ac0d5247d95fba86f225ba16d29e61aeddfe10405ce330172d19a9f1a8afba7a5a"),
+            q_x: &hex!("19b07e2a6dc0fff39083c2a5beb6d3974d15336039c0cbaeb2262707e9ec4826ef8e4812a5e02c2ba64a50470ff7ee477577ea02609055a9a2d5dd2790c25873"),
+            q_y: &hex!("142714d4531e5cdcb599db3fe3555fd7667f2576955165f5d017fc08e707ba6e5b519eeed84a5371cdcd78c582ac962a9aeb762aed599ab58fa5abaec57dba3f"),
+            k: &hex!("6e7deac5934d135ca39d11056175842e7a2b38eb83de9629e14065eee94c19a35aeccaccb3b2e5218f4bcd8ecea7d28aeb32dc9397345f9ccdb220f203c9da5b"),
+            m: &hex!("c5f6fff3e104609d0f97bab32afa3173a10ce8a23e2433f664df95ffc07737088452de0da1f6935d84e3b5a5c5f1632f29cae485ed194e1c75248e6820e809ad"),
+            r: &hex!("9d5e5f9eb96e49d2b5e84ecb9cbe2c3c7c79b31bc6a862dc2d65c1c1338b7e0bedac67ad8eabd0971a5525506ced74a7ec444e958404c867c8178e88f89797b7"),
+            s: &hex!("51d0d632cb35d53ca77a6065196936c8995b0083f4f8db28ad07709949e1820a5a3dd38c7432e612aaac0b4fbd94f52ee77e5e90711f3ca911215e0bc15bdf4f"),
+        },
+        TestVector {
+            d: &hex!("7aa16a68bccab6ab90b7cc183538894c0b22e311a0cf7f8e726ad121ca41fba5c65b7655d8ef4b9a9073d6551b6700b3a2da2255b5b31ed4102edad323c47c13"),
+            q_x: &hex!("0470f24b7cbe7c5d0dedaf4b73b0d0db78f78cba182d52c03daad6e6c13a7520bdffaea58665da79bb0c294eefd7b8a3d2e027ddf9c08fad10469dec1d3ffd67"),
+            q_y: &hex!("5f5374207eb786a5b56fe0484ce7ec201ed14a86812254e10350464725b259cc9810ba6a74f582f94a51f66dfb2f6c1298a1523efba2d0e93776cff2b6cf3a2b"),
+            k: &hex!("2e0f727b26deecb525c7b384b8369c23227d1eff94e53152cacf761c40c708d2d63951128b391dba8e185fa1ecd65d2c937917b368dc80bf660f4623e104d03a"),
+            m: &hex!("6fd512e0fb5ce54a77c42321e9162fab5c8b9484917e45c0acff3d98b2d7cdf403ef907050919d3363150e61f7ebcfc7b650f591219f05162af00ef4b8d91091"),
+            r: &hex!("8f36c3b0c3666f10e3e8e1b14d66063fecc6541b7b850a00e0a2f62c61fda755319298c473f785d133140b4f47967a422051e53363a314da50a51bbe4e353cd5"),
+            s: &hex!("802cc9b8010d7f5a1b41bbb33924bdbbc03515eb14a4de850cc903598644c9a9006f9f0ed2b85595ba4bc6c1eaa419936dfc4f34579ec386984a1ed871f32bae"),
+        },
+        TestVector {
+            d: &hex!("43607a4889a5b36af44b0c39c656a518ef0aa3d14e5543cc254bd2180501cffc1288350adbb0d0ad93567e893f12af18ccd5f18b704ecafbdf6ab3d23ed6546b"),
+            q_x: &hex!("5d8d1e13c3349772aa2cfea9d834505bfef69c7480e9be33d4b38945e28717bc4d6a218b5cf93a66c14206dc5c328e598ccc9673bd64846a59bff9881af11d87"),
+            q_y: &hex!("867e1071a7fa01f6cf5243c62ef63c5e8908e200b3ede606b7e28ad6c558b981656f41d8832d4dc23a4e94c40715e5194267640d9ada8c3a72f753c5c46b0b42"),
+            k: &hex!("47f333aef89a52a016ac4f00422a50dc61ce2545144b09125a0a472aa378cb8925bce4a616a0665183b532dd3018a227c9558d05f0f680aa42c77ed5d99958c3"),
+            m: &hex!("89bb3244e97a6f79c4b53327fbf525bee68cba9eb5b9102bd38034e9853b253597065e49e1bba0022cd893c861fb41cf138a6feb9cf75ec1753f689fc1c1f750"),
+            r: &hex!("56ad6c0169752c08badd754f4d3cbdff941467bba4e71dcbb64e32017b1769896ec76891144fcedf0c78ea44596fffa35eb245ce75511b84fde8d278d29e6a16"),
+            s: &hex!("777285a49fcc977235e3ad3f57b8bae115667595b1aa07ccc0db9125b0b7f9b991c70662a56a5704310e8f4be212b838de437686c087f12060ef78ba02813506"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP512t1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP512r1, ECDSA_TEST_VECTORS};
+        use crate::{r1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP512r1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    /// `ecdsa::new_verification_test!` can't be used here as it decodes
+    /// `q` from SEC1, which isn't supported for 64-byte field elements.
+    mod verify {
+        use super::ECDSA_TEST_VECTORS;
+        use crate::r1::{ecdsa::Signature, AffinePoint, FieldBytes};
+        use ecdsa::{
+            elliptic_curve::{subtle::Choice, DecompressPoint},
+            hazmat::VerifyPrimitive,
+        };
+
+        fn decode_q(q_x: &[u8], q_y: &[u8]) -> AffinePoint {
+            let y_is_odd = Choice::from(q_y[q_y.len() - 1] & 1);
+            AffinePoint::decompress(&FieldBytes::clone_from_slice(q_x), y_is_odd).unwrap()
+        }
+
+        #[test]
+        fn ecdsa_verify_success() {
+            for vector in ECDSA_TEST_VECTORS {
+                let q = decode_q(vector.q_x, vector.q_y);
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = Signature::from_scalars(
+                    FieldBytes::clone_from_slice(vector.r),
+                    FieldBytes::clone_from_slice(vector.s),
+                )
+                .unwrap();
+
+                assert!(q.verify_prehashed(z, &sig).is_ok());
+            }
+        }
+
+        #[test]
+        fn ecdsa_verify_invalid_s() {
+            for vector in ECDSA_TEST_VECTORS {
+                let q = decode_q(vector.q_x, vector.q_y);
+                let z = FieldBytes::clone_from_slice(vector.m);
+
+                // Flip a bit in `s`
+                let mut s_tweaked = FieldBytes::clone_from_slice(vector.s);
+                s_tweaked[0] ^= 1;
+
+                let sig =
+                    Signature::from_scalars(FieldBytes::clone_from_slice(vector.r), s_tweaked)
+                        .unwrap();
+
+                assert!(q.verify_prehashed(z, &sig).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! brainpoolP512t1 elliptic curve: twisted variant
+
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub mod ecdsa;
+
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "arithmetic")]
+pub use self::arithmetic::{AffinePoint, ProjectivePoint};
+
+use elliptic_curve::bigint::U512;
+
+#[cfg(feature = "pkcs8")]
+use crate::pkcs8;
+
+/// brainpoolP512t1 elliptic curve: twisted variant
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BrainpoolP512t1;
+
+impl elliptic_curve::Curve for BrainpoolP512t1 {
+    /// 512-bit field modulus
+    type UInt = U512;
+
+    /// Curve order
+    const ORDER: U512 =
+        U512::from_be_hex("aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90069");
+}
+
+impl elliptic_curve::PrimeCurve for BrainpoolP512t1 {}
+
+impl elliptic_curve::PointCompression for BrainpoolP512t1 {
+    /// Brainpool points are typically uncompressed.
+    const COMPRESS_POINTS: bool = false;
+}
+
+impl elliptic_curve::PointCompaction for BrainpoolP512t1 {
+    /// Brainpool points are typically uncompressed.
+    const COMPACT_POINTS: bool = false;
+}
+
+#[cfg(feature = "pkcs8")]
+impl pkcs8::AssociatedOid for BrainpoolP512t1 {
+    const OID: pkcs8::ObjectIdentifier =
+        pkcs8::ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.14");
+}
+
+/// brainpoolP512t1 field element serialized as bytes.
+///
+/// Byte array containing a serialized field element value (base field or scalar).
+pub type FieldBytes = elliptic_curve::FieldBytes<BrainpoolP512t1>;
+
+/// Non-zero brainpoolP512t1 scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP512t1>;
+
+/// brainpoolP512t1 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP512t1>;
+
+/// brainpoolP512t1 secret key.
+pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP512t1>;
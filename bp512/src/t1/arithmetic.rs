@@ -0,0 +1,66 @@
+//! brainpoolP512t1 curve arithmetic.
+
+use super::BrainpoolP512t1;
+use crate::{arithmetic::field::FieldElement, Scalar};
+use elliptic_curve::{AffineArithmetic, ProjectiveArithmetic, ScalarArithmetic};
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+/// brainpoolP512t1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<BrainpoolP512t1>;
+
+/// brainpoolP512t1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<BrainpoolP512t1>;
+
+impl WeierstrassCurve for BrainpoolP512t1 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = -3 (0xaadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f0)
+    const EQUATION_A: FieldElement = FieldElement::ZERO
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
+
+    /// b = 7cbbbcf9 441cfab7 6e1890e4 6884eae3 21f70c0b cb498152 7897504b ec3e36a6
+    ///     2bcdfa23 04976540 f6450085 f2dae145 c22553b4 65763689 180ea257 1867423e
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "7cbbbcf9441cfab76e1890e46884eae321f70c0bcb4981527897504bec3e36a62bcdfa2304976540f6450085f2dae145c22553b465763689180ea2571867423e",
+    );
+
+    /// Base point of brainpoolP512t1.
+    ///
+    /// Defined in RFC 5639 § 3.7:
+    ///
+    /// ```text
+    /// Gₓ = 640ece5c 12788717 b9c1ba06 cbc2a6fe ba858424 58c56dde 9db1758d 39c0313d
+    ///      82ba5173 5cdb3ea4 99aa77a7 d6943a64 f7a3f25f e26f06b5 1baa2696 fa9035da
+    /// Gᵧ = 5b534bd5 95f5af0f a2c89237 6c84ace1 bb4e3019 b71634c0 1131159c ae03cee9
+    ///      d9932184 beef216b d71df2da df86a627 306ecff9 6dbb8bac e198b61e 00f8b332
+    /// ```
+    ///
+    /// NOTE: coordinate field elements have been translated into the Montgomery
+    /// domain.
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex(
+            "640ece5c12788717b9c1ba06cbc2a6feba85842458c56dde9db1758d39c0313d82ba51735cdb3ea499aa77a7d6943a64f7a3f25fe26f06b51baa2696fa9035da",
+        ),
+        FieldElement::from_be_hex(
+            "5b534bd595f5af0fa2c892376c84ace1bb4e3019b71634c01131159cae03cee9d9932184beef216bd71df2dadf86a627306ecff96dbb8bace198b61e00f8b332",
+        ),
+    );
+}
+
+impl AffineArithmetic for BrainpoolP512t1 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for BrainpoolP512t1 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl ScalarArithmetic for BrainpoolP512t1 {
+    type Scalar = Scalar;
+}
@@ -0,0 +1,101 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP512t1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp512::t1::ecdh::EphemeralSecret;
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_public = alice_secret.public_key();
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_public = bob_secret.public_key();
+//!
+//! // Alice computes a shared secret from Bob's public key
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob computes the same shared secret from Alice's public key
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+//!
+//! Note that public keys can't be serialized as SEC1 points yet, as the
+//! `sec1` crate doesn't support 64-byte field elements.
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
+
+use super::BrainpoolP512t1;
+
+/// brainpoolP512t1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<BrainpoolP512t1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP512t1>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::t1::SecretKey;
+    use elliptic_curve::AffineXCoordinate;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// The RFC 7027 Appendix A.3 private keys on brainpoolP512t1.
+    ///
+    /// The shared secret was cross-checked with OpenSSL.
+    #[test]
+    fn known_answer() {
+        let alice_secret = SecretKey::from_be_bytes(&hex!("16302ff0dbbb5a8d733dab7141c1b45acbc8715939677f6a56850a38bd87bd59b09e80279609ff333eb9d4c061231fb26f92eeb04982a5f1d1764cad57665422")).unwrap();
+        let bob_secret = SecretKey::from_be_bytes(&hex!("230e18e1bcc88a362fa54e4ea3902009292f7f8033624fd471b5d8ace49d12cfabbc19963dab8e2f1eba00bffb29e4d72d13f2224562f405cb80503666b25429")).unwrap();
+        let alice_public = alice_secret.public_key();
+        assert_eq!(alice_public.as_affine().x().as_slice(), &hex!("7047cd66d78504b0d68ddb0e1332ffa5531cf75573d4597a49ef681ca623703894144cdf91810e4953098e35fc0cafd035a323abf102ff495c3bc9f5558a1168"));
+        let bob_public = bob_secret.public_key();
+        assert_eq!(bob_public.as_affine().x().as_slice(), &hex!("4abc9477ab47fc42835efd607720f148a6008042619bec79c1dab3a321b7d910a8f0d63340c13ca8e436ce4f1fa55ddf14822477be9f5da8f11b172a90ff9b9f"));
+
+        let expected = hex!("1ee7ec58878d26b0b28ac2d846d0780d431e6a340e5dbe517cdbdd718909c782daeecba8493b194b3e51172a7ad793541171a13247c77d2bcefecaa8d09f4e3f");
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected);
+
+        let hardened = SharedSecret::diffie_hellman_hardened(
+            alice_secret.to_nonzero_scalar(),
+            bob_public.as_affine(),
+            &mut OsRng,
+        );
+        assert_eq!(hardened.raw_secret_bytes().as_slice(), &expected);
+    }
+
+    #[test]
+    fn shared_secret_agreement() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman(&alice_secret.public_key());
+
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let bob_hardened =
+            bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_hardened.raw_secret_bytes()
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! It requires the `ecdsa` Cargo feature, which provides the [`Signature`] type
+//! (representing an ECDSA/brainpoolP512t1 signature) on its own.
+//!
+//! Enabling the `arithmetic` feature as well provides the [`SigningKey`] and
+//! [`VerifyingKey`] types which natively implement ECDSA/brainpoolP512t1
+//! signing and verification. Their `Signer` and `Verifier` impls for messages
+//! also need the `sha512` feature.
+
+pub use super::BrainpoolP512t1;
+pub use ecdsa::signature::{self, Error};
+
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "arithmetic")]
+use {
+    super::{AffinePoint, FieldBytes},
+    crate::{Scalar, U512},
+    ecdsa::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{FieldSize, ScalarCore},
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
+};
+
+/// ECDSA/brainpoolP512t1 signature (fixed-size)
+pub type Signature = ecdsa::Signature<BrainpoolP512t1>;
+
+/// ECDSA/brainpoolP512t1 signature (ASN.1 DER encoded)
+pub type DerSignature = ecdsa::der::Signature<BrainpoolP512t1>;
+
+/// ECDSA/brainpoolP512t1 signing key
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type SigningKey = ecdsa::SigningKey<BrainpoolP512t1>;
+
+/// ECDSA/brainpoolP512t1 verification key (i.e. public key)
+#[cfg(feature = "arithmetic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub type VerifyingKey = ecdsa::VerifyingKey<BrainpoolP512t1>;
+
+#[cfg(feature = "sha512")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha512")))]
+impl ecdsa::hazmat::DigestPrimitive for BrainpoolP512t1 {
+    type Digest = sha2::Sha512;
+}
+
+#[cfg(feature = "arithmetic")]
+impl Rfc6979Curve for BrainpoolP512t1 {
+    const REDUCE_PREHASH: bool = true;
+}
+
+#[cfg(feature = "arithmetic")]
+impl SignPrimitive<BrainpoolP512t1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<BrainpoolP512t1>>,
+        U512: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<BrainpoolP512t1>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<BrainpoolP512t1, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl VerifyPrimitive<BrainpoolP512t1> for AffinePoint {}
+
+#[cfg(all(test, feature = "arithmetic", feature = "sha512"))]
+mod tests {
+    use super::{
+        signature::{hazmat::PrehashSigner, Signer, Verifier},
+        BrainpoolP512t1, HardenedSigner, Signature, SigningKey, VerifyingKey,
+    };
+    use ecdsa::dev::TestVector;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Deterministic signatures over the RFC 6979 § A.2 test messages using
+    /// SHA-512 and the nonce generation procedure from RFC 6979 § 3.2.
+    ///
+    /// As required by RFC 6979 § 2.3.4, the digest is reduced modulo `n`
+    /// before deriving the nonce, which matters for "test" as its
+    /// SHA-512 digest is larger than `n`.
+    ///
+    /// These were computed with an independent RFC 6979 implementation and
+    /// cross-checked with OpenSSL.
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("86ea16cc5dbcfa01f3e4f7b334524ea365401a6d3c28afc7bde2fe9165cc1fc397a56082e54fdbf155454c6c83c26be760dc31d7f3c89049ffb049049a3f2631");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "694410e363938b100049225b85853dbd028aa98acd92ba517da2c3760c2beec11459957f379bc3846b0488062186d0db58cd1684ab46bb5ff364ded18344dd9d
+                40cd2d5f63e36ad975c2b1108b8be3704fb9564d4201b2f307d9279c0e332792be96f6fbb8eb23791a1c83b3b68108e232a98b581f8127b1678d25a4cb4086f3"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"sample"), signature);
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "3d4e1d63ea814879607bc355824b1243c71a0ed92e4ab9762001f25da874bf8a21d19301b9a28f0d8a85bff95779d60a4575efcaf9d87678dcab5faf156c81ea
+                3d9603bf0778d8d2acab7239544ad0fb184c42ff7de29e98fd3d3c15946412d7be1ee30cdc5fcab3ce0282ca16beca92a5bfec002f15a8e12fe791c0b81342ae"
+            )[..]
+        );
+        assert_eq!(signer.sign_hardened(&mut OsRng, b"test"), signature);
+    }
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&hex!("86ea16cc5dbcfa01f3e4f7b334524ea365401a6d3c28afc7bde2fe9165cc1fc397a56082e54fdbf155454c6c83c26be760dc31d7f3c89049ffb049049a3f2631")).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert!(verifying_key.verify(b"brainpool", &signature).is_ok());
+        assert!(verifying_key.verify(b"brainpoo1", &signature).is_err());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: Signature = signing_key.sign(b"brainpool");
+        assert_eq!(
+            signature,
+            signing_key.sign_hardened(&mut OsRng, b"brainpool")
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// ECDSA/brainpoolP512t1 test vectors with random keys, nonces, and messages.
+    ///
+    /// The `m` field contains the SHA-512 prehash of the message.
+    /// All signatures were cross-checked with OpenSSL.
+    const ECDSA_TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            d: &hex!("5e9e7c14a6ad7829c172735b9c92aef4860e5575b9badcd3e8fa0dd113f606947ef34974ec9aff21ef6333bec5b2b6d32025dfcbae974461f9c131d99fb890af"),
+            q_x: &hex!("28bda224b33e4fc19686a85a839d3735c32b73d45e0e3ed8264764cbd86637e20a31f99d4bbfcf923971c2f744b3a495f89a1da349ae03bf015c311c49b78fb8"),
+            q_y: &hex!("1bbe93a9049b8be6c6b9f9a36afdf3230f1141b731e6302ff105cb80fc966aa829c85f5c8db2e5612367676bac6b6896499f7bcc5673a1b749769a64bfcf8ef2"),
+            k: &hex!("99f93eec6cced7067ab388c45c42b3cf6b70767e499fee6eccb2c8b14b70b4d4f38f21806781d13652814c849b0c8dcaa864841d89ebd43041a784f7e421df66"),
+            m: &hex!("d03a23c463c96b54179a07fc1953f03008f1cd74ea99964174ec0e6c10e666fc01c8b9f9c1d09a0e1a5003ab5e470583b6c17fadb785da0b0f6294ed64203545"),
+            r: &hex!("989e0926341a4a888d198d248ef4b2cbcf9dd7132345d79e68e91294e098c38b17888641832a8c158b76ea7aaaafeec0e4500cd2b4349f5fb8f64b720c65abef"),
+            s: &hex!("2fe63269d186097a6684277c68665bde12e09a4e77354ca461787b7f8923ab8265172826875178cd75794c43e8614481aed5647046a72845f7b3afe583a02f3a"),
+        },
+        TestVector {
+            d: &hex!("a330ca9e312b3c171e9ec66e15162b3bf238427b3677bc72c028021a59ede90facd93b585fd90df183ef1eb5a08a1b25077033c840021221c19a2799d6d3a688"),
+            q_x: &hex!("9822fe1d896b7e41197c499faefd08c1ff704449287398ba61c87f8399c6efa57346a099ddd5f2af8aa25cd6feb88e286f1359fa4de44aca5c6f2804824e5498"),
+            q_y: &hex!("6b689ade78f86c896465c8d6dea5fe0ea86cc3fe69b8b6f4a073a52aae359636f1d79cad88829a1268556f73723f1e07408b63c02f929af7cfd4ab79b722973d"),
+            k: &hex!("1041649cacd98389e53622d87a6d64e193fac05c37d90297e28afc8c505875c5ade0b43fb9f6d35d3395a9d76340c64d30d79ea9355618e400f2f6c4a80719ae"),
+            m: &hex!("a8cf576bcb8912baa5a335c5940e2494d2cf908284c37f33039564e7b1dc1f7de1ed3ba9473f2a9fb804bdcb6399e8660af060f1e8072dba6d3d98854f88974c"),
+            r: &hex!("82d0a855a4967bd7668ac3b201b4cf7497f45d66666388b206a02102f3bc2b2174c492a14b6e2152425072c5f87e44f67de298cfb3e2c36c631dcdb548ba2f41"),
+            s: &hex!("520949be5e1ec343e31b66e008c78de06738d33cb4b9f4603e630186f70aaf1521acd9fb6664244d20f6ef997cd72e83945264a50c398cff6bc1e21abfb67f87"),
+        },
+        TestVector {
+            d: &hex!("324a5cc3454d6f3bf3013e1a017142f69cc8665b8b2c29fc1ad0eb74f2a81cce0306d3718d5f80e2a9d690104c7551f5ca1399183ca1f85c4fbdec67b825bb4d"),
+            q_x: &hex!("0f007dca1d800b440918ae1b6221653fd1ae968c53c98584dad47aa46dc8f710d8291324b2619d8bb4aa3d76920d97e9c3ae1e1d0d6a24ab8a4237dc0b56f617"),
+            q_y: &hex!("8292087b0a6a683678aa62cc406c029ce58d33e1a7397863f3d4d2c9d906b693ae6f0bfe4a04da20a4c31a8b5c3af4ece1a9f89ef842a5c4916924afcdef213f"),
+            k: &hex!("4d2c61f8562cb596a2a77b1d5bfa583fb7df35e172d5cc470e8fd2364bd6f10f8970dcd06197603de6ad7b56e79fa03fb74e50bcdf5f120ccfdb56e68e063b74"),
+            m: &hex!("f7a22b7ffe90fabc9752c0390f24695764f258436306ccd505088b54c86a87d16ce16e1020d10ded964d1a555021a63d71b20f8fe15634fe11701d385020477f"),
+            r: &hex!("620d9b860fc1ab49dad106f98d9671b949aa6c1a18923ec429b6a8f9f76c56f899557e00f14b21e0c6c7a3b184ca6ab6af075620f17881eb537e53f49a3c26bb"),
+            s: &hex!("a2a58b13c6bd21cbcc83805c728c750b40cb110f4a55b54541d3de0c49d5ecfb0a0b2c31259f8761bbf51c22a6651b714ffb93c11a53982f081ffcf0e91a729b"),
+        },
+        TestVector {
+            d: &hex!("8586e822f24cb5e5693e0cff7f0f42c86d345f946adbb8ac85f6ca848049aea6aa527a956c3595007d7253cf1daf729d903baa3e3a40a7c32d3b90e7421682b3"),
+            q_x: &hex!("0facc4131a2a7822b56b23d25cb237f4fc247f530743770611de84c70c03498f037a8058faabe9cb010f6cf42731d4a3fe03f8c160196fe6f7e1ef0d224aa958"),
+            q_y: &hex!("4d9fcbf518af59cc1837b4a88d6a4ab50a8abbcd6b3c237886e3cabfe037fc3fea288f52f281c7b7c37eeb92dc36123b4d41acde02f3a1285dec3d506bab938d"),
+            k: &hex!("079041228ae7ffcf5b83f902914219a76bdd73839bb2383699cc9c1c467b2a215d3935de8dd1cf2d03abf0631668bc4b35d9fee8b26df4c1787ebc24f3fba222"),
+            m: &hex!("537005974f71a4c67fdac71dd1a08fb77ea263b7543b5f265c284863a9b9d676b6ab75932d91d8bbc83a8b27309037e68963125fd0de45230feaf18c56c77466"),
+            r: &hex!("5a179e13d2ea6d0cd566d28ece26e74d7e24e8835586a14ee926331e5e5c6f32fa07e52ed8927cb7d2b280e6b52d13df3d8e8b57e71f86e4b4a7f54daac6a13a"),
+            s: &hex!("8c69fee29ce5e178f896bbb46b0e1f1a3935b7bdc9fabee24b214757c9969afb3ee23062bc6f396c1d65a9f63885d084b91548ddae71c77f1b46565f102043fe"),
+        },
+    ];
+
+    /// `ecdsa::new_signing_test!` can't be used here as [`Scalar`] is
+    /// shared with brainpoolP512r1, which makes `try_sign_prehashed` ambiguous.
+    ///
+    /// [`Scalar`]: crate::Scalar
+    mod sign {
+        use super::{BrainpoolP512t1, ECDSA_TEST_VECTORS};
+        use crate::{t1::FieldBytes, Scalar};
+        use ecdsa::{elliptic_curve::ff::PrimeField, hazmat::SignPrimitive};
+
+        #[test]
+        fn ecdsa_signing() {
+            for vector in ECDSA_TEST_VECTORS {
+                let d = Scalar::from_repr(FieldBytes::clone_from_slice(vector.d)).unwrap();
+                let k = Scalar::from_repr(FieldBytes::clone_from_slice(vector.k)).unwrap();
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = SignPrimitive::<BrainpoolP512t1>::try_sign_prehashed(&d, k, z)
+                    .expect("ECDSA sign failed")
+                    .0;
+
+                assert_eq!(vector.r, sig.r().to_repr().as_slice());
+                assert_eq!(vector.s, sig.s().to_repr().as_slice());
+            }
+        }
+    }
+
+    /// `ecdsa::new_verification_test!` can't be used here as it decodes
+    /// `q` from SEC1, which isn't supported for 64-byte field elements.
+    mod verify {
+        use super::ECDSA_TEST_VECTORS;
+        use crate::t1::{ecdsa::Signature, AffinePoint, FieldBytes};
+        use ecdsa::{
+            elliptic_curve::{subtle::Choice, DecompressPoint},
+            hazmat::VerifyPrimitive,
+        };
+
+        fn decode_q(q_x: &[u8], q_y: &[u8]) -> AffinePoint {
+            let y_is_odd = Choice::from(q_y[q_y.len() - 1] & 1);
+            AffinePoint::decompress(&FieldBytes::clone_from_slice(q_x), y_is_odd).unwrap()
+        }
+
+        #[test]
+        fn ecdsa_verify_success() {
+            for vector in ECDSA_TEST_VECTORS {
+                let q = decode_q(vector.q_x, vector.q_y);
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let sig = Signature::from_scalars(
+                    FieldBytes::clone_from_slice(vector.r),
+                    FieldBytes::clone_from_slice(vector.s),
+                )
+                .unwrap();
+
+                assert!(q.verify_prehashed(z, &sig).is_ok());
+            }
+        }
+
+        #[test]
+        fn ecdsa_verify_invalid_s() {
+            for vector in ECDSA_TEST_VECTORS {
+                let q = decode_q(vector.q_x, vector.q_y);
+                let z = FieldBytes::clone_from_slice(vector.m);
+
+                // Flip a bit in `s`
+                let mut s_tweaked = FieldBytes::clone_from_slice(vector.s);
+                s_tweaked[0] ^= 1;
+
+                let sig =
+                    Signature::from_scalars(FieldBytes::clone_from_slice(vector.r), s_tweaked)
+                        .unwrap();
+
+                assert!(q.verify_prehashed(z, &sig).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! brainpoolP512r1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp512::{
+    r1::{AffinePoint, FieldBytes, ProjectivePoint, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::Group, subtle::Choice, AffineXCoordinate, DecompressPoint, Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: [u8; 129] = hex!(
+    "04 81aee4bd d82ed964 5a21322e 9c4c6a93 85ed9f70 b5d916c1 b43b62ee f4d0098e
+        ff3b1f78 e2d0d48d 50d1687b 93b97d5f 7c6d5047 406a5e68 8b352209 bcb9f822
+        7dde385d 566332ec c0eabfa9 cf7822fd f209f700 24a57b1a a000c55b 881f8111
+        b2dcde49 4a5f485e 5bca4bd8 8a2763ae d1ca2b2f a8f05406 78cd1e0f 3ad80892"
+);
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+///
+/// Points are given in uncompressed SEC1 form.
+const MUL_TEST_VECTORS: &[([u8; 64], [u8; 129])] = &[
+    (
+        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 9f4945f680edf9800a63285758f399b3d18d8141b8a18064a30d3035f4cb6581957877f3a8f0f72597116e702915a4f4f698f404089a4cc5080447def02f4850 6d6b4b188b699c5649826b716292f29d149ce1238d3f1e0f5a2c366b03e5d1b2fdf99bb1709c700fa5c3b602b0960cbf63a42e4181fd929ce269ad21be592e71"),
+    ),
+    (
+        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 08dd87e12b0a4cc436cdd42543f20afe907c80ef3bc2459309c09cefd830151bc1f6fb975ceecade4780ae53e1853d62f56e34abfa9ac7205d4abf882ccb8d94 026ef5c6e1dab71d756ff0067376fa7543d903b4a6334c4bba0b382e1716d843acdab8eb772327b3febfcb69c0f37c5f8cce5bc75d8de6495cdeafba05b02c37"),
+    ),
+    // RFC 7027 Appendix A.3
+    (
+        hex!("16302ff0dbbb5a8d733dab7141c1b45acbc8715939677f6a56850a38bd87bd59b09e80279609ff333eb9d4c061231fb26f92eeb04982a5f1d1764cad57665422"),
+        hex!("04 0a420517e406aac0acdce90fcd71487718d3b953efd7fbec5f7f27e28c6149999397e91e029e06457db2d3e640668b392c2a7e737a7f0bf04436d11640fd09fd 72e6882e8db28aad36237cd25d580db23783961c8dc52dfa2ec138ad472a0fcef3887cf62b623b2a87de5c588301ea3e5fc269b373b60724f5e82a6ad147fde7"),
+    ),
+    (
+        hex!("230e18e1bcc88a362fa54e4ea3902009292f7f8033624fd471b5d8ace49d12cfabbc19963dab8e2f1eba00bffb29e4d72d13f2224562f405cb80503666b25429"),
+        hex!("04 9d45f66de5d67e2e6db6e93a59ce0bb48106097ff78a081de781cdb31fce8ccbaaea8dd4320c4119f1e9cd437a2eab3731fa9668ab268d871deda55a5473199f 2fdc313095bcdd5fb3a91636f07a959c8e86b5636a1e930e8396049cb481961d365cc11453a06c719835475b12cb52fc3c383bce35e27ef194512b71876285fa"),
+    ),
+    // n - 1
+    (
+        hex!("aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90068"),
+        hex!("04 81aee4bdd82ed9645a21322e9c4c6a9385ed9f70b5d916c1b43b62eef4d0098eff3b1f78e2d0d48d50d1687b93b97d5f7c6d5047406a5e688b352209bcb9f822 2cff655b8586919e7eea27046451d909d92696b38f2456f43662d76ee813875fca70bcb751671fe4530355525c7c1d3756b7d3ff8492727eafdd42471d624061"),
+    ),
+];
+
+/// Decode an uncompressed SEC1 point by decompressing its x-coordinate.
+///
+/// The `sec1` crate doesn't support 64-byte field elements, so
+/// `FromEncodedPoint` isn't available for this curve.
+fn decode_point(bytes: &[u8; 129]) -> Option<AffinePoint> {
+    assert_eq!(bytes[0], 0x04);
+    let x = FieldBytes::clone_from_slice(&bytes[1..65]);
+    let y_is_odd = Choice::from(bytes[128] & 1);
+    let point = Option::<AffinePoint>::from(AffinePoint::decompress(&x, y_is_odd))?;
+    assert_eq!(point.x(), x);
+    Some(point)
+}
+
+#[test]
+fn generator_decompression() {
+    let point = decode_point(&UNCOMPRESSED_BASEPOINT).unwrap();
+    assert_eq!(point, AffinePoint::GENERATOR);
+}
+
+#[test]
+fn off_curve_x_rejected() {
+    // `b` is a quadratic non-residue, so there's no point with `x = 0`
+    let x = FieldBytes::default();
+    assert!(bool::from(
+        AffinePoint::decompress(&x, Choice::from(0)).is_none()
+    ));
+    assert!(bool::from(
+        AffinePoint::decompress(&x, Choice::from(1)).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(Some(point), decode_point(expected));
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(Some(*public_key.as_affine()), decode_point(expected));
+}
@@ -0,0 +1,120 @@
+//! brainpoolP512t1 arithmetic tests.
+
+#![cfg(feature = "arithmetic")]
+
+use bp512::{
+    t1::{AffinePoint, FieldBytes, ProjectivePoint, SecretKey},
+    Scalar,
+};
+use elliptic_curve::{
+    group::Group, subtle::Choice, AffineXCoordinate, DecompressPoint, Field, PrimeField,
+};
+use hex_literal::hex;
+
+const UNCOMPRESSED_BASEPOINT: [u8; 129] = hex!(
+    "04 640ece5c 12788717 b9c1ba06 cbc2a6fe ba858424 58c56dde 9db1758d 39c0313d
+        82ba5173 5cdb3ea4 99aa77a7 d6943a64 f7a3f25f e26f06b5 1baa2696 fa9035da
+        5b534bd5 95f5af0f a2c89237 6c84ace1 bb4e3019 b71634c0 1131159c ae03cee9
+        d9932184 beef216b d71df2da df86a627 306ecff9 6dbb8bac e198b61e 00f8b332"
+);
+
+/// Scalar multiples of the generator: `(k, k * G)`.
+///
+/// Points are given in uncompressed SEC1 form.
+const MUL_TEST_VECTORS: &[([u8; 64], [u8; 129])] = &[
+    (
+        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"),
+        hex!("04 933c7b01bde24d4d1ad98a0d59c2aa1813233450ba591f4e6ddbfe134393a05f2e47303eb1add38ed67e1111a8bd1047940e001ea1cdbb1038d30badc85026ac 5bae564f3396de724edb02c6595d28abb3b040429f9d6d51e9866e67422ba054d107b0a24b5badc8b23e54b313246df33a75ebea1ff87ef4f64b1b5e608410f2"),
+    ),
+    (
+        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003"),
+        hex!("04 6ebd6e634974f138300e1d9024e1132bf53bfdcb1d0142501efcbbd2a295f70fac1b86449310ab68d8c7e6aaafa22a0a4398aedacdcfadd2cbdd03a56ee4ff0d 2fbe930ec94f50e8031161d73095549c2d39e1085dedb61db91f1c931c1a0c1022effddc3f91bde114e87f77f544ee1ac6ddb5db1f55fe8406fb7f856ff951b0"),
+    ),
+    // RFC 7027 Appendix A.3 private keys
+    (
+        hex!("16302ff0dbbb5a8d733dab7141c1b45acbc8715939677f6a56850a38bd87bd59b09e80279609ff333eb9d4c061231fb26f92eeb04982a5f1d1764cad57665422"),
+        hex!("04 7047cd66d78504b0d68ddb0e1332ffa5531cf75573d4597a49ef681ca623703894144cdf91810e4953098e35fc0cafd035a323abf102ff495c3bc9f5558a1168 779d9ba573df5d9eb09568ca183f86b01186523631a88414a0f91d71e1b7d87ff306c5d99dd1c6ba8c0c3ad905a6db6b13969d444a11dfc706dd3e3dc1613e2a"),
+    ),
+    (
+        hex!("230e18e1bcc88a362fa54e4ea3902009292f7f8033624fd471b5d8ace49d12cfabbc19963dab8e2f1eba00bffb29e4d72d13f2224562f405cb80503666b25429"),
+        hex!("04 4abc9477ab47fc42835efd607720f148a6008042619bec79c1dab3a321b7d910a8f0d63340c13ca8e436ce4f1fa55ddf14822477be9f5da8f11b172a90ff9b9f 63e6a8df47897eaf1e083208655326cfa29242382cc3633425e614056efb85ced2be217d4b4fdefa9ededb3b15989868bb82974d4aca9639eaeddb0d8e9dd518"),
+    ),
+    // n - 1
+    (
+        hex!("aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90068"),
+        hex!("04 640ece5c12788717b9c1ba06cbc2a6feba85842458c56dde9db1758d39c0313d82ba51735cdb3ea499aa77a7d6943a64f7a3f25fe26f06b51baa2696fa9035da 4f8a51e345f4157b9d0c5476c7454f260fe25d99fcb39d4ec532872dc22f3987a3ba797bdcd746d6d7afae50071cdabef8132f35bfc73ad84711aa38574195c1"),
+    ),
+];
+
+/// Decode an uncompressed SEC1 point by decompressing its x-coordinate.
+///
+/// The `sec1` crate doesn't support 64-byte field elements, so
+/// `FromEncodedPoint` isn't available for this curve.
+fn decode_point(bytes: &[u8; 129]) -> Option<AffinePoint> {
+    assert_eq!(bytes[0], 0x04);
+    let x = FieldBytes::clone_from_slice(&bytes[1..65]);
+    let y_is_odd = Choice::from(bytes[128] & 1);
+    let point = Option::<AffinePoint>::from(AffinePoint::decompress(&x, y_is_odd))?;
+    assert_eq!(point.x(), x);
+    Some(point)
+}
+
+#[test]
+fn generator_decompression() {
+    let point = decode_point(&UNCOMPRESSED_BASEPOINT).unwrap();
+    assert_eq!(point, AffinePoint::GENERATOR);
+}
+
+#[test]
+fn off_curve_x_rejected() {
+    // `b` is a quadratic non-residue, so there's no point with `x = 0`
+    let x = FieldBytes::default();
+    assert!(bool::from(
+        AffinePoint::decompress(&x, Choice::from(0)).is_none()
+    ));
+    assert!(bool::from(
+        AffinePoint::decompress(&x, Choice::from(1)).is_none()
+    ));
+}
+
+#[test]
+fn generator_has_prime_order() {
+    let generator = ProjectivePoint::GENERATOR;
+    assert!(!bool::from(generator.is_identity()));
+    assert_eq!(
+        generator * -Scalar::one() + generator,
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn scalar_multiplication() {
+    for (k, expected) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+        assert_eq!(Some(point), decode_point(expected));
+    }
+}
+
+#[test]
+fn repeated_addition() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = ProjectivePoint::IDENTITY;
+
+    for i in 1u64..=16 {
+        p += generator;
+        assert_eq!(p, generator * Scalar::from(i));
+        assert_eq!(
+            p,
+            generator.double() * Scalar::from(i) - generator * Scalar::from(i)
+        );
+    }
+}
+
+#[test]
+fn public_key_derivation() {
+    let (k, expected) = &MUL_TEST_VECTORS[2];
+    let secret_key = SecretKey::from_be_bytes(k).unwrap();
+    let public_key = secret_key.public_key();
+    assert_eq!(Some(*public_key.as_affine()), decode_point(expected));
+}
@@ -60,6 +60,11 @@ fn bench_field_element_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a,
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_field_element_invert_vartime<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_field_element(c: &mut Criterion) {
     let mut group = c.benchmark_group("field element operations");
     bench_field_element_normalize_weak(&mut group);
@@ -67,6 +72,7 @@ fn bench_field_element(c: &mut Criterion) {
     bench_field_element_mul(&mut group);
     bench_field_element_square(&mut group);
     bench_field_element_invert(&mut group);
+    bench_field_element_invert_vartime(&mut group);
     bench_field_element_sqrt(&mut group);
     group.finish();
 }
@@ -1,7 +1,8 @@
 //! secp256k1 scalar arithmetic benchmarks
 
 use criterion::{
-    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
+    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, BenchmarkId,
+    Criterion,
 };
 use hex_literal::hex;
 use k256::{
@@ -44,10 +45,32 @@ fn bench_point_lincomb<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     });
 }
 
+fn bench_point_multiscalar_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let mut p = ProjectivePoint::GENERATOR;
+    let mut s = test_scalar_x();
+    let pairs = (0..128)
+        .map(|_| {
+            p = p.double();
+            s *= test_scalar_y();
+            (p, s)
+        })
+        .collect::<Vec<_>>();
+
+    // Lengths around the switch from Straus to Pippenger
+    for n in [1, 4, 8, 12, 16, 24, 32, 64, 128] {
+        group.bench_with_input(
+            BenchmarkId::new("multiscalar_mul_vartime", n),
+            &pairs[..n],
+            |b, pairs| b.iter(|| ProjectivePoint::multiscalar_mul_vartime(pairs)),
+        );
+    }
+}
+
 fn bench_high_level(c: &mut Criterion) {
     let mut group = c.benchmark_group("high-level operations");
     bench_point_mul(&mut group);
     bench_point_lincomb(&mut group);
+    bench_point_multiscalar_mul(&mut group);
     group.finish();
 }
 
@@ -79,6 +102,11 @@ fn bench_scalar_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_scalar_invert_vartime<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_scalar_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_scalar(c: &mut Criterion) {
     let mut group = c.benchmark_group("scalar operations");
     bench_scalar_sub(&mut group);
@@ -86,6 +114,7 @@ fn bench_scalar(c: &mut Criterion) {
     bench_scalar_mul(&mut group);
     bench_scalar_negate(&mut group);
     bench_scalar_invert(&mut group);
+    bench_scalar_invert_vartime(&mut group);
     group.finish();
 }
 
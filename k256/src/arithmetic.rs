@@ -17,6 +17,9 @@ use affine::AffinePoint;
 use projective::ProjectivePoint;
 use scalar::Scalar;
 
+/// Scalar blinded with a randomly generated masking value.
+pub type BlindedScalar = weierstrass::BlindedScalar<Scalar>;
+
 const CURVE_EQUATION_B_SINGLE: u32 = 7u32;
 
 #[rustfmt::skip]
@@ -34,15 +34,25 @@ cfg_if! {
 use crate::FieldBytes;
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use elliptic_curve::{
+    bigint::{ArrayEncoding, Encoding, U256},
     ff::Field,
     rand_core::RngCore,
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
 };
+use safegcd::Inverter;
 
 #[cfg(test)]
 use num_bigint::{BigUint, ToBigUint};
 
+/// Constant representing the modulus
+/// p = 2^256 - 2^32 - 2^9 - 2^8 - 2^7 - 2^6 - 2^4 - 1
+const MODULUS: U256 =
+    U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+
+/// Inverter for the field modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U256::LIMBS }, 5> = Inverter::new(MODULUS.as_words());
+
 /// An element in the finite field used for curve coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement(FieldElementImpl);
@@ -199,36 +209,28 @@ impl FieldElement {
     }
 
     /// Returns the multiplicative inverse of self, if self is non-zero.
-    /// The result has magnitude 1, but is not normalized.
+    /// The result is normalized.
     pub fn invert(&self) -> CtOption<Self> {
-        // The binary representation of (p - 2) has 5 blocks of 1s, with lengths in
-        // { 1, 2, 22, 223 }. Use an addition chain to calculate 2^n - 1 for each block:
-        // [1], [2], 3, 6, 9, 11, [22], 44, 88, 176, 220, [223]
-
-        let x2 = self.pow2k(1).mul(self);
-        let x3 = x2.pow2k(1).mul(self);
-        let x6 = x3.pow2k(3).mul(&x3);
-        let x9 = x6.pow2k(3).mul(&x3);
-        let x11 = x9.pow2k(2).mul(&x2);
-        let x22 = x11.pow2k(11).mul(&x11);
-        let x44 = x22.pow2k(22).mul(&x22);
-        let x88 = x44.pow2k(44).mul(&x44);
-        let x176 = x88.pow2k(88).mul(&x88);
-        let x220 = x176.pow2k(44).mul(&x44);
-        let x223 = x220.pow2k(3).mul(&x3);
-
-        // The final result is then assembled using a sliding window over the blocks.
-        let res = x223
-            .pow2k(23)
-            .mul(&x22)
-            .pow2k(5)
-            .mul(self)
-            .pow2k(3)
-            .mul(&x2)
-            .pow2k(2)
-            .mul(self);
+        let x = U256::from_be_byte_array(self.to_bytes());
+        let inverse = U256::from_words(INVERTER.invert(x.as_words()));
+        CtOption::new(
+            Self::from_bytes_unchecked(&inverse.to_be_bytes()),
+            !self.normalizes_to_zero(),
+        )
+    }
 
-        CtOption::new(res, !self.normalizes_to_zero())
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    /// The result is normalized.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let x = U256::from_be_byte_array(self.to_bytes());
+        let inverse = U256::from_words(INVERTER.invert_vartime(x.as_words()));
+        CtOption::new(
+            Self::from_bytes_unchecked(&inverse.to_be_bytes()),
+            !self.normalizes_to_zero(),
+        )
     }
 
     /// Returns the square root of self mod p, or `None` if no square root exists.
@@ -543,6 +545,18 @@ mod tests {
         assert_eq!((two * &inv_two).normalize(), one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(FieldElement::zero().invert_vartime().is_none()));
+
+        let one = FieldElement::one();
+        assert_eq!(one.invert_vartime().unwrap(), one);
+
+        let two = one + &one;
+        let inv_two = two.invert_vartime().unwrap();
+        assert_eq!((two * &inv_two).normalize(), one);
+    }
+
     #[test]
     fn sqrt() {
         let one = FieldElement::one();
@@ -669,6 +683,7 @@ mod tests {
             let inv_bi = inv.to_biguint().unwrap();
             let m = FieldElement::modulus_as_biguint();
             assert_eq!((&inv_bi * &a_bi) % &m, 1.to_biguint().unwrap());
+            assert_eq!(a.invert_vartime().unwrap(), inv);
         }
     }
 }
@@ -1,13 +1,15 @@
+use elliptic_curve::bigint::U512;
 use elliptic_curve::consts::{U4, U48};
 use elliptic_curve::generic_array::GenericArray;
 use elliptic_curve::group::cofactor::CofactorGroup;
 use elliptic_curve::hash2curve::{
     FromOkm, GroupDigest, Isogeny, IsogenyCoefficients, MapToCurve, OsswuMap, OsswuMapParams, Sgn0,
 };
+use elliptic_curve::ops::Reduce;
 use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use elliptic_curve::Field;
 
-use crate::{AffinePoint, ProjectivePoint, Secp256k1};
+use crate::{AffinePoint, ProjectivePoint, Scalar, Secp256k1};
 
 use super::FieldElement;
 
@@ -40,6 +42,16 @@ impl FromOkm for FieldElement {
     }
 }
 
+impl FromOkm for Scalar {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        let mut wide = GenericArray::default();
+        wide[16..].copy_from_slice(data);
+        <Scalar as Reduce<U512>>::from_be_bytes_reduced(wide)
+    }
+}
+
 impl Sgn0 for FieldElement {
     fn sgn0(&self) -> Choice {
         self.normalize().is_odd()
@@ -45,6 +45,20 @@ use elliptic_curve::{
     IsHigh,
 };
 
+#[cfg(feature = "alloc")]
+use {
+    alloc::vec::Vec,
+    core::borrow::Borrow,
+    elliptic_curve::bigint::{ArrayEncoding, U256},
+    weierstrass::pippenger_vartime,
+};
+
+/// Number of terms above which the bucket method is used instead of Straus.
+///
+/// See the `multiscalar_mul_vartime` benchmarks in `benches/scalar.rs`.
+#[cfg(feature = "alloc")]
+const PIPPENGER_THRESHOLD: usize = 12;
+
 /// Lookup table containing precomputed values `[p, 2p, 3p, ..., 8p]`
 #[derive(Copy, Clone, Default)]
 struct LookupTable([ProjectivePoint; 8]);
@@ -371,6 +385,47 @@ fn lincomb_generic<const N: usize>(xs: &[ProjectivePoint; N], ks: &[Scalar; N])
     acc
 }
 
+/// Calculates `sum(x[i] * k[i])` for the first `N` terms using [`lincomb_generic`].
+#[cfg(feature = "alloc")]
+fn lincomb_chunk<const N: usize>(pairs: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let mut xs = [ProjectivePoint::IDENTITY; N];
+    let mut ks = [Scalar::ZERO; N];
+
+    for (i, (x, k)) in pairs.iter().take(N).enumerate() {
+        xs[i] = *x;
+        ks[i] = *k;
+    }
+
+    lincomb_generic(&xs, &ks)
+}
+
+/// Calculates `sum(x[i] * k[i])` using the bucket method with signed digits.
+///
+/// Each term is first split in two using the endomorphism, so that all
+/// scalars are less than `2^128`.
+#[cfg(feature = "alloc")]
+fn pippenger(pairs: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let mut points = Vec::with_capacity(2 * pairs.len());
+    let mut scalars = Vec::with_capacity(2 * pairs.len());
+
+    for (x, k) in pairs {
+        let (r1, r2) = decompose_scalar(k);
+
+        for (point, r) in [(*x, r1), (x.endomorphism(), r2)] {
+            let (point, r) = if r.is_high().into() {
+                (-point, -r)
+            } else {
+                (point, r)
+            };
+
+            points.push(point);
+            scalars.push(U256::from(r).to_le_byte_array());
+        }
+    }
+
+    pippenger_vartime(&points, &scalars, 128)
+}
+
 #[inline(always)]
 fn mul(x: &ProjectivePoint, k: &Scalar) -> ProjectivePoint {
     lincomb_generic(&[*x], &[*k])
@@ -387,6 +442,49 @@ impl LinearCombination for ProjectivePoint {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl ProjectivePoint {
+    /// Calculates `sum(x_i * k_i)` for the given `(x_i, k_i)` pairs.
+    ///
+    /// Uses interleaved windows (Straus) for a small number of terms and the
+    /// bucket method (Pippenger) for a large number of terms.
+    ///
+    /// This is **not** constant time, and must only be used with public
+    /// scalars, e.g. for batch verification.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn multiscalar_mul_vartime<I, T>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<(Self, Scalar)>,
+    {
+        let pairs = pairs
+            .into_iter()
+            .map(|pair| *pair.borrow())
+            .collect::<Vec<_>>();
+
+        if pairs.len() > PIPPENGER_THRESHOLD {
+            return pippenger(&pairs);
+        }
+
+        // Process the terms in chunks of 4, 2 or 1 to avoid padding
+        let mut acc = Self::IDENTITY;
+        let mut rest = pairs.as_slice();
+
+        while !rest.is_empty() {
+            let (sum, n) = match rest.len() {
+                1 => (lincomb_chunk::<1>(rest), 1),
+                2..=3 => (lincomb_chunk::<2>(rest), 2),
+                _ => (lincomb_chunk::<4>(rest), 4),
+            };
+
+            acc += sum;
+            rest = &rest[n..];
+        }
+
+        acc
+    }
+}
+
 impl Mul<Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
@@ -439,4 +537,27 @@ mod tests {
         let test = ProjectivePoint::lincomb(&x, &k, &y, &l);
         assert_eq!(reference, test);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_multiscalar_mul_vartime() {
+        use super::PIPPENGER_THRESHOLD;
+        use alloc::vec::Vec;
+
+        // Test every length covered by Straus and the first ones using Pippenger
+        for n in 0..=PIPPENGER_THRESHOLD + 2 {
+            let pairs = (0..n)
+                .map(|_| {
+                    (
+                        ProjectivePoint::random(&mut OsRng),
+                        Scalar::random(&mut OsRng),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let reference = pairs.iter().map(|(x, k)| x * k).sum::<ProjectivePoint>();
+            let test = ProjectivePoint::multiscalar_mul_vartime(&pairs);
+            assert_eq!(reference, test, "{} terms", n);
+        }
+    }
 }
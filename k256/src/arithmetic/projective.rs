@@ -10,7 +10,7 @@ use core::{
 };
 use elliptic_curve::{
     group::{
-        ff::Field,
+        ff::{BatchInverter, Field},
         prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
         Curve, Group, GroupEncoding,
     },
@@ -341,6 +341,31 @@ impl Curve for ProjectivePoint {
     fn to_affine(&self) -> AffinePoint {
         ProjectivePoint::to_affine(self)
     }
+
+    /// Converts a batch of projective points into affine points using a single
+    /// field inversion (Montgomery's trick).
+    fn batch_normalize(p: &[Self], q: &mut [AffinePoint]) {
+        assert_eq!(p.len(), q.len());
+
+        // Invert the `z`-coordinates in place in `q[i].y`, using `q[i].x` as
+        // scratch space. They are normalized first so that identity points
+        // compare equal to zero and are skipped.
+        for (p, q) in p.iter().zip(q.iter_mut()) {
+            q.y = p.z.normalize();
+        }
+
+        BatchInverter::invert_with_internal_scratch(q, |q| &mut q.y, |q| &mut q.x);
+
+        for (p, q) in p.iter().zip(q.iter_mut()) {
+            let zinv = q.y;
+            let affine = AffinePoint::new(p.x * &zinv, p.y * &zinv);
+            *q = AffinePoint::conditional_select(
+                &affine,
+                &AffinePoint::IDENTITY,
+                zinv.normalizes_to_zero(),
+            );
+        }
+    }
 }
 
 impl PrimeCurve for ProjectivePoint {
@@ -562,7 +587,7 @@ mod tests {
         test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
         Scalar,
     };
-    use elliptic_curve::group::{ff::PrimeField, prime::PrimeCurveAffine};
+    use elliptic_curve::group::{ff::PrimeField, prime::PrimeCurveAffine, Curve};
 
     #[test]
     fn affine_to_projective() {
@@ -693,6 +718,24 @@ mod tests {
         assert_eq!(generator.double() - &generator, generator);
     }
 
+    #[test]
+    fn batch_normalize() {
+        let generator = ProjectivePoint::GENERATOR;
+        let points = [
+            generator,
+            ProjectivePoint::IDENTITY,
+            generator.double(),
+            generator.double() + &generator,
+            generator - &generator,
+        ];
+        let mut affine_points = [AffinePoint::GENERATOR; 5];
+        ProjectivePoint::batch_normalize(&points, &mut affine_points);
+
+        for (point, affine) in points.iter().zip(affine_points.iter()) {
+            assert_eq!(point.to_affine(), *affine);
+        }
+    }
+
     #[test]
     fn test_vector_scalar_mult() {
         let generator = ProjectivePoint::GENERATOR;
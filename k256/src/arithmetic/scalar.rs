@@ -21,6 +21,7 @@ use elliptic_curve::{
     zeroize::DefaultIsZeroes,
     Curve, IsHigh, ScalarArithmetic, ScalarCore,
 };
+use safegcd::Inverter;
 
 #[cfg(feature = "bits")]
 use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
@@ -42,6 +43,9 @@ const MODULUS: [Word; bigint::nlimbs!(256)] = ORDER.to_words();
 /// Constant representing the modulus / 2
 const FRAC_MODULUS_2: U256 = ORDER.shr_vartime(1);
 
+/// Inverter for the scalar modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U256::LIMBS }, 5> = Inverter::new(ORDER.as_words());
+
 /// Scalars are elements in the finite field modulo n.
 ///
 /// # Trait impls
@@ -128,53 +132,17 @@ impl Scalar {
 
     /// Inverts the scalar.
     pub fn invert(&self) -> CtOption<Self> {
-        // Using an addition chain from
-        // https://briansmith.org/ecc-inversion-addition-chains-01#secp256k1_scalar_inversion
-        let x_1 = *self;
-        let x_10 = self.pow2k(1);
-        let x_11 = x_10.mul(&x_1);
-        let x_101 = x_10.mul(&x_11);
-        let x_111 = x_10.mul(&x_101);
-        let x_1001 = x_10.mul(&x_111);
-        let x_1011 = x_10.mul(&x_1001);
-        let x_1101 = x_10.mul(&x_1011);
-
-        let x6 = x_1101.pow2k(2).mul(&x_1011);
-        let x8 = x6.pow2k(2).mul(&x_11);
-        let x14 = x8.pow2k(6).mul(&x6);
-        let x28 = x14.pow2k(14).mul(&x14);
-        let x56 = x28.pow2k(28).mul(&x28);
-
-        #[rustfmt::skip]
-            let res = x56
-            .pow2k(56).mul(&x56)
-            .pow2k(14).mul(&x14)
-            .pow2k(3).mul(&x_101)
-            .pow2k(4).mul(&x_111)
-            .pow2k(4).mul(&x_101)
-            .pow2k(5).mul(&x_1011)
-            .pow2k(4).mul(&x_1011)
-            .pow2k(4).mul(&x_111)
-            .pow2k(5).mul(&x_111)
-            .pow2k(6).mul(&x_1101)
-            .pow2k(4).mul(&x_101)
-            .pow2k(3).mul(&x_111)
-            .pow2k(5).mul(&x_1001)
-            .pow2k(6).mul(&x_101)
-            .pow2k(10).mul(&x_111)
-            .pow2k(4).mul(&x_111)
-            .pow2k(9).mul(&x8)
-            .pow2k(5).mul(&x_1001)
-            .pow2k(6).mul(&x_1011)
-            .pow2k(4).mul(&x_1101)
-            .pow2k(5).mul(&x_11)
-            .pow2k(6).mul(&x_1101)
-            .pow2k(10).mul(&x_1101)
-            .pow2k(4).mul(&x_1001)
-            .pow2k(6).mul(&x_1)
-            .pow2k(8).mul(&x6);
-
-        CtOption::new(res, !self.is_zero())
+        let inverse = INVERTER.invert(self.0.as_words());
+        CtOption::new(Self(U256::from_words(inverse)), !self.is_zero())
+    }
+
+    /// Inverts the scalar in variable time.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = INVERTER.invert_vartime(self.0.as_words());
+        CtOption::new(Self(U256::from_words(inverse)), !self.is_zero())
     }
 
     /// Returns the scalar modulus as a `BigUint` object.
@@ -211,15 +179,6 @@ impl Scalar {
     pub(crate) const fn from_bytes_unchecked(bytes: &[u8; 32]) -> Self {
         Self(U256::from_be_slice(bytes))
     }
-
-    /// Raises the scalar to the power `2^k`.
-    fn pow2k(&self, k: usize) -> Self {
-        let mut x = *self;
-        for _j in 0..k {
-            x = x.square();
-        }
-        x
-    }
 }
 
 impl Field for Scalar {
@@ -964,6 +923,7 @@ mod tests {
             let inv_bi = inv.to_biguint().unwrap();
             let m = Scalar::modulus_as_biguint();
             assert_eq!((&inv_bi * &a_bi) % &m, 1.to_biguint().unwrap());
+            assert_eq!(a.invert_vartime().unwrap(), inv);
         }
 
         #[test]
@@ -51,7 +51,7 @@ use crate::{
     elliptic_curve::{
         bigint::U256,
         consts::U32,
-        ops::{Invert, LinearCombination, Reduce},
+        ops::{LinearCombination, Reduce},
         DecompressPoint,
     },
     AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar,
@@ -184,7 +184,7 @@ impl Signature {
         }
 
         let R = ProjectivePoint::from(R.unwrap());
-        let r_inv = *r.invert();
+        let r_inv = r.invert_vartime().unwrap();
         let u1 = -(r_inv * z);
         let u2 = r_inv * *s;
         let pk = ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, &u1, &R, &u2);
@@ -9,7 +9,7 @@ use core::{
 use ecdsa_core::{
     hazmat::SignPrimitive,
     signature::{
-        digest::{Digest, FixedOutput},
+        digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
         DigestSigner, RandomizedDigestSigner,
     },
 };
@@ -20,9 +20,10 @@ use elliptic_curve::{
     rand_core::{CryptoRng, RngCore},
     subtle::{Choice, ConstantTimeEq, CtOption},
     zeroize::{Zeroize, ZeroizeOnDrop},
-    IsHigh,
+    IsHigh, ScalarCore,
 };
 use sha2::Sha256;
+use weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve};
 
 #[cfg(any(feature = "keccak256", feature = "sha256"))]
 use ecdsa_core::signature::{self, PrehashSignature, RandomizedSigner};
@@ -160,7 +161,24 @@ where
     }
 }
 
+impl Rfc6979Curve for Secp256k1 {
+    const REDUCE_PREHASH: bool = false;
+}
+
 impl SignPrimitive<Secp256k1> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<ecdsa_core::RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<Secp256k1>>,
+        U256: for<'a> From<&'a Self>,
+        D: Digest + BlockSizeUser + FixedOutput<OutputSize = U32> + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<Secp256k1, D>(self, z, ad)
+    }
+
     #[allow(non_snake_case, clippy::many_single_char_names)]
     fn try_sign_prehashed<K>(
         &self,
@@ -314,4 +332,36 @@ impl FromStr for SigningKey {
 mod tests {
     use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, Secp256k1};
     ecdsa_core::new_signing_test!(Secp256k1, ECDSA_TEST_VECTORS);
+
+    /// RFC6979 nonces are derived from the prehash as-is like in the `ecdsa`
+    /// crate, including when it's larger than the order `n`, rather than
+    /// from the prehash reduced modulo `n`.
+    mod rfc6979_prehash_above_order {
+        use crate::{FieldBytes, Scalar};
+        use ecdsa_core::hazmat::SignPrimitive;
+        use elliptic_curve::ff::PrimeField;
+        use hex_literal::hex;
+        use sha2::Sha256;
+
+        #[test]
+        fn nonce_is_unreduced() {
+            let x = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+            let d = Scalar::from_repr(x.into()).unwrap();
+            let k = hex!("766d8b826064b7f27df663b2b78697ed61f9ad41adf6ea302e4c065283e7508b");
+            let k = Scalar::from_repr(k.into()).unwrap();
+            let z = FieldBytes::clone_from_slice(&[0xff; 32]);
+
+            let expected = d.try_sign_prehashed(k, z).unwrap().0;
+            assert_eq!(
+                expected.as_ref(),
+                &hex!(
+                    "21972c07b7108adf973fdc16df6e995ee611c2e5d9b7dcef724be5ae7b3f58c2
+                    668a276e1b584077277904956afbb0a2f615e11c6fd76ecb58bba40d530181f6"
+                )[..]
+            );
+
+            let signature = d.try_sign_prehashed_rfc6979::<Sha256>(z, &[]).unwrap().0;
+            assert_eq!(signature, expected);
+        }
+    }
 }
@@ -9,7 +9,7 @@ use ecdsa_core::{hazmat::VerifyPrimitive, signature};
 use elliptic_curve::{
     bigint::U256,
     consts::U32,
-    ops::{Invert, LinearCombination, Reduce},
+    ops::{LinearCombination, Reduce},
     sec1::ToEncodedPoint,
     IsHigh,
 };
@@ -109,7 +109,8 @@ impl VerifyPrimitive<Secp256k1> for AffinePoint {
             return Err(Error::new());
         }
 
-        let s_inv = *s.invert();
+        // The signature is public, so `s` can be inverted in variable time
+        let s_inv = s.invert_vartime().unwrap();
         let u1 = z * s_inv;
         let u2 = *r * s_inv;
 
@@ -19,6 +19,9 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
@@ -41,7 +44,9 @@ pub mod test_vectors;
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
+pub use arithmetic::{
+    affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar, BlindedScalar,
+};
 
 #[cfg(feature = "expose-field")]
 pub use arithmetic::FieldElement;
@@ -65,17 +65,34 @@
 mod sign;
 mod verify;
 
+#[cfg(feature = "alloc")]
+mod batch;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod musig2;
+
+#[cfg(all(feature = "alloc", feature = "hash2curve"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "hash2curve"))))]
+pub mod frost;
+
 pub use self::{sign::SigningKey, verify::VerifyingKey};
 pub use ecdsa_core::signature::{self, Error};
 
-use crate::{arithmetic::FieldElement, NonZeroScalar};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::batch::{verify_batch, BatchError};
+
+use crate::{arithmetic::FieldElement, NonZeroScalar, Scalar};
 use core::{cmp, fmt};
 use ecdsa_core::signature::Result;
+use elliptic_curve::ff::PrimeField;
 use sha2::{Digest, Sha256};
 
 const AUX_TAG: &[u8] = b"BIP0340/aux";
 const NONCE_TAG: &[u8] = b"BIP0340/nonce";
 const CHALLENGE_TAG: &[u8] = b"BIP0340/challenge";
+const TAPTWEAK_TAG: &[u8] = b"TapTweak";
 
 /// Taproot Schnorr signature as defined in [BIP340].
 ///
@@ -186,6 +203,25 @@ fn tagged_hash(tag: &[u8]) -> Sha256 {
     digest
 }
 
+/// Parse a tweak, which must be less than the curve order.
+fn tweak_scalar(tweak: &[u8; 32]) -> Result<Scalar> {
+    Option::from(Scalar::from_repr((*tweak).into())).ok_or_else(Error::new)
+}
+
+/// Compute the [BIP341] Taproot tweak of `internal_key` committing to the
+/// optional script tree `merkle_root`.
+///
+/// [BIP341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+fn taproot_tweak(internal_key: &VerifyingKey, merkle_root: Option<[u8; 32]>) -> Result<Scalar> {
+    let mut tweak = tagged_hash(TAPTWEAK_TAG).chain_update(internal_key.to_bytes());
+
+    if let Some(merkle_root) = merkle_root {
+        tweak.update(merkle_root);
+    }
+
+    tweak_scalar(&tweak.finalize().into())
+}
+
 // Test vectors from:
 // https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
 #[cfg(test)]
@@ -447,4 +483,189 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bip340_batch_verify() {
+        use super::verify_batch;
+        use alloc::vec::Vec;
+        use rand_core::OsRng;
+
+        let mut valid = BIP340_SIGN_VECTORS
+            .iter()
+            .map(|vector| {
+                (
+                    VerifyingKey::from_bytes(&vector.public_key).unwrap(),
+                    vector.message,
+                    Signature::from_bytes(&vector.signature).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut invalid = Vec::new();
+
+        for vector in BIP340_VERIFY_VECTORS {
+            if let (Ok(pk), Ok(sig)) = (
+                VerifyingKey::from_bytes(&vector.public_key),
+                Signature::from_bytes(&vector.signature),
+            ) {
+                if vector.valid {
+                    valid.push((pk, vector.message, sig));
+                } else {
+                    invalid.push((pk, vector.message, sig));
+                }
+            }
+        }
+
+        fn to_batch(
+            items: &[(VerifyingKey, [u8; 32], Signature)],
+        ) -> Vec<(&VerifyingKey, &[u8; 32], &Signature)> {
+            items.iter().map(|(pk, msg, sig)| (pk, msg, sig)).collect()
+        }
+
+        assert!(verify_batch(&mut OsRng, &[]).is_ok());
+        assert!(verify_batch(&mut OsRng, &to_batch(&valid[..1])).is_ok());
+        assert!(verify_batch(&mut OsRng, &to_batch(&valid)).is_ok());
+
+        for item in &invalid {
+            let mut items = valid.clone();
+            items.insert(2, *item);
+
+            let err = verify_batch(&mut OsRng, &to_batch(&items)).unwrap_err();
+            assert_eq!(err.index(), 2);
+            assert_eq!(
+                verify_batch(&mut OsRng, &to_batch(&[*item]))
+                    .unwrap_err()
+                    .index(),
+                0
+            );
+        }
+
+        // a negated `s` at index 1 is reported even though the `R` at index 5
+        // is not an X coordinate on the curve
+        let vector = |index| {
+            let vector = BIP340_VERIFY_VECTORS
+                .iter()
+                .find(|vector| vector.index == index)
+                .unwrap();
+
+            (
+                VerifyingKey::from_bytes(&vector.public_key).unwrap(),
+                vector.message,
+                Signature::from_bytes(&vector.signature).unwrap(),
+            )
+        };
+
+        let mut items = valid.iter().cycle().take(6).copied().collect::<Vec<_>>();
+        items[1] = vector(8);
+        items[5] = vector(11);
+
+        let err = verify_batch(&mut OsRng, &to_batch(&items)).unwrap_err();
+        assert_eq!(err.index(), 1);
+    }
+
+    /// BIP341 `scriptPubKey` test vector
+    struct TaprootVector {
+        /// Internal public key
+        internal_key: [u8; 32],
+
+        /// Script tree merkle root
+        merkle_root: Option<[u8; 32]>,
+
+        /// Expected output public key
+        output_key: [u8; 32],
+
+        /// Parity of the `y` coordinate of the output key
+        output_parity: bool,
+    }
+
+    // Test vectors from:
+    // https://github.com/bitcoin/bips/blob/master/bip-0341/wallet-test-vectors.json
+    const BIP341_VECTORS: &[TaprootVector] = &[
+        TaprootVector {
+            internal_key: hex!("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d"),
+            merkle_root: None,
+            output_key: hex!("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"),
+            output_parity: true,
+        },
+        TaprootVector {
+            internal_key: hex!("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27"),
+            merkle_root: Some(hex!(
+                "5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"
+            )),
+            output_key: hex!("147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"),
+            output_parity: true,
+        },
+    ];
+
+    #[test]
+    fn bip341_output_keys() {
+        for vector in BIP341_VECTORS {
+            let internal_key = VerifyingKey::from_bytes(&vector.internal_key).unwrap();
+            let output_key = internal_key.taproot_tweak(vector.merkle_root).unwrap();
+            assert_eq!(output_key.to_bytes().as_slice(), &vector.output_key);
+
+            output_key
+                .verify_taproot_commitment(&internal_key, vector.merkle_root, vector.output_parity)
+                .unwrap();
+            assert!(output_key
+                .verify_taproot_commitment(&internal_key, vector.merkle_root, !vector.output_parity)
+                .is_err());
+            assert!(output_key
+                .verify_taproot_commitment(&internal_key, Some([0; 32]), vector.output_parity)
+                .is_err());
+            assert!(internal_key
+                .verify_taproot_commitment(&internal_key, vector.merkle_root, vector.output_parity)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn bip341_key_path_spending() {
+        let internal_key = SigningKey::from_bytes(&hex!(
+            "6b973d88838f27366ed61c9ad6367663045cb456e28335c109e30717ae0c6baa"
+        ))
+        .unwrap();
+
+        // BIP341 does not normalize the tweaked secret key to have an even
+        // `y` coordinate, so compare after parsing it as a `SigningKey`
+        let expected = SigningKey::from_bytes(&hex!(
+            "2405b971772ad26915c8dcdf10f238753a9b837e5f8e6a86fd7c0cce5b7296d9"
+        ))
+        .unwrap();
+
+        let output_key = internal_key.taproot_tweak(None).unwrap();
+        assert_eq!(output_key.to_bytes(), expected.to_bytes());
+        assert_eq!(
+            output_key.verifying_key(),
+            &internal_key.verifying_key().taproot_tweak(None).unwrap()
+        );
+
+        let msg = [0x42; 32];
+        let sig = output_key.try_sign_prehashed(&msg, &[0; 32]).unwrap();
+        output_key
+            .verifying_key()
+            .verify_prehashed(&msg, &sig)
+            .unwrap();
+    }
+
+    #[test]
+    fn tweak_add() {
+        let signing_key = SigningKey::from_bytes(&hex!(
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF"
+        ))
+        .unwrap();
+        let tweak = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+
+        let tweaked = signing_key.tweak_add(&tweak).unwrap();
+        assert_eq!(
+            tweaked.verifying_key(),
+            &signing_key.verifying_key().tweak_add(&tweak).unwrap()
+        );
+
+        // tweaks must be less than the curve order
+        let tweak = hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        assert!(signing_key.tweak_add(&tweak).is_err());
+        assert!(signing_key.verifying_key().tweak_add(&tweak).is_err());
+    }
 }
@@ -0,0 +1,108 @@
+//! Taproot Schnorr batch verification.
+
+use super::{tagged_hash, Signature, VerifyingKey, CHALLENGE_TAG};
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use core::fmt;
+use ecdsa_core::signature::Error;
+use elliptic_curve::{
+    bigint::U256,
+    group::Group,
+    ops::Reduce,
+    rand_core::{CryptoRng, RngCore},
+    DecompactPoint,
+};
+use sha2::Digest;
+
+/// Error returned by [`verify_batch`], identifying the first invalid
+/// signature in the batch.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    index: usize,
+}
+
+impl BatchError {
+    /// Get the index of the first invalid signature in the batch.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid signature at index {}", self.index)
+    }
+}
+
+impl From<BatchError> for Error {
+    fn from(_: BatchError) -> Error {
+        Error::new()
+    }
+}
+
+/// Verify a batch of Schnorr signatures over pre-hashed messages as described
+/// in the [BIP340] batch verification section.
+///
+/// All signatures are checked at once using a single multi-scalar
+/// multiplication with random weights drawn from `rng`, which is
+/// significantly faster than verifying them one-by-one using
+/// [`VerifyingKey::verify_prehashed`]. If the batch is invalid, the
+/// signatures are verified individually to identify the first invalid one.
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki#batch-verification
+pub fn verify_batch(
+    mut rng: impl CryptoRng + RngCore,
+    batch: &[(&VerifyingKey, &[u8; 32], &Signature)],
+) -> Result<(), BatchError> {
+    let mut pairs = Vec::with_capacity(2 * batch.len() + 1);
+    let mut s_sum = Scalar::ZERO;
+    let mut lifted = true;
+
+    for (index, (verifying_key, msg_digest, sig)) in batch.iter().enumerate() {
+        let (r_bytes, _) = sig.bytes.split_at(Signature::BYTE_SIZE / 2);
+        let r = AffinePoint::decompact(FieldBytes::from_slice(r_bytes));
+        let r = match Option::<AffinePoint>::from(r) {
+            Some(r) => r,
+            None => {
+                // an earlier signature may be invalid too, so find the first
+                // one using the individual checks below
+                lifted = false;
+                break;
+            }
+        };
+
+        let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            tagged_hash(CHALLENGE_TAG)
+                .chain_update(r_bytes)
+                .chain_update(verifying_key.to_bytes())
+                .chain_update(msg_digest)
+                .finalize(),
+        );
+
+        // the first weight can be `1` without loss of security
+        let a = if index == 0 {
+            Scalar::ONE
+        } else {
+            *NonZeroScalar::random(&mut rng)
+        };
+
+        pairs.push((ProjectivePoint::from(r), a));
+        pairs.push((ProjectivePoint::from(*verifying_key.as_affine()), a * e));
+        s_sum += a * **sig.s();
+    }
+
+    // Check `(a₁s₁ + ... + aₙsₙ)×G == a₁×R₁ + ... + aₙ×Rₙ + a₁e₁×P₁ + ... + aₙeₙ×Pₙ`
+    pairs.push((ProjectivePoint::GENERATOR, -s_sum));
+
+    if lifted && bool::from(ProjectivePoint::multiscalar_mul_vartime(&pairs).is_identity()) {
+        return Ok(());
+    }
+
+    for (index, (verifying_key, msg_digest, sig)) in batch.iter().enumerate() {
+        verifying_key
+            .verify_prehashed(msg_digest, sig)
+            .map_err(|_| BatchError { index })?;
+    }
+
+    Ok(())
+}
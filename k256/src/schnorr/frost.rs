@@ -0,0 +1,1341 @@
+//! FROST threshold Schnorr signatures as defined in [RFC9591].
+//!
+//! FROST allows any `t` out of `n` participants holding shares of a group
+//! secret key to jointly produce a single Schnorr signature which verifies
+//! under the group public key. Two ciphersuites are provided:
+//!
+//! - [`Secp256k1Sha256`]: `FROST(secp256k1, SHA-256)` as specified in
+//!   RFC9591, which produces [`Signature`]s.
+//! - [`Secp256k1Sha256Taproot`]: a [BIP340]-compatible variant which negates
+//!   the group public key and the group commitment where necessary to have
+//!   even `y` coordinates and uses the BIP340 challenge. It produces Taproot
+//!   Schnorr [`Signature`](super::Signature)s which verify under
+//!   [`PublicKeyPackage::verifying_key`].
+//!
+//! Keys are generated either by a trusted dealer using
+//! [`trusted_dealer_keygen`] or [`split`], or without one using the
+//! distributed key generation in the [`dkg`] module. Either way, each
+//! participant ends up with a [`KeyPackage`] and everyone shares the same
+//! [`PublicKeyPackage`].
+//!
+//! Signing takes two rounds:
+//!
+//! 1. Each participant generates [`SigningNonces`] and [`SigningCommitments`]
+//!    using [`commit`] and sends the commitments to the coordinator. The
+//!    coordinator selects at least `t` participants and sends them a
+//!    [`SigningPackage`] containing their commitments and the message.
+//! 2. Each selected participant produces a [`SignatureShare`] using
+//!    [`SigningPackage::sign`] and sends it to the coordinator, who combines
+//!    the shares using [`SigningPackage::aggregate`].
+//!
+//! # ⚠️ Warning
+//!
+//! [`SigningNonces`] must never be used more than once: signing two different
+//! messages with the same nonces leaks the participant's signing share.
+//! [`SigningPackage::sign`] takes the nonces by value to help enforce this.
+//!
+//! # Usage
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use k256::schnorr::frost::{self, Secp256k1Sha256Taproot, SigningPackage};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//! use std::collections::BTreeMap;
+//!
+//! // 2-of-3 key generation using a trusted dealer
+//! let (shares, public_key_package) = frost::trusted_dealer_keygen(2, 3, &mut OsRng)?;
+//! let key_packages = shares
+//!     .values()
+//!     .map(|share| share.verify())
+//!     .collect::<Result<Vec<_>, _>>()?;
+//!
+//! // First round: commit to nonces
+//! let mut nonces = Vec::new();
+//! let mut commitments = BTreeMap::new();
+//!
+//! for key_package in &key_packages[..2] {
+//!     let (signing_nonces, signing_commitments) =
+//!         frost::commit::<Secp256k1Sha256Taproot>(key_package, &mut OsRng);
+//!     nonces.push(signing_nonces);
+//!     commitments.insert(key_package.identifier(), signing_commitments);
+//! }
+//!
+//! // Second round: produce signature shares
+//! let msg = [0x42; 32];
+//! let signing_package = SigningPackage::<Secp256k1Sha256Taproot>::new(commitments, &msg);
+//! let mut signature_shares = BTreeMap::new();
+//!
+//! for (signing_nonces, key_package) in nonces.into_iter().zip(&key_packages) {
+//!     let signature_share = signing_package.sign(signing_nonces, key_package)?;
+//!     signature_shares.insert(key_package.identifier(), signature_share);
+//! }
+//!
+//! let signature = signing_package.aggregate(&signature_shares, &public_key_package)?;
+//! public_key_package
+//!     .verifying_key()
+//!     .verify_prehashed(&msg, &signature)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [RFC9591]: https://www.rfc-editor.org/rfc/rfc9591.html
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+pub mod dkg;
+
+use super::{tagged_hash, VerifyingKey, CHALLENGE_TAG};
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{cmp::Ordering, fmt::Debug, marker::PhantomData};
+use ecdsa_core::signature::{Error, Result};
+use elliptic_curve::{
+    bigint::U256,
+    group::{
+        ff::{Field, PrimeField},
+        prime::PrimeCurveAffine,
+        GroupEncoding,
+    },
+    hash2curve::{hash_to_field, ExpandMsgXmd},
+    ops::{LinearCombination, Reduce},
+    rand_core::{CryptoRng, RngCore},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+use sha2::{Digest, Sha256};
+
+/// Size of a compressed SEC1 point in bytes.
+const POINT_SIZE: usize = 33;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// FROST ciphersuite.
+///
+/// This trait is sealed and implemented by [`Secp256k1Sha256`] and
+/// [`Secp256k1Sha256Taproot`].
+pub trait Ciphersuite: sealed::Sealed + Copy + Clone + Debug {
+    /// Context string used for domain separation of all hashes.
+    const CONTEXT_STRING: &'static [u8];
+
+    /// Whether the group public key and the group commitment are negated
+    /// where necessary to have an even `y` coordinate.
+    const EVEN_Y: bool;
+
+    /// Signature produced by [`SigningPackage::aggregate`].
+    type Signature;
+
+    /// Compute the challenge for the group commitment `r`, the group public
+    /// key and the message.
+    fn challenge(r: &AffinePoint, group_public_key: &AffinePoint, msg: &[u8]) -> Scalar;
+
+    /// Encode the group commitment `r` and the aggregate response `z` as a
+    /// signature.
+    fn signature(r: &AffinePoint, z: &Scalar) -> Result<Self::Signature>;
+}
+
+/// `FROST(secp256k1, SHA-256)` ciphersuite as defined in [RFC9591].
+///
+/// [RFC9591]: https://www.rfc-editor.org/rfc/rfc9591.html#name-frostsecp256k1-sha-256
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Secp256k1Sha256;
+
+impl sealed::Sealed for Secp256k1Sha256 {}
+
+impl Ciphersuite for Secp256k1Sha256 {
+    const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-v1";
+    const EVEN_Y: bool = false;
+
+    type Signature = Signature;
+
+    fn challenge(r: &AffinePoint, group_public_key: &AffinePoint, msg: &[u8]) -> Scalar {
+        hash_to_scalar::<Self>(b"chal", &[&r.to_bytes(), &group_public_key.to_bytes(), msg])
+    }
+
+    fn signature(r: &AffinePoint, z: &Scalar) -> Result<Signature> {
+        Ok(Signature { r: *r, z: *z })
+    }
+}
+
+/// `FROST(secp256k1, SHA-256)` variant producing [BIP340] Taproot Schnorr
+/// signatures.
+///
+/// The message is used as-is as the BIP340 message, i.e. signatures over a
+/// 32-byte message verify using [`VerifyingKey::verify_prehashed`].
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Secp256k1Sha256Taproot;
+
+impl sealed::Sealed for Secp256k1Sha256Taproot {}
+
+impl Ciphersuite for Secp256k1Sha256Taproot {
+    const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-TR-v1";
+    const EVEN_Y: bool = true;
+
+    type Signature = super::Signature;
+
+    fn challenge(r: &AffinePoint, group_public_key: &AffinePoint, msg: &[u8]) -> Scalar {
+        <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            tagged_hash(CHALLENGE_TAG)
+                .chain_update(r.x.to_bytes())
+                .chain_update(group_public_key.x.to_bytes())
+                .chain_update(msg)
+                .finalize(),
+        )
+    }
+
+    fn signature(r: &AffinePoint, z: &Scalar) -> Result<super::Signature> {
+        let mut bytes = [0u8; super::Signature::BYTE_SIZE];
+        let (r_bytes, z_bytes) = bytes.split_at_mut(super::Signature::BYTE_SIZE / 2);
+        r_bytes.copy_from_slice(&r.x.to_bytes());
+        z_bytes.copy_from_slice(&z.to_bytes());
+        super::Signature::try_from(&bytes[..])
+    }
+}
+
+/// Identifier of a participant, which is a nonzero scalar.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Identifier(Scalar);
+
+impl Identifier {
+    /// Size of a serialized identifier in bytes.
+    pub const BYTE_SIZE: usize = 32;
+
+    /// Create an identifier from a nonzero integer.
+    pub fn new(id: u16) -> Result<Self> {
+        if id == 0 {
+            return Err(Error::new());
+        }
+
+        Ok(Self(Scalar::from(u64::from(id))))
+    }
+
+    /// Parse an identifier.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        NonZeroScalar::try_from(bytes)
+            .map(|scalar| Self(*scalar))
+            .map_err(|_| Error::new())
+    }
+
+    /// Serialize this identifier.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// identifiers are sorted by their numeric value
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bytes().cmp(&other.to_bytes())
+    }
+}
+
+/// Secret share of the group secret key sent by a trusted dealer to a
+/// participant, along with the commitment to the dealer's polynomial.
+#[derive(Clone)]
+pub struct SecretShare {
+    identifier: Identifier,
+    signing_share: Scalar,
+    commitment: Vec<AffinePoint>,
+}
+
+impl SecretShare {
+    /// Get the identifier of the participant this share belongs to.
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// Verify this share against the commitment to the dealer's polynomial
+    /// and convert it into a [`KeyPackage`].
+    pub fn verify(&self) -> Result<KeyPackage> {
+        let verifying_share = ProjectivePoint::GENERATOR * self.signing_share;
+
+        if evaluate_commitment(&self.commitment, &self.identifier.0) != verifying_share {
+            return Err(Error::new());
+        }
+
+        Ok(KeyPackage {
+            identifier: self.identifier,
+            signing_share: self.signing_share,
+            verifying_share: to_public_key(verifying_share)?,
+            group_public_key: to_public_key(self.commitment[0].into())?,
+            min_signers: self.commitment.len() as u16,
+        })
+    }
+
+    /// Parse a secret share, which consists of the identifier and the
+    /// signing share as scalars, followed by the commitment to the dealer's
+    /// polynomial as a sequence of compressed points.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < Identifier::BYTE_SIZE + 32 {
+            return Err(Error::new());
+        }
+
+        let (identifier, rest) = bytes.split_at(Identifier::BYTE_SIZE);
+        let (signing_share, commitment) = rest.split_at(32);
+
+        Ok(Self {
+            identifier: Identifier::from_bytes(identifier)?,
+            signing_share: deserialize_scalar(signing_share)?,
+            commitment: deserialize_commitment(commitment)?,
+        })
+    }
+
+    /// Serialize this secret share.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(Identifier::BYTE_SIZE + 32 + self.commitment.len() * POINT_SIZE);
+        bytes.extend_from_slice(&self.identifier.to_bytes());
+        bytes.extend_from_slice(&self.signing_share.to_bytes());
+        serialize_commitment(&self.commitment, &mut bytes);
+        bytes
+    }
+}
+
+impl Drop for SecretShare {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretShare {}
+
+/// Key material of a participant which is needed for signing.
+#[derive(Clone)]
+pub struct KeyPackage {
+    identifier: Identifier,
+    signing_share: Scalar,
+    verifying_share: PublicKey,
+    group_public_key: PublicKey,
+    min_signers: u16,
+}
+
+impl KeyPackage {
+    /// Size of a serialized key package in bytes.
+    pub const BYTE_SIZE: usize = Identifier::BYTE_SIZE + 32 + 2 * POINT_SIZE + 2;
+
+    /// Parse a key package, which consists of the identifier and the signing
+    /// share as scalars, the verifying share and the group public key as
+    /// compressed points, and the minimum number of signers as a big-endian
+    /// `u16`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (identifier, rest) = bytes.split_at(Identifier::BYTE_SIZE);
+        let (signing_share, rest) = rest.split_at(32);
+        let (verifying_share, rest) = rest.split_at(POINT_SIZE);
+        let (group_public_key, min_signers) = rest.split_at(POINT_SIZE);
+
+        let key_package = Self {
+            identifier: Identifier::from_bytes(identifier)?,
+            signing_share: deserialize_scalar(signing_share)?,
+            verifying_share: deserialize_element(verifying_share)?,
+            group_public_key: deserialize_element(group_public_key)?,
+            min_signers: u16::from_be_bytes([min_signers[0], min_signers[1]]),
+        };
+
+        if key_package.min_signers < 2
+            || key_package.verifying_share.to_projective()
+                != ProjectivePoint::GENERATOR * key_package.signing_share
+        {
+            return Err(Error::new());
+        }
+
+        Ok(key_package)
+    }
+
+    /// Serialize this key package.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        let (identifier, rest) = bytes.split_at_mut(Identifier::BYTE_SIZE);
+        let (signing_share, rest) = rest.split_at_mut(32);
+        let (verifying_share, rest) = rest.split_at_mut(POINT_SIZE);
+        let (group_public_key, min_signers) = rest.split_at_mut(POINT_SIZE);
+
+        identifier.copy_from_slice(&self.identifier.to_bytes());
+        signing_share.copy_from_slice(&self.signing_share.to_bytes());
+        verifying_share.copy_from_slice(&self.verifying_share.as_affine().to_bytes());
+        group_public_key.copy_from_slice(&self.group_public_key.as_affine().to_bytes());
+        min_signers.copy_from_slice(&self.min_signers.to_be_bytes());
+        bytes
+    }
+
+    /// Get the identifier of this participant.
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// Get the public key corresponding to the signing share of this
+    /// participant.
+    pub fn verifying_share(&self) -> &PublicKey {
+        &self.verifying_share
+    }
+
+    /// Get the group public key.
+    pub fn group_public_key(&self) -> &PublicKey {
+        &self.group_public_key
+    }
+
+    /// Get the minimum number of participants required to sign.
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+impl Drop for KeyPackage {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for KeyPackage {}
+
+/// Public key material shared by all participants, which is needed to verify
+/// signature shares and signatures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicKeyPackage {
+    verifying_shares: BTreeMap<Identifier, PublicKey>,
+    group_public_key: PublicKey,
+}
+
+impl PublicKeyPackage {
+    /// Create a public key package from the verifying shares of all
+    /// participants and the group public key.
+    pub fn new(
+        verifying_shares: BTreeMap<Identifier, PublicKey>,
+        group_public_key: PublicKey,
+    ) -> Self {
+        Self {
+            verifying_shares,
+            group_public_key,
+        }
+    }
+
+    /// Get the verifying shares of all participants.
+    pub fn verifying_shares(&self) -> &BTreeMap<Identifier, PublicKey> {
+        &self.verifying_shares
+    }
+
+    /// Get the group public key.
+    pub fn group_public_key(&self) -> &PublicKey {
+        &self.group_public_key
+    }
+
+    /// Get the x-only group public key which signatures produced using the
+    /// [`Secp256k1Sha256Taproot`] ciphersuite verify under.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey::from_bytes(&self.group_public_key.as_affine().x.to_bytes())
+            .expect("invalid group public key")
+    }
+}
+
+/// Generate a random group secret key and split it into `max_signers`
+/// shares, any `min_signers` of which can sign.
+pub fn trusted_dealer_keygen(
+    min_signers: u16,
+    max_signers: u16,
+    mut rng: impl CryptoRng + RngCore,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage)> {
+    let secret = NonZeroScalar::random(&mut rng);
+    split(&secret, min_signers, max_signers, rng)
+}
+
+/// Split an existing group secret key into `max_signers` shares, any
+/// `min_signers` of which can sign.
+///
+/// The shares are assigned the identifiers `1` to `max_signers`.
+pub fn split(
+    secret: &NonZeroScalar,
+    min_signers: u16,
+    max_signers: u16,
+    mut rng: impl CryptoRng + RngCore,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage)> {
+    validate_num_signers(min_signers, max_signers)?;
+
+    let mut coefficients = Vec::with_capacity(min_signers.into());
+    coefficients.push(**secret);
+    coefficients.extend((1..min_signers).map(|_| Scalar::random(&mut rng)));
+
+    let result = split_with_coefficients(&coefficients, max_signers);
+    coefficients.iter_mut().for_each(Zeroize::zeroize);
+    result
+}
+
+/// Split the secret `coefficients[0]` using the polynomial with the given
+/// coefficients.
+fn split_with_coefficients(
+    coefficients: &[Scalar],
+    max_signers: u16,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage)> {
+    let commitment = coefficients
+        .iter()
+        .map(|coefficient| (ProjectivePoint::GENERATOR * coefficient).to_affine())
+        .collect::<Vec<_>>();
+
+    let mut shares = BTreeMap::new();
+    let mut verifying_shares = BTreeMap::new();
+
+    for id in 1..=max_signers {
+        let identifier = Identifier::new(id)?;
+        let signing_share = evaluate_polynomial(coefficients, &identifier.0);
+        let verifying_share = to_public_key(ProjectivePoint::GENERATOR * signing_share)?;
+
+        verifying_shares.insert(identifier, verifying_share);
+        shares.insert(
+            identifier,
+            SecretShare {
+                identifier,
+                signing_share,
+                commitment: commitment.clone(),
+            },
+        );
+    }
+
+    let group_public_key = to_public_key(commitment[0].into())?;
+    Ok((
+        shares,
+        PublicKeyPackage::new(verifying_shares, group_public_key),
+    ))
+}
+
+/// Secret nonces of a participant for a single signing operation.
+///
+/// Must only be used for a single [`SigningPackage::sign`] call.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    /// Get the commitments to these nonces.
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+
+    /// Derive nonces from the given random bytes and the signing share
+    /// (`nonce_generate`).
+    fn from_randomness<C: Ciphersuite>(
+        hiding_randomness: &[u8; 32],
+        binding_randomness: &[u8; 32],
+        signing_share: &Scalar,
+    ) -> Self {
+        let signing_share = signing_share.to_bytes();
+        let hiding = hash_to_scalar::<C>(b"nonce", &[hiding_randomness, &signing_share]);
+        let binding = hash_to_scalar::<C>(b"nonce", &[binding_randomness, &signing_share]);
+
+        Self {
+            hiding,
+            binding,
+            commitments: SigningCommitments {
+                hiding: (ProjectivePoint::GENERATOR * hiding).to_affine(),
+                binding: (ProjectivePoint::GENERATOR * binding).to_affine(),
+            },
+        }
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SigningNonces {}
+
+/// Commitments to the nonces of a participant, which are sent to the
+/// coordinator.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SigningCommitments {
+    hiding: AffinePoint,
+    binding: AffinePoint,
+}
+
+impl SigningCommitments {
+    /// Size of serialized signing commitments in bytes.
+    pub const BYTE_SIZE: usize = 2 * POINT_SIZE;
+
+    /// Parse signing commitments, which consist of the hiding and binding
+    /// nonce commitments as compressed points.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (hiding, binding) = bytes.split_at(POINT_SIZE);
+        let hiding = PublicKey::from_sec1_bytes(hiding).map_err(|_| Error::new())?;
+        let binding = PublicKey::from_sec1_bytes(binding).map_err(|_| Error::new())?;
+
+        Ok(Self {
+            hiding: *hiding.as_affine(),
+            binding: *binding.as_affine(),
+        })
+    }
+
+    /// Serialize these signing commitments.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        bytes[..POINT_SIZE].copy_from_slice(&self.hiding.to_bytes());
+        bytes[POINT_SIZE..].copy_from_slice(&self.binding.to_bytes());
+        bytes
+    }
+}
+
+/// Generate fresh signing nonces and the corresponding commitments for the
+/// participant with the given key package (`commit`).
+pub fn commit<C: Ciphersuite>(
+    key_package: &KeyPackage,
+    mut rng: impl CryptoRng + RngCore,
+) -> (SigningNonces, SigningCommitments) {
+    let mut hiding_randomness = [0u8; 32];
+    let mut binding_randomness = [0u8; 32];
+    rng.fill_bytes(&mut hiding_randomness);
+    rng.fill_bytes(&mut binding_randomness);
+
+    let nonces = SigningNonces::from_randomness::<C>(
+        &hiding_randomness,
+        &binding_randomness,
+        &key_package.signing_share,
+    );
+
+    hiding_randomness.zeroize();
+    binding_randomness.zeroize();
+
+    let commitments = nonces.commitments;
+    (nonces, commitments)
+}
+
+/// Signature share produced by a single participant.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SignatureShare(Scalar);
+
+impl SignatureShare {
+    /// Size of a serialized signature share in bytes.
+    pub const BYTE_SIZE: usize = 32;
+
+    /// Parse a signature share.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        Option::from(Scalar::from_repr(*FieldBytes::from_slice(bytes)))
+            .map(Self)
+            .ok_or_else(Error::new)
+    }
+
+    /// Serialize this signature share.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+}
+
+/// Signature produced using the [`Secp256k1Sha256`] ciphersuite.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    r: AffinePoint,
+    z: Scalar,
+}
+
+impl Signature {
+    /// Size of a serialized signature in bytes.
+    pub const BYTE_SIZE: usize = POINT_SIZE + 32;
+
+    /// Parse a signature, which consists of the group commitment as a
+    /// compressed point followed by the response scalar.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r, z) = bytes.split_at(POINT_SIZE);
+        let r = PublicKey::from_sec1_bytes(r).map_err(|_| Error::new())?;
+        let z =
+            Option::from(Scalar::from_repr(*FieldBytes::from_slice(z))).ok_or_else(Error::new)?;
+
+        Ok(Self {
+            r: *r.as_affine(),
+            z,
+        })
+    }
+
+    /// Serialize this signature.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        bytes[..POINT_SIZE].copy_from_slice(&self.r.to_bytes());
+        bytes[POINT_SIZE..].copy_from_slice(&self.z.to_bytes());
+        bytes
+    }
+
+    /// Verify this signature over `msg` under the group public key.
+    pub fn verify(&self, group_public_key: &PublicKey, msg: &[u8]) -> Result<()> {
+        let group_public_key = group_public_key.as_affine();
+        let c = Secp256k1Sha256::challenge(&self.r, group_public_key, msg);
+        verify_signature(&self.r, &self.z, group_public_key, &c)
+    }
+}
+
+/// Signing package for a single message, sent by the coordinator to the
+/// selected participants.
+#[derive(Clone, Debug)]
+pub struct SigningPackage<C: Ciphersuite> {
+    commitments: BTreeMap<Identifier, SigningCommitments>,
+    message: Vec<u8>,
+    ciphersuite: PhantomData<C>,
+}
+
+impl<C: Ciphersuite> SigningPackage<C> {
+    /// Create a signing package for `msg` from the commitments of the
+    /// participants selected for signing.
+    pub fn new(commitments: BTreeMap<Identifier, SigningCommitments>, msg: &[u8]) -> Self {
+        Self {
+            commitments,
+            message: msg.to_vec(),
+            ciphersuite: PhantomData,
+        }
+    }
+
+    /// Get the commitments of the participants selected for signing.
+    pub fn commitments(&self) -> &BTreeMap<Identifier, SigningCommitments> {
+        &self.commitments
+    }
+
+    /// Get the message to be signed.
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// Compute the signature share of the participant with the given key
+    /// package, consuming its signing nonces (`sign`).
+    pub fn sign(&self, nonces: SigningNonces, key_package: &KeyPackage) -> Result<SignatureShare> {
+        let identifier = &key_package.identifier;
+
+        if self.commitments.len() < key_package.min_signers.into()
+            || self.commitments.get(identifier) != Some(&nonces.commitments)
+        {
+            return Err(Error::new());
+        }
+
+        let state = self.state(&key_package.group_public_key)?;
+        let rho = state.binding_factors[identifier];
+        let lambda = self.lagrange_coefficient(identifier)?;
+
+        let (d, e) = if state.negate_nonces {
+            (-nonces.hiding, -nonces.binding)
+        } else {
+            (nonces.hiding, nonces.binding)
+        };
+
+        let s = state.key_sign * key_package.signing_share;
+        Ok(SignatureShare(d + e * rho + lambda * s * state.c))
+    }
+
+    /// Verify the signature share of the participant with the given
+    /// identifier (`verify_signature_share`).
+    ///
+    /// This allows identifying which participant provided an invalid
+    /// signature share if [`SigningPackage::aggregate`] fails.
+    pub fn verify_share(
+        &self,
+        identifier: &Identifier,
+        signature_share: &SignatureShare,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<()> {
+        let commitments = self.commitments.get(identifier).ok_or_else(Error::new)?;
+        let verifying_share = public_key_package
+            .verifying_shares
+            .get(identifier)
+            .ok_or_else(Error::new)?;
+
+        let state = self.state(&public_key_package.group_public_key)?;
+        let rho = state.binding_factors[identifier];
+        let lambda = self.lagrange_coefficient(identifier)?;
+
+        let r = ProjectivePoint::lincomb(
+            &commitments.hiding.into(),
+            &Scalar::ONE,
+            &commitments.binding.into(),
+            &rho,
+        );
+
+        let r = if state.negate_nonces { -r } else { r };
+
+        // Check `z×G - c×λ×Y == R`
+        let expected = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &signature_share.0,
+            &verifying_share.to_projective(),
+            &-(state.c * lambda * state.key_sign),
+        );
+
+        if expected == r {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Aggregate the signature shares of all selected participants into the
+    /// final signature (`aggregate`).
+    pub fn aggregate(
+        &self,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<C::Signature> {
+        if !signature_shares.keys().eq(self.commitments.keys()) {
+            return Err(Error::new());
+        }
+
+        let state = self.state(&public_key_package.group_public_key)?;
+        let z = signature_shares
+            .values()
+            .fold(Scalar::ZERO, |z, share| z + share.0);
+
+        verify_signature(&state.r, &z, &state.group_public_key, &state.c)?;
+        C::signature(&state.r, &z)
+    }
+
+    /// Compute the values shared by all participants.
+    fn state(&self, group_public_key: &PublicKey) -> Result<SigningState> {
+        let mut group_public_key = *group_public_key.as_affine();
+
+        let key_sign = if C::EVEN_Y && !has_even_y(&group_public_key) {
+            group_public_key = -group_public_key;
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        let mut encoded_commitments = Vec::with_capacity(
+            self.commitments.len() * (Identifier::BYTE_SIZE + SigningCommitments::BYTE_SIZE),
+        );
+
+        for (identifier, commitments) in &self.commitments {
+            encoded_commitments.extend_from_slice(&identifier.to_bytes());
+            encoded_commitments.extend_from_slice(&commitments.to_bytes());
+        }
+
+        let binding_factor_prefix = [
+            group_public_key.to_bytes().as_slice(),
+            &hash_to_bytes::<C>(b"msg", &self.message),
+            &hash_to_bytes::<C>(b"com", &encoded_commitments),
+        ]
+        .concat();
+
+        let binding_factors = self
+            .commitments
+            .keys()
+            .map(|identifier| {
+                let rho =
+                    hash_to_scalar::<C>(b"rho", &[&binding_factor_prefix, &identifier.to_bytes()]);
+                (*identifier, rho)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let r = self
+            .commitments
+            .iter()
+            .map(|(identifier, commitments)| {
+                ProjectivePoint::lincomb(
+                    &commitments.hiding.into(),
+                    &Scalar::ONE,
+                    &commitments.binding.into(),
+                    &binding_factors[identifier],
+                )
+            })
+            .sum::<ProjectivePoint>()
+            .to_affine();
+
+        if r.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        let negate_nonces = C::EVEN_Y && !has_even_y(&r);
+        let r = if negate_nonces { -r } else { r };
+        let c = C::challenge(&r, &group_public_key, &self.message);
+
+        Ok(SigningState {
+            binding_factors,
+            r,
+            negate_nonces,
+            group_public_key,
+            key_sign,
+            c,
+        })
+    }
+
+    /// Compute the Lagrange coefficient of the participant with the given
+    /// identifier for the set of selected participants.
+    fn lagrange_coefficient(&self, identifier: &Identifier) -> Result<Scalar> {
+        if !self.commitments.contains_key(identifier) {
+            return Err(Error::new());
+        }
+
+        let mut numerator = Scalar::ONE;
+        let mut denominator = Scalar::ONE;
+
+        for x in self.commitments.keys().filter(|x| *x != identifier) {
+            numerator *= x.0;
+            denominator *= x.0 - identifier.0;
+        }
+
+        Option::from(denominator.invert())
+            .map(|denominator: Scalar| numerator * denominator)
+            .ok_or_else(Error::new)
+    }
+}
+
+/// Values derived from a [`SigningPackage`] which are common to all
+/// participants.
+struct SigningState {
+    /// Binding factor of each participant
+    binding_factors: BTreeMap<Identifier, Scalar>,
+
+    /// Group commitment, negated if necessary
+    r: AffinePoint,
+
+    /// Whether the group commitment was negated
+    negate_nonces: bool,
+
+    /// Group public key, negated if necessary
+    group_public_key: AffinePoint,
+
+    /// `-1` if the group public key was negated, `1` otherwise
+    key_sign: Scalar,
+
+    /// Challenge
+    c: Scalar,
+}
+
+/// Hash the concatenation of `msg` to a scalar using the domain separation
+/// tag `CONTEXT_STRING || tag`.
+fn hash_to_scalar<C: Ciphersuite>(tag: &[u8], msg: &[&[u8]]) -> Scalar {
+    let dst = [C::CONTEXT_STRING, tag].concat();
+    let mut scalar = [Scalar::ZERO];
+    hash_to_field::<ExpandMsgXmd<Sha256>, Scalar>(msg, &dst, &mut scalar)
+        .expect("invalid domain separation tag");
+    scalar[0]
+}
+
+/// Hash `msg` using SHA-256 prefixed with `CONTEXT_STRING || tag`.
+fn hash_to_bytes<C: Ciphersuite>(tag: &[u8], msg: &[u8]) -> FieldBytes {
+    Sha256::new()
+        .chain_update(C::CONTEXT_STRING)
+        .chain_update(tag)
+        .chain_update(msg)
+        .finalize()
+}
+
+/// Check `z×G - c×P == R`.
+fn verify_signature(
+    r: &AffinePoint,
+    z: &Scalar,
+    public_key: &AffinePoint,
+    c: &Scalar,
+) -> Result<()> {
+    let expected =
+        ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, z, &(*public_key).into(), &-c);
+
+    if expected == (*r).into() {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Evaluate the polynomial with the given coefficients at `x`.
+fn evaluate_polynomial(coefficients: &[Scalar], x: &Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::ZERO, |acc, coefficient| acc * x + coefficient)
+}
+
+/// Evaluate the commitment to a polynomial at `x`, i.e. compute `f(x)×G`.
+fn evaluate_commitment(commitment: &[AffinePoint], x: &Scalar) -> ProjectivePoint {
+    commitment
+        .iter()
+        .rev()
+        .fold(ProjectivePoint::IDENTITY, |acc, coefficient| {
+            acc * x + coefficient
+        })
+}
+
+fn validate_num_signers(min_signers: u16, max_signers: u16) -> Result<()> {
+    if min_signers < 2 || min_signers > max_signers {
+        Err(Error::new())
+    } else {
+        Ok(())
+    }
+}
+
+fn to_public_key(point: ProjectivePoint) -> Result<PublicKey> {
+    PublicKey::from_affine(point.to_affine()).map_err(|_| Error::new())
+}
+
+/// Parse a compressed point which is not the identity (`DeserializeElement`).
+fn deserialize_element(bytes: &[u8]) -> Result<PublicKey> {
+    if bytes.len() != POINT_SIZE {
+        return Err(Error::new());
+    }
+
+    PublicKey::from_sec1_bytes(bytes).map_err(|_| Error::new())
+}
+
+/// Parse a canonical big-endian scalar (`DeserializeScalar`).
+fn deserialize_scalar(bytes: &[u8]) -> Result<Scalar> {
+    if bytes.len() != 32 {
+        return Err(Error::new());
+    }
+
+    Option::from(Scalar::from_repr(*FieldBytes::from_slice(bytes))).ok_or_else(Error::new)
+}
+
+/// Parse the commitment to a polynomial of degree `min_signers - 1`, which is
+/// a sequence of `min_signers` compressed points.
+fn deserialize_commitment(bytes: &[u8]) -> Result<Vec<AffinePoint>> {
+    let len = bytes.len() / POINT_SIZE;
+
+    if bytes.len() % POINT_SIZE != 0 || len < 2 || len > usize::from(u16::MAX) {
+        return Err(Error::new());
+    }
+
+    bytes
+        .chunks(POINT_SIZE)
+        .map(|point| deserialize_element(point).map(|point| *point.as_affine()))
+        .collect()
+}
+
+/// Append the commitment to a polynomial to `bytes` as a sequence of
+/// compressed points.
+fn serialize_commitment(commitment: &[AffinePoint], bytes: &mut Vec<u8>) {
+    for point in commitment {
+        bytes.extend_from_slice(&point.to_bytes());
+    }
+}
+
+fn has_even_y(point: &AffinePoint) -> bool {
+    point.y.normalize().is_even().into()
+}
+
+// Test vectors from:
+// https://www.rfc-editor.org/rfc/rfc9591.html#name-frostsecp256k1-sha-256-2
+#[cfg(test)]
+mod tests {
+    use super::{
+        commit, split_with_coefficients, trusted_dealer_keygen, BTreeMap, Ciphersuite, Identifier,
+        KeyPackage, PublicKeyPackage, Scalar, Secp256k1Sha256, Secp256k1Sha256Taproot, SecretShare,
+        SignatureShare, SigningCommitments, SigningNonces, SigningPackage, Vec,
+    };
+    use elliptic_curve::{ff::PrimeField, group::GroupEncoding};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    const GROUP_SECRET_KEY: [u8; 32] =
+        hex!("0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114");
+    const GROUP_PUBLIC_KEY: [u8; 33] =
+        hex!("02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f");
+    const COEFFICIENT: [u8; 32] =
+        hex!("fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579");
+    const SHARES: [[u8; 32]; 3] = [
+        hex!("08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c"),
+        hex!("04f0feac2edcedc6ce1253b7fab8c86b856a797f44d83d82a385554e6e401984"),
+        hex!("00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc"),
+    ];
+    const MESSAGE: &[u8] = &hex!("74657374");
+
+    /// Round one and two test vector of a single participant
+    struct SignerVector {
+        /// Identifier of the participant
+        identifier: u16,
+
+        /// Hiding nonce randomness
+        hiding_nonce_randomness: [u8; 32],
+
+        /// Binding nonce randomness
+        binding_nonce_randomness: [u8; 32],
+
+        /// Expected hiding nonce
+        hiding_nonce: [u8; 32],
+
+        /// Expected binding nonce
+        binding_nonce: [u8; 32],
+
+        /// Expected hiding and binding nonce commitments
+        commitments: [u8; 66],
+
+        /// Expected binding factor
+        binding_factor: [u8; 32],
+
+        /// Expected signature share
+        sig_share: [u8; 32],
+    }
+
+    const SIGNER_VECTORS: &[SignerVector] = &[
+        SignerVector {
+            identifier: 1,
+            hiding_nonce_randomness: hex!(
+                "7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2"
+            ),
+            binding_nonce_randomness: hex!(
+                "47acab018f116020c10cb9b9abdc7ac10aae1b48ca6e36dc15acb6ec9be5cdc5"
+            ),
+            hiding_nonce: hex!("841d3a6450d7580b4da83c8e618414d0f024391f2aeb511d7579224420aa81f0"),
+            binding_nonce: hex!("8d2624f532af631377f33cf44b5ac5f849067cae2eacb88680a31e77c79b5a80"),
+            commitments: hex!(
+                "03c699af97d26bb4d3f05232ec5e1938c12f1e6ae97643c8f8f11c9820303f1904
+                 02fa2aaccd51b948c9dc1a325d77226e98a5a3fe65fe9ba213761a60123040a45e"
+            ),
+            binding_factor: hex!(
+                "3e08fe561e075c653cbfd46908a10e7637c70c74f0a77d5fd45d1a750c739ec6"
+            ),
+            sig_share: hex!("c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197"),
+        },
+        SignerVector {
+            identifier: 3,
+            hiding_nonce_randomness: hex!(
+                "e6cc56ccbd0502b3f6f831d91e2ebd01c4de0479e0191b66895a4ffd9b68d544"
+            ),
+            binding_nonce_randomness: hex!(
+                "7203d55eb82a5ca0d7d83674541ab55f6e76f1b85391d2c13706a89a064fd5b9"
+            ),
+            hiding_nonce: hex!("2b19b13f193f4ce83a399362a90cdc1e0ddcd83e57089a7af0bdca71d47869b2"),
+            binding_nonce: hex!("7a443bde83dc63ef52dda354005225ba0e553243402a4705ce28ffaafe0f5b98"),
+            commitments: hex!(
+                "03077507ba327fc074d2793955ef3410ee3f03b82b4cdc2370f71d865beb926ef6
+                 02ad53031ddfbbacfc5fbda3d3b0c2445c8e3e99cbc4ca2db2aa283fa68525b135"
+            ),
+            binding_factor: hex!(
+                "93f79041bb3fd266105be251adaeb5fd7f8b104fb554a4ba9a0becea48ddbfd7"
+            ),
+            sig_share: hex!("0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d"),
+        },
+    ];
+
+    const SIGNATURE: [u8; 65] = hex!(
+        "0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0
+         c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324"
+    );
+
+    fn scalar(bytes: &[u8; 32]) -> Scalar {
+        Scalar::from_repr((*bytes).into()).unwrap()
+    }
+
+    fn key_packages(
+        min_signers: u16,
+        max_signers: u16,
+    ) -> (BTreeMap<Identifier, KeyPackage>, PublicKeyPackage) {
+        let (shares, public_key_package) =
+            trusted_dealer_keygen(min_signers, max_signers, &mut OsRng).unwrap();
+
+        let key_packages = shares
+            .iter()
+            .map(|(identifier, share)| (*identifier, share.verify().unwrap()))
+            .collect();
+
+        (key_packages, public_key_package)
+    }
+
+    fn round1<C: Ciphersuite>(
+        key_packages: &[&KeyPackage],
+    ) -> (Vec<SigningNonces>, BTreeMap<Identifier, SigningCommitments>) {
+        let mut nonces = Vec::new();
+        let mut commitments = BTreeMap::new();
+
+        for key_package in key_packages {
+            let (signing_nonces, signing_commitments) = commit::<C>(key_package, &mut OsRng);
+            nonces.push(signing_nonces);
+            commitments.insert(key_package.identifier(), signing_commitments);
+        }
+
+        (nonces, commitments)
+    }
+
+    #[test]
+    fn rfc9591_vectors() {
+        let (shares, public_key_package) =
+            split_with_coefficients(&[scalar(&GROUP_SECRET_KEY), scalar(&COEFFICIENT)], 3).unwrap();
+
+        assert_eq!(
+            public_key_package.group_public_key().as_affine().to_bytes()[..],
+            GROUP_PUBLIC_KEY
+        );
+
+        for (share, expected) in shares.values().zip(&SHARES) {
+            assert_eq!(&share.signing_share.to_bytes()[..], expected);
+        }
+
+        let mut nonces = Vec::new();
+        let mut commitments = BTreeMap::new();
+
+        for vector in SIGNER_VECTORS {
+            let identifier = Identifier::new(vector.identifier).unwrap();
+            let signing_nonces = SigningNonces::from_randomness::<Secp256k1Sha256>(
+                &vector.hiding_nonce_randomness,
+                &vector.binding_nonce_randomness,
+                &shares[&identifier].signing_share,
+            );
+
+            assert_eq!(signing_nonces.hiding.to_bytes()[..], vector.hiding_nonce);
+            assert_eq!(signing_nonces.binding.to_bytes()[..], vector.binding_nonce);
+            assert_eq!(signing_nonces.commitments().to_bytes(), vector.commitments);
+
+            commitments.insert(identifier, *signing_nonces.commitments());
+            nonces.push(signing_nonces);
+        }
+
+        let signing_package = SigningPackage::<Secp256k1Sha256>::new(commitments, MESSAGE);
+        let state = signing_package
+            .state(public_key_package.group_public_key())
+            .unwrap();
+        let mut signature_shares = BTreeMap::new();
+
+        for (vector, signing_nonces) in SIGNER_VECTORS.iter().zip(nonces) {
+            let identifier = Identifier::new(vector.identifier).unwrap();
+            assert_eq!(
+                state.binding_factors[&identifier].to_bytes()[..],
+                vector.binding_factor
+            );
+
+            let key_package = shares[&identifier].verify().unwrap();
+            let signature_share = signing_package.sign(signing_nonces, &key_package).unwrap();
+            assert_eq!(signature_share.to_bytes()[..], vector.sig_share);
+
+            signing_package
+                .verify_share(&identifier, &signature_share, &public_key_package)
+                .unwrap();
+            signature_shares.insert(identifier, signature_share);
+        }
+
+        let signature = signing_package
+            .aggregate(&signature_shares, &public_key_package)
+            .unwrap();
+        assert_eq!(signature.to_bytes(), SIGNATURE);
+        signature
+            .verify(public_key_package.group_public_key(), MESSAGE)
+            .unwrap();
+    }
+
+    #[test]
+    fn secret_share_and_key_package_encoding() {
+        let (shares, _) =
+            split_with_coefficients(&[scalar(&GROUP_SECRET_KEY), scalar(&COEFFICIENT)], 3).unwrap();
+
+        for (share, expected) in shares.values().zip(&SHARES) {
+            let bytes = share.to_bytes();
+            assert_eq!(bytes.len(), 32 + 32 + 2 * 33);
+            assert_eq!(bytes[..32], share.identifier().to_bytes()[..]);
+            assert_eq!(&bytes[32..64], expected);
+            assert_eq!(bytes[64..97], GROUP_PUBLIC_KEY);
+
+            let share = SecretShare::from_bytes(&bytes).unwrap();
+            assert_eq!(share.to_bytes(), bytes);
+            assert!(SecretShare::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+            assert!(SecretShare::from_bytes(&bytes[..bytes.len() - 33]).is_err());
+
+            let key_package = share.verify().unwrap();
+            let mut bytes = key_package.to_bytes();
+            assert_eq!(&bytes[32..64], expected);
+            assert_eq!(bytes[97..130], GROUP_PUBLIC_KEY);
+            assert_eq!(bytes[130..], [0, 2]);
+
+            let key_package = KeyPackage::from_bytes(&bytes).unwrap();
+            assert_eq!(key_package.to_bytes(), bytes);
+            assert!(KeyPackage::from_bytes(&bytes[1..]).is_err());
+
+            // the verifying share must match the signing share
+            bytes[63] ^= 1;
+            assert!(KeyPackage::from_bytes(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn taproot_sign_and_aggregate() {
+        let (key_packages, public_key_package) = key_packages(3, 5);
+        let signers = key_packages.values().skip(1).take(3).collect::<Vec<_>>();
+        let (nonces, commitments) = round1::<Secp256k1Sha256Taproot>(&signers);
+
+        let msg = [0x42; 32];
+        let signing_package = SigningPackage::<Secp256k1Sha256Taproot>::new(commitments, &msg);
+        let signature_shares = nonces
+            .into_iter()
+            .zip(&signers)
+            .map(|(signing_nonces, key_package)| {
+                let share = signing_package.sign(signing_nonces, key_package).unwrap();
+                (key_package.identifier(), share)
+            })
+            .collect();
+
+        let signature = signing_package
+            .aggregate(&signature_shares, &public_key_package)
+            .unwrap();
+
+        public_key_package
+            .verifying_key()
+            .verify_prehashed(&msg, &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn invalid_signature_share() {
+        let (key_packages, public_key_package) = key_packages(2, 3);
+        let signers = key_packages.values().take(2).collect::<Vec<_>>();
+        let (nonces, commitments) = round1::<Secp256k1Sha256>(&signers);
+
+        let signing_package = SigningPackage::<Secp256k1Sha256>::new(commitments, MESSAGE);
+        let mut signature_shares = nonces
+            .into_iter()
+            .zip(&signers)
+            .map(|(signing_nonces, key_package)| {
+                let share = signing_package.sign(signing_nonces, key_package).unwrap();
+                (key_package.identifier(), share)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let identifier = signers[1].identifier();
+        let share = signature_shares[&identifier];
+        signature_shares.insert(identifier, SignatureShare(share.0 + Scalar::ONE));
+
+        assert!(signing_package
+            .verify_share(
+                &signers[0].identifier(),
+                &signature_shares[&signers[0].identifier()],
+                &public_key_package
+            )
+            .is_ok());
+        assert!(signing_package
+            .verify_share(
+                &identifier,
+                &signature_shares[&identifier],
+                &public_key_package
+            )
+            .is_err());
+        assert!(signing_package
+            .aggregate(&signature_shares, &public_key_package)
+            .is_err());
+    }
+
+    #[test]
+    fn too_few_signers() {
+        let (key_packages, _) = key_packages(3, 3);
+        let signers = key_packages.values().take(2).collect::<Vec<_>>();
+        let (mut nonces, commitments) = round1::<Secp256k1Sha256>(&signers);
+
+        let signing_package = SigningPackage::<Secp256k1Sha256>::new(commitments, MESSAGE);
+        assert!(signing_package.sign(nonces.remove(0), signers[0]).is_err());
+    }
+}
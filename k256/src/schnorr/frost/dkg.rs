@@ -0,0 +1,482 @@
+//! Distributed key generation (DKG) without a trusted dealer.
+//!
+//! This is the Pedersen DKG with proofs of knowledge described in the
+//! [FROST paper], which takes two rounds:
+//!
+//! 1. Each participant calls [`part1`] and sends the resulting
+//!    [`Round1Package`] to all other participants over a broadcast channel.
+//! 2. After receiving the round 1 packages of all other participants, each
+//!    participant calls [`part2`] and sends each other participant its
+//!    [`Round2Package`] over a confidential and authenticated channel.
+//!
+//! Finally, after receiving the round 2 packages of all other participants,
+//! each participant calls [`part3`] to obtain its [`KeyPackage`] and the
+//! [`PublicKeyPackage`].
+//!
+//! [FROST paper]: https://eprint.iacr.org/2020/852.pdf
+
+use super::{
+    deserialize_commitment, deserialize_element, deserialize_scalar, evaluate_commitment,
+    evaluate_polynomial, hash_to_scalar, serialize_commitment, to_public_key, validate_num_signers,
+    Ciphersuite, Identifier, KeyPackage, PublicKeyPackage, POINT_SIZE,
+};
+use crate::{AffinePoint, FieldBytes, ProjectivePoint, Scalar};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::iter;
+use ecdsa_core::signature::{Error, Result};
+use elliptic_curve::{
+    group::{ff::Field, GroupEncoding},
+    ops::LinearCombination,
+    rand_core::{CryptoRng, RngCore},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+
+/// Secret state of a participant after [`part1`].
+pub struct Round1SecretPackage {
+    identifier: Identifier,
+    coefficients: Vec<Scalar>,
+    commitment: Vec<AffinePoint>,
+    max_signers: u16,
+}
+
+impl Drop for Round1SecretPackage {
+    fn drop(&mut self) {
+        self.coefficients.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+impl ZeroizeOnDrop for Round1SecretPackage {}
+
+/// Package broadcast by a participant to all other participants after
+/// [`part1`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round1Package {
+    /// Commitment to the participant's secret polynomial
+    commitment: Vec<AffinePoint>,
+
+    /// Proof of knowledge of the participant's secret: commitment
+    proof_r: AffinePoint,
+
+    /// Proof of knowledge of the participant's secret: response
+    proof_mu: Scalar,
+}
+
+impl Round1Package {
+    /// Parse a round 1 package, which consists of the commitment to the
+    /// participant's secret polynomial as a sequence of compressed points,
+    /// followed by the proof of knowledge as a compressed point and a scalar.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let commitment_len = bytes
+            .len()
+            .checked_sub(POINT_SIZE + 32)
+            .ok_or_else(Error::new)?;
+
+        let (commitment, proof) = bytes.split_at(commitment_len);
+        let (proof_r, proof_mu) = proof.split_at(POINT_SIZE);
+
+        Ok(Self {
+            commitment: deserialize_commitment(commitment)?,
+            proof_r: *deserialize_element(proof_r)?.as_affine(),
+            proof_mu: deserialize_scalar(proof_mu)?,
+        })
+    }
+
+    /// Serialize this round 1 package.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity((self.commitment.len() + 1) * POINT_SIZE + 32);
+        serialize_commitment(&self.commitment, &mut bytes);
+        bytes.extend_from_slice(&self.proof_r.to_bytes());
+        bytes.extend_from_slice(&self.proof_mu.to_bytes());
+        bytes
+    }
+
+    /// Get the commitment to the participant's secret polynomial.
+    pub fn commitment(&self) -> &[AffinePoint] {
+        &self.commitment
+    }
+}
+
+/// Secret state of a participant after [`part2`].
+pub struct Round2SecretPackage {
+    identifier: Identifier,
+    commitment: Vec<AffinePoint>,
+    signing_share: Scalar,
+    max_signers: u16,
+}
+
+impl Drop for Round2SecretPackage {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Round2SecretPackage {}
+
+/// Package sent privately by a participant to another participant after
+/// [`part2`].
+#[derive(Clone)]
+pub struct Round2Package {
+    /// Evaluation of the sender's secret polynomial at the recipient's
+    /// identifier
+    signing_share: Scalar,
+}
+
+impl Round2Package {
+    /// Size of a serialized round 2 package in bytes.
+    pub const BYTE_SIZE: usize = 32;
+
+    /// Parse a round 2 package, which consists of the signing share as a
+    /// scalar.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        deserialize_scalar(bytes).map(|signing_share| Self { signing_share })
+    }
+
+    /// Serialize this round 2 package.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.signing_share.to_bytes()
+    }
+}
+
+impl Drop for Round2Package {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Round2Package {}
+
+/// Generate a random secret polynomial for the participant with the given
+/// identifier, along with a commitment to it and a proof of knowledge of
+/// its secret.
+pub fn part1<C: Ciphersuite>(
+    identifier: Identifier,
+    min_signers: u16,
+    max_signers: u16,
+    mut rng: impl CryptoRng + RngCore,
+) -> Result<(Round1SecretPackage, Round1Package)> {
+    validate_num_signers(min_signers, max_signers)?;
+
+    let coefficients = (0..min_signers)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let commitment = coefficients
+        .iter()
+        .map(|coefficient| (ProjectivePoint::GENERATOR * coefficient).to_affine())
+        .collect::<Vec<_>>();
+
+    let mut k = Scalar::random(&mut rng);
+    let proof_r = (ProjectivePoint::GENERATOR * k).to_affine();
+    let c = proof_challenge::<C>(&identifier, &commitment[0], &proof_r);
+    let proof_mu = k + coefficients[0] * c;
+    k.zeroize();
+
+    let package = Round1Package {
+        commitment: commitment.clone(),
+        proof_r,
+        proof_mu,
+    };
+
+    let secret_package = Round1SecretPackage {
+        identifier,
+        coefficients,
+        commitment,
+        max_signers,
+    };
+
+    Ok((secret_package, package))
+}
+
+/// Verify the round 1 packages of all other participants and compute the
+/// round 2 packages to be sent to each of them.
+pub fn part2<C: Ciphersuite>(
+    secret_package: Round1SecretPackage,
+    round1_packages: &BTreeMap<Identifier, Round1Package>,
+) -> Result<(Round2SecretPackage, BTreeMap<Identifier, Round2Package>)> {
+    if round1_packages.len() + 1 != usize::from(secret_package.max_signers)
+        || round1_packages.contains_key(&secret_package.identifier)
+    {
+        return Err(Error::new());
+    }
+
+    let mut round2_packages = BTreeMap::new();
+
+    for (identifier, package) in round1_packages {
+        if package.commitment.len() != secret_package.coefficients.len() {
+            return Err(Error::new());
+        }
+
+        let c = proof_challenge::<C>(identifier, &package.commitment[0], &package.proof_r);
+
+        // Check `μ×G - c×C₀ == R`
+        let expected = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &package.proof_mu,
+            &package.commitment[0].into(),
+            &-c,
+        );
+
+        if expected != package.proof_r.into() {
+            return Err(Error::new());
+        }
+
+        let signing_share = evaluate_polynomial(&secret_package.coefficients, &identifier.0);
+        round2_packages.insert(*identifier, Round2Package { signing_share });
+    }
+
+    let round2_secret_package = Round2SecretPackage {
+        identifier: secret_package.identifier,
+        commitment: secret_package.commitment.clone(),
+        signing_share: evaluate_polynomial(
+            &secret_package.coefficients,
+            &secret_package.identifier.0,
+        ),
+        max_signers: secret_package.max_signers,
+    };
+
+    Ok((round2_secret_package, round2_packages))
+}
+
+/// Verify the round 2 packages received from all other participants and
+/// compute the key package of this participant and the public key package.
+pub fn part3(
+    secret_package: &Round2SecretPackage,
+    round1_packages: &BTreeMap<Identifier, Round1Package>,
+    round2_packages: &BTreeMap<Identifier, Round2Package>,
+) -> Result<(KeyPackage, PublicKeyPackage)> {
+    if round1_packages.len() + 1 != usize::from(secret_package.max_signers)
+        || !round1_packages.keys().eq(round2_packages.keys())
+    {
+        return Err(Error::new());
+    }
+
+    let mut signing_share = secret_package.signing_share;
+    let mut group_commitment = secret_package
+        .commitment
+        .iter()
+        .map(ProjectivePoint::from)
+        .collect::<Vec<_>>();
+
+    for (package, round2_package) in round1_packages.values().zip(round2_packages.values()) {
+        if package.commitment.len() != group_commitment.len() {
+            signing_share.zeroize();
+            return Err(Error::new());
+        }
+
+        let expected = evaluate_commitment(&package.commitment, &secret_package.identifier.0);
+
+        if expected != ProjectivePoint::GENERATOR * round2_package.signing_share {
+            signing_share.zeroize();
+            return Err(Error::new());
+        }
+
+        signing_share += round2_package.signing_share;
+
+        for (sum, coefficient) in group_commitment.iter_mut().zip(&package.commitment) {
+            *sum += coefficient;
+        }
+    }
+
+    let group_commitment = group_commitment
+        .iter()
+        .map(ProjectivePoint::to_affine)
+        .collect::<Vec<_>>();
+
+    let verifying_shares = iter::once(&secret_package.identifier)
+        .chain(round1_packages.keys())
+        .map(|identifier| {
+            let verifying_share = evaluate_commitment(&group_commitment, &identifier.0);
+            Ok((*identifier, to_public_key(verifying_share)?))
+        })
+        .collect::<Result<BTreeMap<_, _>>>();
+
+    let key_package = KeyPackage {
+        identifier: secret_package.identifier,
+        signing_share,
+        verifying_share: to_public_key(ProjectivePoint::GENERATOR * signing_share)?,
+        group_public_key: to_public_key(group_commitment[0].into())?,
+        min_signers: group_commitment.len() as u16,
+    };
+
+    signing_share.zeroize();
+    let public_key_package = PublicKeyPackage::new(verifying_shares?, key_package.group_public_key);
+
+    if public_key_package.verifying_shares[&key_package.identifier] != key_package.verifying_share {
+        return Err(Error::new());
+    }
+
+    Ok((key_package, public_key_package))
+}
+
+/// Compute the challenge of the proof of knowledge of a participant's secret.
+fn proof_challenge<C: Ciphersuite>(
+    identifier: &Identifier,
+    verifying_key: &AffinePoint,
+    r: &AffinePoint,
+) -> Scalar {
+    hash_to_scalar::<C>(
+        b"dkg",
+        &[
+            &identifier.to_bytes(),
+            &verifying_key.to_bytes(),
+            &r.to_bytes(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, part3, Round1Package, Round2Package};
+    use crate::schnorr::frost::{
+        commit, Identifier, KeyPackage, PublicKeyPackage, Secp256k1Sha256Taproot, SigningPackage,
+    };
+    use crate::Scalar;
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use ecdsa_core::signature::Result;
+    use rand_core::OsRng;
+
+    type C = Secp256k1Sha256Taproot;
+
+    /// Run the DKG with `max_signers` participants, optionally tampering with
+    /// the round 2 package sent from participant 1 to participant 2.
+    fn dkg(
+        min_signers: u16,
+        max_signers: u16,
+        tamper: bool,
+    ) -> Vec<Result<(KeyPackage, PublicKeyPackage)>> {
+        let identifiers = (1..=max_signers)
+            .map(|id| Identifier::new(id).unwrap())
+            .collect::<Vec<_>>();
+
+        let mut round1_secrets = Vec::new();
+        let mut round1_packages = BTreeMap::<Identifier, Round1Package>::new();
+
+        for identifier in &identifiers {
+            let (secret, package) =
+                part1::<C>(*identifier, min_signers, max_signers, &mut OsRng).unwrap();
+            round1_secrets.push(secret);
+            round1_packages.insert(*identifier, package);
+        }
+
+        let others = |identifier: &Identifier| {
+            round1_packages
+                .iter()
+                .filter(|(id, _)| *id != identifier)
+                .map(|(id, package)| (*id, package.clone()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut round2_secrets = Vec::new();
+        let mut round2_packages = BTreeMap::<(Identifier, Identifier), Round2Package>::new();
+
+        for secret in round1_secrets {
+            let sender = secret.identifier;
+            let (round2_secret, packages) = part2::<C>(secret, &others(&sender)).unwrap();
+            round2_secrets.push(round2_secret);
+
+            for (recipient, mut package) in packages {
+                if tamper && sender == identifiers[0] && recipient == identifiers[1] {
+                    package.signing_share += Scalar::ONE;
+                }
+
+                round2_packages.insert((sender, recipient), package);
+            }
+        }
+
+        round2_secrets
+            .iter()
+            .map(|secret| {
+                let recipient = secret.identifier;
+                let received = round2_packages
+                    .iter()
+                    .filter(|((_, to), _)| *to == recipient)
+                    .map(|((from, _), package)| (*from, package.clone()))
+                    .collect();
+
+                part3(secret, &others(&recipient), &received)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dkg_sign_and_aggregate() {
+        let results = dkg(2, 3, false)
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let public_key_package = &results[0].1;
+        assert!(results.iter().all(|(_, pkp)| pkp == public_key_package));
+
+        let signers = [&results[0].0, &results[2].0];
+        let mut nonces = Vec::new();
+        let mut commitments = BTreeMap::new();
+
+        for key_package in signers {
+            let (signing_nonces, signing_commitments) = commit::<C>(key_package, &mut OsRng);
+            nonces.push(signing_nonces);
+            commitments.insert(key_package.identifier(), signing_commitments);
+        }
+
+        let msg = [0x17; 32];
+        let signing_package = SigningPackage::<C>::new(commitments, &msg);
+        let signature_shares = nonces
+            .into_iter()
+            .zip(signers)
+            .map(|(signing_nonces, key_package)| {
+                let share = signing_package.sign(signing_nonces, key_package).unwrap();
+                (key_package.identifier(), share)
+            })
+            .collect();
+
+        let signature = signing_package
+            .aggregate(&signature_shares, public_key_package)
+            .unwrap();
+
+        public_key_package
+            .verifying_key()
+            .verify_prehashed(&msg, &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn dkg_package_encoding() {
+        let identifiers = [Identifier::new(1).unwrap(), Identifier::new(2).unwrap()];
+        let (secret, _) = part1::<C>(identifiers[0], 2, 2, &mut OsRng).unwrap();
+        let (_, package) = part1::<C>(identifiers[1], 2, 2, &mut OsRng).unwrap();
+
+        let bytes = package.to_bytes();
+        assert_eq!(bytes.len(), 3 * 33 + 32);
+        assert_eq!(Round1Package::from_bytes(&bytes).unwrap(), package);
+        assert!(Round1Package::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Round1Package::from_bytes(&bytes[33..]).is_err());
+
+        let mut round1_packages = BTreeMap::new();
+        round1_packages.insert(identifiers[1], package);
+        let (_, round2_packages) = part2::<C>(secret, &round1_packages).unwrap();
+
+        let bytes = round2_packages[&identifiers[1]].to_bytes();
+        assert_eq!(Round2Package::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        assert!(Round2Package::from_bytes(&[0xff; Round2Package::BYTE_SIZE]).is_err());
+    }
+
+    #[test]
+    fn dkg_invalid_share() {
+        let results = dkg(2, 3, true);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn dkg_invalid_proof_of_knowledge() {
+        let identifiers = [Identifier::new(1).unwrap(), Identifier::new(2).unwrap()];
+        let (secret, _) = part1::<C>(identifiers[0], 2, 2, &mut OsRng).unwrap();
+        let (_, mut package) = part1::<C>(identifiers[1], 2, 2, &mut OsRng).unwrap();
+        package.proof_mu += Scalar::ONE;
+
+        let mut round1_packages = BTreeMap::new();
+        round1_packages.insert(identifiers[1], package);
+        assert!(part2::<C>(secret, &round1_packages).is_err());
+    }
+}
@@ -0,0 +1,1291 @@
+//! MuSig2 multi-signatures as defined in [BIP327].
+//!
+//! MuSig2 allows `n` signers to jointly produce a single [BIP340] Schnorr
+//! [`Signature`] which verifies under an aggregate public key, e.g. for
+//! Taproot key-path spends. Signing takes two rounds:
+//!
+//! 1. Each signer generates a [`SecNonce`]/[`PubNonce`] pair and sends the
+//!    public nonce to the other signers. The public nonces are combined into
+//!    an [`AggNonce`].
+//! 2. Each signer creates a [`Session`] from the [`KeyAggContext`], the
+//!    aggregate nonce and the message, and produces a [`PartialSignature`]
+//!    which is sent to the aggregator.
+//!
+//! The partial signatures are then combined into a final [`Signature`].
+//!
+//! # ⚠️ Warning
+//!
+//! A [`SecNonce`] must never be used more than once: signing two different
+//! messages with the same secret nonce leaks the signer's secret key.
+//! [`Session::sign`] takes the secret nonce by value to help enforce this.
+//!
+//! # Usage
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use k256::{
+//!     schnorr::musig2::{nonce_gen, AggNonce, KeyAggContext, Session},
+//!     PublicKey, SecretKey,
+//! };
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let secret_keys = [SecretKey::random(&mut OsRng), SecretKey::random(&mut OsRng)];
+//! let public_keys = secret_keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+//! let key_agg = KeyAggContext::new(&public_keys)?;
+//! let msg = b"MuSig2 multi-signatures are indistinguishable from single-signer ones";
+//!
+//! // First round: exchange public nonces
+//! let (secnonces, pubnonces): (Vec<_>, Vec<_>) = secret_keys
+//!     .iter()
+//!     .zip(&public_keys)
+//!     .map(|(sk, pk)| nonce_gen(&mut OsRng, pk, Some(sk), Some(&key_agg), Some(msg), None))
+//!     .collect::<Result<Vec<_>, _>>()?
+//!     .into_iter()
+//!     .unzip();
+//!
+//! // Second round: exchange partial signatures
+//! let session = Session::new(&key_agg, &AggNonce::new(&pubnonces), msg);
+//! let partial_signatures = secnonces
+//!     .into_iter()
+//!     .zip(&secret_keys)
+//!     .map(|(secnonce, sk)| session.sign(secnonce, sk))
+//!     .collect::<Result<Vec<_>, _>>()?;
+//!
+//! let signature = session.aggregate(&partial_signatures)?;
+//! assert_eq!(signature.as_bytes().len(), 64);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [BIP327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use super::{tagged_hash, Signature, VerifyingKey, CHALLENGE_TAG};
+use crate::{
+    AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey,
+};
+use alloc::vec::Vec;
+use ecdsa_core::signature::{Error, Result};
+use elliptic_curve::{
+    bigint::U256,
+    group::{ff::PrimeField, prime::PrimeCurveAffine, GroupEncoding},
+    ops::{LinearCombination, Reduce},
+    rand_core::{CryptoRng, RngCore},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+use sha2::Digest;
+
+const KEYAGG_LIST_TAG: &[u8] = b"KeyAgg list";
+const KEYAGG_COEFF_TAG: &[u8] = b"KeyAgg coefficient";
+const AUX_TAG: &[u8] = b"MuSig/aux";
+const NONCE_TAG: &[u8] = b"MuSig/nonce";
+const NONCE_COEFF_TAG: &[u8] = b"MuSig/noncecoef";
+
+/// Size of a compressed SEC1 point in bytes.
+const POINT_SIZE: usize = 33;
+
+/// Aggregate public key of a set of MuSig2 signers, along with any tweaks
+/// which have been applied to it.
+#[derive(Clone, Debug)]
+pub struct KeyAggContext {
+    /// Individual public keys, in the order they were aggregated
+    pubkeys: Vec<PublicKey>,
+
+    /// Hash of the list of individual public keys
+    list_hash: FieldBytes,
+
+    /// First public key which differs from the first one in the list
+    second_key: Option<PublicKey>,
+
+    /// Aggregate public key
+    q: AffinePoint,
+
+    /// Accumulated sign of the aggregate public key
+    gacc: Scalar,
+
+    /// Accumulated tweak
+    tacc: Scalar,
+}
+
+impl KeyAggContext {
+    /// Aggregate the given individual public keys (`KeyAgg`).
+    ///
+    /// The order of the keys matters: all signers must use the same order.
+    pub fn new(pubkeys: &[PublicKey]) -> Result<Self> {
+        let first_key = pubkeys.first().ok_or_else(Error::new)?;
+
+        let mut list_hash = tagged_hash(KEYAGG_LIST_TAG);
+
+        for pk in pubkeys {
+            list_hash.update(pk.as_affine().to_bytes());
+        }
+
+        let mut ctx = Self {
+            pubkeys: pubkeys.to_vec(),
+            list_hash: list_hash.finalize(),
+            second_key: pubkeys.iter().find(|pk| *pk != first_key).copied(),
+            q: AffinePoint::IDENTITY,
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+        };
+
+        ctx.q = pubkeys
+            .iter()
+            .map(|pk| pk.to_projective() * ctx.coefficient(pk))
+            .sum::<ProjectivePoint>()
+            .to_affine();
+
+        if ctx.q.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        Ok(ctx)
+    }
+
+    /// Apply a plain tweak `t` to the aggregate public key `Q`, resulting in
+    /// `Q + t×G`, e.g. for BIP32 derivation.
+    pub fn with_plain_tweak(&self, tweak: &[u8; 32]) -> Result<Self> {
+        self.with_tweak(tweak, false)
+    }
+
+    /// Apply an x-only tweak `t` to the aggregate public key `Q`, resulting in
+    /// `Q' + t×G` where `Q'` is `Q` negated if necessary to have an even `y`
+    /// coordinate, e.g. for a BIP341 Taproot output key.
+    pub fn with_xonly_tweak(&self, tweak: &[u8; 32]) -> Result<Self> {
+        self.with_tweak(tweak, true)
+    }
+
+    /// Get the aggregate public key, including any tweaks.
+    pub fn aggregated_key(&self) -> PublicKey {
+        PublicKey::from_affine(self.q).expect("aggregate key is the identity")
+    }
+
+    /// Get the x-only aggregate public key which final signatures verify
+    /// under, including any tweaks.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey::from_bytes(&self.q.x.to_bytes()).expect("invalid aggregate key")
+    }
+
+    fn with_tweak(&self, tweak: &[u8; 32], is_xonly: bool) -> Result<Self> {
+        let g = if is_xonly && !has_even_y(&self.q) {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        let t =
+            Option::<Scalar>::from(Scalar::from_repr((*tweak).into())).ok_or_else(Error::new)?;
+
+        let q = ProjectivePoint::lincomb(&self.q.into(), &g, &ProjectivePoint::GENERATOR, &t)
+            .to_affine();
+
+        if q.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        Ok(Self {
+            q,
+            gacc: g * self.gacc,
+            tacc: t + g * self.tacc,
+            ..self.clone()
+        })
+    }
+
+    /// Compute the key aggregation coefficient of `pk`.
+    fn coefficient(&self, pk: &PublicKey) -> Scalar {
+        if self.second_key.as_ref() == Some(pk) {
+            return Scalar::ONE;
+        }
+
+        <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            tagged_hash(KEYAGG_COEFF_TAG)
+                .chain_update(self.list_hash)
+                .chain_update(pk.as_affine().to_bytes())
+                .finalize(),
+        )
+    }
+
+    /// Compute the key aggregation coefficient of `pk`, ensuring it is one of
+    /// the aggregated public keys.
+    fn session_coefficient(&self, pk: &PublicKey) -> Result<Scalar> {
+        if self.pubkeys.contains(pk) {
+            Ok(self.coefficient(pk))
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Secret nonce of a signer.
+///
+/// Must only be used for a single [`Session::sign`] call.
+pub struct SecNonce {
+    k1: Scalar,
+    k2: Scalar,
+    pk: PublicKey,
+}
+
+impl SecNonce {
+    /// Size of a serialized secret nonce in bytes.
+    pub const BYTE_SIZE: usize = 64 + POINT_SIZE;
+
+    /// Parse a secret nonce, which consists of two scalars followed by the
+    /// compressed public key of the signer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let k1 = nonzero_scalar_from_bytes(&bytes[..32])?;
+        let k2 = nonzero_scalar_from_bytes(&bytes[32..64])?;
+        let pk = PublicKey::from_sec1_bytes(&bytes[64..]).map_err(|_| Error::new())?;
+
+        Ok(Self { k1, k2, pk })
+    }
+
+    /// Serialize this secret nonce.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// Storing secret nonces makes it easy to accidentally reuse them.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        bytes[..32].copy_from_slice(&self.k1.to_bytes());
+        bytes[32..64].copy_from_slice(&self.k2.to_bytes());
+        bytes[64..].copy_from_slice(&self.pk.as_affine().to_bytes());
+        bytes
+    }
+}
+
+impl Drop for SecNonce {
+    fn drop(&mut self) {
+        self.k1.zeroize();
+        self.k2.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecNonce {}
+
+/// Public nonce of a signer, which is sent to the other signers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PubNonce {
+    r1: AffinePoint,
+    r2: AffinePoint,
+}
+
+impl PubNonce {
+    /// Size of a serialized public nonce in bytes.
+    pub const BYTE_SIZE: usize = 2 * POINT_SIZE;
+
+    /// Parse a public nonce, which consists of two compressed points.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r1, r2) = bytes.split_at(POINT_SIZE);
+        let r1 = PublicKey::from_sec1_bytes(r1).map_err(|_| Error::new())?;
+        let r2 = PublicKey::from_sec1_bytes(r2).map_err(|_| Error::new())?;
+
+        Ok(Self {
+            r1: *r1.as_affine(),
+            r2: *r2.as_affine(),
+        })
+    }
+
+    /// Serialize this public nonce.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        points_to_bytes(&self.r1, &self.r2)
+    }
+}
+
+/// Aggregate of the public nonces of all signers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AggNonce {
+    r1: AffinePoint,
+    r2: AffinePoint,
+}
+
+impl AggNonce {
+    /// Size of a serialized aggregate nonce in bytes.
+    pub const BYTE_SIZE: usize = 2 * POINT_SIZE;
+
+    /// Aggregate the public nonces of all signers (`NonceAgg`).
+    pub fn new(pubnonces: &[PubNonce]) -> Self {
+        let r1 = pubnonces
+            .iter()
+            .map(|nonce| ProjectivePoint::from(nonce.r1));
+        let r2 = pubnonces
+            .iter()
+            .map(|nonce| ProjectivePoint::from(nonce.r2));
+
+        Self {
+            r1: r1.sum::<ProjectivePoint>().to_affine(),
+            r2: r2.sum::<ProjectivePoint>().to_affine(),
+        }
+    }
+
+    /// Parse an aggregate nonce, which consists of two compressed points
+    /// where the identity is encoded as 33 zero bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        let (r1, r2) = bytes.split_at(POINT_SIZE);
+        let r1 = AffinePoint::from_bytes(r1.into());
+        let r2 = AffinePoint::from_bytes(r2.into());
+
+        Ok(Self {
+            r1: Option::from(r1).ok_or_else(Error::new)?,
+            r2: Option::from(r2).ok_or_else(Error::new)?,
+        })
+    }
+
+    /// Serialize this aggregate nonce.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        points_to_bytes(&self.r1, &self.r2)
+    }
+}
+
+/// Generate a fresh secret and public nonce pair (`NonceGen`).
+///
+/// Only `rng` and the public key `pk` of the signer are required. Providing
+/// the signer's secret key, the key aggregation context, the message and any
+/// extra input is optional, but hardens the nonce generation against a bad
+/// RNG.
+pub fn nonce_gen(
+    mut rng: impl CryptoRng + RngCore,
+    pk: &PublicKey,
+    sk: Option<&SecretKey>,
+    key_agg: Option<&KeyAggContext>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> Result<(SecNonce, PubNonce)> {
+    let mut rand = [0u8; 32];
+    rng.fill_bytes(&mut rand);
+
+    let aggpk = key_agg.map(|ctx| ctx.q.x.to_bytes());
+    let nonces = nonce_gen_with_rand(&rand, pk, sk, aggpk.as_ref(), msg, extra_in);
+    rand.zeroize();
+    nonces
+}
+
+/// Generate a secret and public nonce pair (`NonceGen`) from the given
+/// random bytes `rand`.
+///
+/// `aggpk` is the x-only aggregate public key, see
+/// [`KeyAggContext::verifying_key`].
+///
+/// # ⚠️ Warning
+///
+/// This is a low-level interface intended only for testing. `rand` must be
+/// freshly generated for every call, otherwise the secret key can leak. The
+/// preferred interface is [`nonce_gen`].
+pub fn nonce_gen_with_rand(
+    rand: &[u8; 32],
+    pk: &PublicKey,
+    sk: Option<&SecretKey>,
+    aggpk: Option<&FieldBytes>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> Result<(SecNonce, PubNonce)> {
+    let mut rand = *rand;
+
+    if let Some(sk) = sk {
+        let aux = tagged_hash(AUX_TAG).chain_update(rand).finalize();
+
+        for ((r, a), b) in rand.iter_mut().zip(aux.iter()).zip(sk.to_be_bytes().iter()) {
+            *r = a ^ b;
+        }
+    }
+
+    let pk_bytes = pk.as_affine().to_bytes();
+    let aggpk = aggpk.map(|aggpk| aggpk.as_slice()).unwrap_or_default();
+    let extra_in = extra_in.unwrap_or_default();
+
+    let mut k = [Scalar::ZERO; 2];
+
+    for (i, k_i) in (0u8..).zip(k.iter_mut()) {
+        let mut hash = tagged_hash(NONCE_TAG)
+            .chain_update(rand)
+            .chain_update([pk_bytes.len() as u8])
+            .chain_update(pk_bytes)
+            .chain_update([aggpk.len() as u8])
+            .chain_update(aggpk);
+
+        match msg {
+            Some(msg) => {
+                hash.update([1]);
+                hash.update((msg.len() as u64).to_be_bytes());
+                hash.update(msg);
+            }
+            None => hash.update([0]),
+        }
+
+        *k_i = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            hash.chain_update((extra_in.len() as u32).to_be_bytes())
+                .chain_update(extra_in)
+                .chain_update([i])
+                .finalize(),
+        );
+    }
+
+    rand.zeroize();
+
+    if k.iter().any(|k_i| bool::from(k_i.is_zero())) {
+        return Err(Error::new());
+    }
+
+    let secnonce = SecNonce {
+        k1: k[0],
+        k2: k[1],
+        pk: *pk,
+    };
+
+    let pubnonce = PubNonce {
+        r1: (ProjectivePoint::GENERATOR * k[0]).to_affine(),
+        r2: (ProjectivePoint::GENERATOR * k[1]).to_affine(),
+    };
+
+    k.zeroize();
+    Ok((secnonce, pubnonce))
+}
+
+/// Partial signature produced by a single signer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PartialSignature(Scalar);
+
+impl PartialSignature {
+    /// Size of a serialized partial signature in bytes.
+    pub const BYTE_SIZE: usize = 32;
+
+    /// Parse a partial signature.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE {
+            return Err(Error::new());
+        }
+
+        Option::from(Scalar::from_repr(*FieldBytes::from_slice(bytes)))
+            .map(Self)
+            .ok_or_else(Error::new)
+    }
+
+    /// Serialize this partial signature.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+}
+
+/// Signing session for a single message, shared by all signers.
+#[derive(Clone, Debug)]
+pub struct Session {
+    /// Key aggregation context
+    key_agg: KeyAggContext,
+
+    /// Nonce coefficient
+    b: Scalar,
+
+    /// Final nonce
+    r: AffinePoint,
+
+    /// Challenge
+    e: Scalar,
+}
+
+impl Session {
+    /// Create a signing session for `msg` from the key aggregation context
+    /// and the aggregate nonce.
+    pub fn new(key_agg: &KeyAggContext, aggnonce: &AggNonce, msg: &[u8]) -> Self {
+        let q_bytes = key_agg.q.x.to_bytes();
+
+        let b = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            tagged_hash(NONCE_COEFF_TAG)
+                .chain_update(aggnonce.to_bytes())
+                .chain_update(q_bytes)
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        let r =
+            ProjectivePoint::lincomb(&aggnonce.r1.into(), &Scalar::ONE, &aggnonce.r2.into(), &b)
+                .to_affine();
+
+        let r = if r.is_identity().into() {
+            AffinePoint::GENERATOR
+        } else {
+            r
+        };
+
+        let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+            tagged_hash(CHALLENGE_TAG)
+                .chain_update(r.x.to_bytes())
+                .chain_update(q_bytes)
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        Self {
+            key_agg: key_agg.clone(),
+            b,
+            r,
+            e,
+        }
+    }
+
+    /// Compute the partial signature of the signer with secret key `sk`,
+    /// consuming its secret nonce (`Sign`).
+    pub fn sign(&self, secnonce: SecNonce, sk: &SecretKey) -> Result<PartialSignature> {
+        let pk = sk.public_key();
+
+        if pk != secnonce.pk {
+            return Err(Error::new());
+        }
+
+        let a = self.key_agg.session_coefficient(&pk)?;
+        let (k1, k2) = if has_even_y(&self.r) {
+            (secnonce.k1, secnonce.k2)
+        } else {
+            (-secnonce.k1, -secnonce.k2)
+        };
+
+        let d = self.key_sign() * *sk.to_nonzero_scalar();
+        let s = k1 + self.b * k2 + self.e * a * d;
+        let psig = PartialSignature(s);
+
+        // Verify the partial signature before releasing it, as recommended by
+        // BIP327 to protect against fault attacks
+        let pubnonce = PubNonce {
+            r1: (ProjectivePoint::GENERATOR * secnonce.k1).to_affine(),
+            r2: (ProjectivePoint::GENERATOR * secnonce.k2).to_affine(),
+        };
+        self.verify_partial(&psig, &pubnonce, &pk)?;
+
+        Ok(psig)
+    }
+
+    /// Verify the partial signature of the signer with public key `pk` and
+    /// public nonce `pubnonce` (`PartialSigVerify`).
+    ///
+    /// This allows identifying which signer provided an invalid partial
+    /// signature if [`Session::aggregate`] produces an invalid signature.
+    pub fn verify_partial(
+        &self,
+        psig: &PartialSignature,
+        pubnonce: &PubNonce,
+        pk: &PublicKey,
+    ) -> Result<()> {
+        let a = self.key_agg.session_coefficient(pk)?;
+
+        let re = ProjectivePoint::lincomb(
+            &pubnonce.r1.into(),
+            &Scalar::ONE,
+            &pubnonce.r2.into(),
+            &self.b,
+        );
+
+        let re = if has_even_y(&self.r) { re } else { -re };
+
+        // Check `s×G - e×a×g'×P == Re`
+        let expected = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &psig.0,
+            &pk.to_projective(),
+            &-(self.e * a * self.key_sign()),
+        );
+
+        if expected == re {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Aggregate the partial signatures of all signers into the final
+    /// signature (`PartialSigAgg`).
+    pub fn aggregate(&self, psigs: &[PartialSignature]) -> Result<Signature> {
+        let g = if has_even_y(&self.key_agg.q) {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        };
+
+        let s = psigs
+            .iter()
+            .fold(self.e * g * self.key_agg.tacc, |s, psig| s + psig.0);
+
+        let mut bytes = [0u8; Signature::BYTE_SIZE];
+        let (r_bytes, s_bytes) = bytes.split_at_mut(Signature::BYTE_SIZE / 2);
+        r_bytes.copy_from_slice(&self.r.x.to_bytes());
+        s_bytes.copy_from_slice(&s.to_bytes());
+        Signature::try_from(&bytes[..])
+    }
+
+    /// Compute `g⋅gacc`, which is `-1` or `1` depending on the sign the
+    /// individual secret keys need to have in the final signature.
+    fn key_sign(&self) -> Scalar {
+        if has_even_y(&self.key_agg.q) {
+            self.key_agg.gacc
+        } else {
+            -self.key_agg.gacc
+        }
+    }
+}
+
+fn has_even_y(point: &AffinePoint) -> bool {
+    point.y.normalize().is_even().into()
+}
+
+fn nonzero_scalar_from_bytes(bytes: &[u8]) -> Result<Scalar> {
+    NonZeroScalar::try_from(bytes)
+        .map(|scalar| *scalar)
+        .map_err(|_| Error::new())
+}
+
+fn points_to_bytes(r1: &AffinePoint, r2: &AffinePoint) -> [u8; 2 * POINT_SIZE] {
+    let mut bytes = [0u8; 2 * POINT_SIZE];
+    bytes[..POINT_SIZE].copy_from_slice(&r1.to_bytes());
+    bytes[POINT_SIZE..].copy_from_slice(&r2.to_bytes());
+    bytes
+}
+
+// Test vectors from:
+// https://github.com/bitcoin/bips/tree/master/bip-0327
+#[cfg(test)]
+mod tests {
+    use super::{
+        nonce_gen, nonce_gen_with_rand, AggNonce, KeyAggContext, PartialSignature, PubNonce,
+        SecNonce, Session,
+    };
+    use crate::{FieldBytes, PublicKey, SecretKey};
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    const SECRET_KEY: [u8; 32] =
+        hex!("7FB9E0E687ADA1EEBF7ECFE2F21E73EBDB51A7D450948DFE8D76D7F2D1007671");
+
+    const SECNONCE: [u8; 97] = hex!(
+        "508B81A611F100A6B2B6B29656590898AF488BCF2E1F55CF22E5CFB84421FE61
+         FA27FD49B1D50085B481285E1CA205D55C82CC1B31FF5CD54A489829355901F7
+         03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9"
+    );
+
+    const PUBNONCES: [[u8; 66]; 4] = [
+        hex!(
+            "0337C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA
+             0287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480"
+        ),
+        hex!(
+            "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798
+             0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+        ),
+        hex!(
+            "032DE2662628C90B03F5E720284EB52FF7D71F4284F627B68A853D78C78E1FFE93
+             03E4C5524E83FFE1493B9077CF1CA6BEB2090C93D930321071AD40B2F44E599046"
+        ),
+        hex!(
+            "0237C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA
+             0387BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480"
+        ),
+    ];
+
+    const MSG: [u8; 32] = hex!("F95466D086770E689964664219266FE5ED215C92AE20BAB5C9D79ADDDDF3C0CF");
+
+    /// Partial signing test vector
+    struct SignVector {
+        /// Indices of the aggregated public keys
+        key_indices: &'static [usize],
+
+        /// Indices of the aggregated public nonces
+        nonce_indices: &'static [usize],
+
+        /// Message
+        msg: &'static [u8],
+
+        /// Expected partial signature
+        expected: [u8; 32],
+    }
+
+    fn public_keys(keys: &[[u8; 33]], indices: &[usize]) -> Vec<PublicKey> {
+        indices
+            .iter()
+            .map(|&i| PublicKey::from_sec1_bytes(&keys[i]).unwrap())
+            .collect()
+    }
+
+    fn aggnonce(indices: &[usize]) -> AggNonce {
+        let pubnonces = indices
+            .iter()
+            .map(|&i| PubNonce::from_bytes(&PUBNONCES[i]).unwrap())
+            .collect::<Vec<_>>();
+
+        AggNonce::new(&pubnonces)
+    }
+
+    #[test]
+    fn key_agg_vectors() {
+        const PUBKEYS: [[u8; 33]; 3] = [
+            hex!("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+            hex!("023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66"),
+        ];
+
+        let vectors: [(&[usize], [u8; 32]); 4] = [
+            (
+                &[0, 1, 2],
+                hex!("90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C"),
+            ),
+            (
+                &[2, 1, 0],
+                hex!("6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B"),
+            ),
+            (
+                &[0, 0, 0],
+                hex!("B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935"),
+            ),
+            (
+                &[0, 0, 1, 1],
+                hex!("69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E"),
+            ),
+        ];
+
+        for (indices, expected) in vectors {
+            let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, indices)).unwrap();
+            assert_eq!(key_agg.verifying_key().to_bytes().as_slice(), &expected);
+        }
+
+        // tweak is out of range
+        let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, &[0, 1])).unwrap();
+        let tweak = hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        assert!(key_agg.with_xonly_tweak(&tweak).is_err());
+
+        // aggregating no keys
+        assert!(KeyAggContext::new(&[]).is_err());
+    }
+
+    #[test]
+    fn nonce_gen_vectors() {
+        let sk = SecretKey::from_be_bytes(&[0x02; 32]).unwrap();
+        let pk = sk.public_key();
+        let aggpk = FieldBytes::from([0x07; 32]);
+
+        let vectors: [(&[u8], [u8; 97]); 2] = [
+            (
+                &[0x01; 32],
+                hex!(
+                    "B114E502BEAA4E301DD08A50264172C84E41650E6CB726B410C0694D59EFFB64
+                     95B5CAF28D045B973D63E3C99A44B807BDE375FD6CB39E46DC4A511708D0E9D2
+                     024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766"
+                ),
+            ),
+            (
+                &[],
+                hex!(
+                    "E862B068500320088138468D47E0E6F147E01B6024244AE45EAC40ACE5929B9F
+                     0789E051170B9E705D0B9EB49049A323BBBBB206D8E05C19F46C6228742AA7A9
+                     024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766"
+                ),
+            ),
+        ];
+
+        for (msg, expected) in vectors {
+            let (secnonce, pubnonce) = nonce_gen_with_rand(
+                &[0x0F; 32],
+                &pk,
+                Some(&sk),
+                Some(&aggpk),
+                Some(msg),
+                Some(&[0x08; 32]),
+            )
+            .unwrap();
+
+            assert_eq!(secnonce.to_bytes(), expected);
+            assert_eq!(
+                PubNonce::from_bytes(&pubnonce.to_bytes()).unwrap(),
+                pubnonce
+            );
+        }
+    }
+
+    #[test]
+    fn nonce_agg_vectors() {
+        const PNONCES: [[u8; 66]; 7] = [
+            hex!(
+                "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E666
+                 03BA47FBC1834437B3212E89A84D8425E7BF12E0245D98262268EBDCB385D50641"
+            ),
+            hex!(
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A6
+                 0248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833"
+            ),
+            hex!(
+                "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E666
+                 0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+            ),
+            hex!(
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A6
+                 0379BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+            ),
+            hex!(
+                "04FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A6
+                 0248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833"
+            ),
+            hex!(
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A6
+                 0248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B831"
+            ),
+            hex!(
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A6
+                 02FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30"
+            ),
+        ];
+
+        let vectors: [(&[usize], [u8; 66]); 2] = [
+            (
+                &[0, 1],
+                hex!(
+                    "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B
+                     024725377345BDE0E9C33AF3C43C0A29A9249F2F2956FA8CFEB55C8573D0262DC8"
+                ),
+            ),
+            // sum of the second points is the point at infinity, which is
+            // serialized as 33 zero bytes
+            (
+                &[2, 3],
+                hex!(
+                    "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B
+                     000000000000000000000000000000000000000000000000000000000000000000"
+                ),
+            ),
+        ];
+
+        for (indices, expected) in vectors {
+            let pubnonces = indices
+                .iter()
+                .map(|&i| PubNonce::from_bytes(&PNONCES[i]).unwrap())
+                .collect::<Vec<_>>();
+
+            let aggnonce = AggNonce::new(&pubnonces);
+            assert_eq!(aggnonce.to_bytes(), expected);
+            assert_eq!(
+                AggNonce::from_bytes(&expected).unwrap().to_bytes(),
+                expected
+            );
+        }
+
+        // wrong tag 0x04 in the first half
+        assert!(PubNonce::from_bytes(&PNONCES[4]).is_err());
+
+        // second half is not an X coordinate
+        assert!(PubNonce::from_bytes(&PNONCES[5]).is_err());
+
+        // second half exceeds the field size
+        assert!(PubNonce::from_bytes(&PNONCES[6]).is_err());
+    }
+
+    #[test]
+    fn sign_verify_vectors() {
+        const PUBKEYS: [[u8; 33]; 3] = [
+            hex!("03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9"),
+            hex!("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA661"),
+        ];
+
+        let sk = SecretKey::from_be_bytes(&SECRET_KEY).unwrap();
+        let pk = sk.public_key();
+
+        assert_eq!(
+            aggnonce(&[0, 1, 2]).to_bytes(),
+            hex!(
+                "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61
+                 037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9"
+            )
+        );
+        assert_eq!(aggnonce(&[0, 3]).to_bytes(), [0; AggNonce::BYTE_SIZE]);
+
+        let vectors = [
+            SignVector {
+                key_indices: &[0, 1, 2],
+                nonce_indices: &[0, 1, 2],
+                msg: &MSG,
+                expected: hex!("012ABBCB52B3016AC03AD82395A1A415C48B93DEF78718E62A7A90052FE224FB"),
+            },
+            SignVector {
+                key_indices: &[1, 0, 2],
+                nonce_indices: &[1, 0, 2],
+                msg: &MSG,
+                expected: hex!("9FF2F7AAA856150CC8819254218D3ADEEB0535269051897724F9DB3789513A52"),
+            },
+            SignVector {
+                key_indices: &[1, 2, 0],
+                nonce_indices: &[1, 2, 0],
+                msg: &MSG,
+                expected: hex!("FA23C359F6FAC4E7796BB93BC9F0532A95468C539BA20FF86D7C76ED92227900"),
+            },
+            // both halves of aggregate nonce are the point at infinity
+            SignVector {
+                key_indices: &[0, 1],
+                nonce_indices: &[0, 3],
+                msg: &MSG,
+                expected: hex!("AE386064B26105404798F75DE2EB9AF5EDA5387B064B83D049CB7C5E08879531"),
+            },
+            // empty message
+            SignVector {
+                key_indices: &[0, 1, 2],
+                nonce_indices: &[0, 1, 2],
+                msg: &[],
+                expected: hex!("D7D63FFD644CCDA4E62BC2BC0B1D02DD32A1DC3030E155195810231D1037D82D"),
+            },
+            // 38-byte message
+            SignVector {
+                key_indices: &[0, 1, 2],
+                nonce_indices: &[0, 1, 2],
+                msg: &[0x26; 38],
+                expected: hex!("E184351828DA5094A97C79CABDAAA0BFB87608C32E8829A4DF5340A6F243B78C"),
+            },
+        ];
+
+        for vector in vectors {
+            let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, vector.key_indices)).unwrap();
+            let session = Session::new(&key_agg, &aggnonce(vector.nonce_indices), vector.msg);
+            let secnonce = SecNonce::from_bytes(&SECNONCE).unwrap();
+            let psig = session.sign(secnonce, &sk).unwrap();
+            assert_eq!(psig.to_bytes().as_slice(), &vector.expected);
+
+            let pubnonce = PubNonce::from_bytes(&PUBNONCES[0]).unwrap();
+            assert!(session.verify_partial(&psig, &pubnonce, &pk).is_ok());
+
+            let wrong_psig = PartialSignature(-psig.0);
+            assert!(session.verify_partial(&wrong_psig, &pubnonce, &pk).is_err());
+        }
+
+        // signer is not one of the aggregated keys
+        let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, &[1, 2])).unwrap();
+        let session = Session::new(&key_agg, &aggnonce(&[0, 1, 2]), &MSG);
+        let secnonce = SecNonce::from_bytes(&SECNONCE).unwrap();
+        assert!(session.sign(secnonce, &sk).is_err());
+
+        // secnonce is for a different signer
+        let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, &[0, 1, 2])).unwrap();
+        let session = Session::new(&key_agg, &aggnonce(&[0, 1, 2]), &MSG);
+        let secnonce = SecNonce::from_bytes(&SECNONCE).unwrap();
+        let other_sk = SecretKey::from_be_bytes(&[0x02; 32]).unwrap();
+        assert!(session.sign(secnonce, &other_sk).is_err());
+
+        // secnonce has already been used (zeroed)
+        let mut used_secnonce = SECNONCE;
+        used_secnonce[..64].fill(0);
+        assert!(SecNonce::from_bytes(&used_secnonce).is_err());
+
+        // invalid public nonce
+        let mut pubnonce = PUBNONCES[0];
+        pubnonce[0] = 0x04;
+        assert!(PubNonce::from_bytes(&pubnonce).is_err());
+
+        // partial signature exceeds group size
+        let psig = hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        assert!(PartialSignature::from_bytes(&psig).is_err());
+    }
+
+    #[test]
+    fn tweak_vectors() {
+        const PUBKEYS: [[u8; 33]; 3] = [
+            hex!("03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9"),
+            hex!("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+        ];
+
+        const TWEAKS: [[u8; 32]; 4] = [
+            hex!("E8F791FF9225A2AF0102AFFF4A9A723D9612A682A25EBE79802B263CDFCD83BB"),
+            hex!("AE2EA797CC0FE72AC5B97B97F3C6957D7E4199A167A58EB08BCAFFDA70AC0455"),
+            hex!("F52ECBC565B3D8BEA2DFD5B75A4F457E54369809322E4120831626F290FA87E0"),
+            hex!("1969AD73CC177FA0B4FCED6DF1F7BF9907E665FDE9BA196A74FED0A3CF5AEF9D"),
+        ];
+
+        let sk = SecretKey::from_be_bytes(&SECRET_KEY).unwrap();
+        let key_agg = KeyAggContext::new(&public_keys(&PUBKEYS, &[1, 2, 0])).unwrap();
+
+        let vectors: [(&[usize], &[bool], [u8; 32]); 5] = [
+            (
+                &[0],
+                &[true],
+                hex!("E28A5C66E61E178C2BA19DB77B6CF9F7E2F0F56C17918CD13135E60CC848FE91"),
+            ),
+            (
+                &[0],
+                &[false],
+                hex!("38B0767798252F21BF5702C48028B095428320F73A4B14DB1E25DE58543D2D2D"),
+            ),
+            (
+                &[0, 1],
+                &[false, true],
+                hex!("408A0A21C4A0F5DACAF9646AD6EB6FECD7F7A11F03ED1F48DFFF2185BC2C2408"),
+            ),
+            (
+                &[0, 1, 2, 3],
+                &[false, false, true, true],
+                hex!("45ABD206E61E3DF2EC9E264A6FEC8292141A633C28586388235541F9ADE75435"),
+            ),
+            (
+                &[0, 1, 2, 3],
+                &[true, false, true, false],
+                hex!("B255FDCAC27B40C7CE7848E2D3B7BF5EA0ED756DA81565AC804CCCA3E1D5D239"),
+            ),
+        ];
+
+        for (tweak_indices, is_xonly, expected) in vectors {
+            let mut tweaked = key_agg.clone();
+
+            for (&i, &is_xonly) in tweak_indices.iter().zip(is_xonly) {
+                tweaked = if is_xonly {
+                    tweaked.with_xonly_tweak(&TWEAKS[i]).unwrap()
+                } else {
+                    tweaked.with_plain_tweak(&TWEAKS[i]).unwrap()
+                };
+            }
+
+            let session = Session::new(&tweaked, &aggnonce(&[1, 2, 0]), &MSG);
+            let secnonce = SecNonce::from_bytes(&SECNONCE).unwrap();
+            let psig = session.sign(secnonce, &sk).unwrap();
+            assert_eq!(psig.to_bytes().as_slice(), &expected);
+        }
+    }
+
+    #[test]
+    fn sig_agg_vectors() {
+        const PUBKEYS: [[u8; 33]; 4] = [
+            hex!("03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9"),
+            hex!("02D2DC6F5DF7C56ACF38C7FA0AE7A759AE30E19B37359DFDE015872324C7EF6E05"),
+            hex!("03C7FB101D97FF930ACD0C6760852EF64E69083DE0B06AC6335724754BB4B0522C"),
+            hex!("02352433B21E7E05D3B452B81CAE566E06D2E003ECE16D1074AABA4289E0E3D581"),
+        ];
+
+        const PNONCES: [[u8; 66]; 6] = [
+            hex!(
+                "036E5EE6E28824029FEA3E8A9DDD2C8483F5AF98F7177C3AF3CB6F47CAF8D94AE9
+                 02DBA67E4A1F3680826172DA15AFB1A8CA85C7C5CC88900905C8DC8C328511B53E"
+            ),
+            hex!(
+                "03E4F798DA48A76EEC1C9CC5AB7A880FFBA201A5F064E627EC9CB0031D1D58FC51
+                 03E06180315C5A522B7EC7C08B69DCD721C313C940819296D0A7AB8E8795AC1F00"
+            ),
+            hex!(
+                "02C0068FD25523A31578B8077F24F78F5BD5F2422AFF47C1FADA0F36B3CEB6C7D2
+                 02098A55D1736AA5FCC21CF0729CCE852575C06C081125144763C2C4C4A05C09B6"
+            ),
+            hex!(
+                "031F5C87DCFBFCF330DEE4311D85E8F1DEA01D87A6F1C14CDFC7E4F1D8C441CFA4
+                 0277BF176E9F747C34F81B0D9F072B1B404A86F402C2D86CF9EA9E9C69876EA3B9"
+            ),
+            hex!(
+                "023F7042046E0397822C4144A17F8B63D78748696A46C3B9F0A901D296EC3406C3
+                 02022B0B464292CF9751D699F10980AC764E6F671EFCA15069BBE62B0D1C62522A"
+            ),
+            hex!(
+                "02D97DDA5988461DF58C5897444F116A7C74E5711BF77A9446E27806563F3B6C47
+                 020CBAD9C363A7737F99FA06B6BE093CEAFF5397316C5AC46915C43767AE867C00"
+            ),
+        ];
+
+        const TWEAKS: [[u8; 32]; 3] = [
+            hex!("B511DA492182A91B0FFB9A98020D55F260AE86D7ECBD0399C7383D59A5F2AF7C"),
+            hex!("A815FE049EE3C5AAB66310477FBC8BCCCAC2F3395F59F921C364ACD78A2F48DC"),
+            hex!("75448A87274B056468B977BE06EB1E9F657577B7320B0A3376EA51FD420D18A8"),
+        ];
+
+        const PSIGS: [[u8; 32]; 9] = [
+            hex!("B15D2CD3C3D22B04DAE438CE653F6B4ECF042F42CFDED7C41B64AAF9B4AF53FB"),
+            hex!("6193D6AC61B354E9105BBDC8937A3454A6D705B6D57322A5A472A02CE99FCB64"),
+            hex!("9A87D3B79EC67228CB97878B76049B15DBD05B8158D17B5B9114D3C226887505"),
+            hex!("66F82EA90923689B855D36C6B7E032FB9970301481B99E01CDB4D6AC7C347A15"),
+            hex!("4F5AEE41510848A6447DCD1BBC78457EF69024944C87F40250D3EF2C25D33EFE"),
+            hex!("DDEF427BBB847CC027BEFF4EDB01038148917832253EBC355FC33F4A8E2FCCE4"),
+            hex!("97B890A26C981DA8102D3BC294159D171D72810FDF7C6A691DEF02F0F7AF3FDC"),
+            hex!("53FA9E08BA5243CBCB0D797C5EE83BC6728E539EB76C2D0BF0F971EE4E909971"),
+            hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+        ];
+
+        const MSG: [u8; 32] =
+            hex!("599C67EA410D005B9DA90817CF03ED3B1C868E4DA4EDF00A5880B0082C237869");
+
+        /// Signature aggregation test vector
+        struct SigAggVector {
+            /// Expected aggregate nonce
+            aggnonce: [u8; 66],
+
+            /// Indices of the aggregated public nonces
+            nonce_indices: &'static [usize],
+
+            /// Indices of the aggregated public keys
+            key_indices: &'static [usize],
+
+            /// Indices of the tweaks and whether they are x-only
+            tweaks: &'static [(usize, bool)],
+
+            /// Indices of the partial signatures
+            psig_indices: &'static [usize],
+
+            /// Expected signature
+            expected: [u8; 64],
+        }
+
+        let key_agg = |vector: &SigAggVector| {
+            vector.tweaks.iter().fold(
+                KeyAggContext::new(&public_keys(&PUBKEYS, vector.key_indices)).unwrap(),
+                |key_agg, &(i, is_xonly)| {
+                    if is_xonly {
+                        key_agg.with_xonly_tweak(&TWEAKS[i]).unwrap()
+                    } else {
+                        key_agg.with_plain_tweak(&TWEAKS[i]).unwrap()
+                    }
+                },
+            )
+        };
+
+        let session = |vector: &SigAggVector| {
+            let pubnonces = vector
+                .nonce_indices
+                .iter()
+                .map(|&i| PubNonce::from_bytes(&PNONCES[i]).unwrap())
+                .collect::<Vec<_>>();
+
+            let aggnonce = AggNonce::new(&pubnonces);
+            assert_eq!(aggnonce.to_bytes(), vector.aggnonce);
+            Session::new(&key_agg(vector), &aggnonce, &MSG)
+        };
+
+        let vectors = [
+            SigAggVector {
+                aggnonce: hex!(
+                    "0341432722C5CD0268D829C702CF0D1CBCE57033EED201FD335191385227C3210C
+                     03D377F2D258B64AADC0E16F26462323D701D286046A2EA93365656AFD9875982B"
+                ),
+                nonce_indices: &[0, 1],
+                key_indices: &[0, 1],
+                tweaks: &[],
+                psig_indices: &[0, 1],
+                expected: hex!(
+                    "041DA22223CE65C92C9A0D6C2CAC828AAF1EEE56304FEC371DDF91EBB2B9EF09
+                     12F1038025857FEDEB3FF696F8B99FA4BB2C5812F6095A2E0004EC99CE18DE1E"
+                ),
+            },
+            SigAggVector {
+                aggnonce: hex!(
+                    "0224AFD36C902084058B51B5D36676BBA4DC97C775873768E58822F87FE437D792
+                     028CB15929099EEE2F5DAE404CD39357591BA32E9AF4E162B8D3E7CB5EFE31CB20"
+                ),
+                nonce_indices: &[0, 2],
+                key_indices: &[0, 2],
+                tweaks: &[],
+                psig_indices: &[2, 3],
+                expected: hex!(
+                    "1069B67EC3D2F3C7C08291ACCB17A9C9B8F2819A52EB5DF8726E17E7D6B52E9F
+                     01800260A7E9DAC450F4BE522DE4CE12BA91AEAF2B4279219EF74BE1D286ADD9"
+                ),
+            },
+            SigAggVector {
+                aggnonce: hex!(
+                    "0208C5C438C710F4F96A61E9FF3C37758814B8C3AE12BFEA0ED2C87FF6954FF186
+                     020B1816EA104B4FCA2D304D733E0E19CEAD51303FF6420BFD222335CAA402916D"
+                ),
+                nonce_indices: &[0, 3],
+                key_indices: &[0, 2],
+                tweaks: &[(0, false)],
+                psig_indices: &[4, 5],
+                expected: hex!(
+                    "5C558E1DCADE86DA0B2F02626A512E30A22CF5255CAEA7EE32C38E9A71A0E914
+                     8BA6C0E6EC7683B64220F0298696F1B878CD47B107B81F7188812D593971E0CC"
+                ),
+            },
+            SigAggVector {
+                aggnonce: hex!(
+                    "02B5AD07AFCD99B6D92CB433FBD2A28FDEB98EAE2EB09B6014EF0F8197CD584033
+                     02E8616910F9293CF692C49F351DB86B25E352901F0E237BAFDA11F1C1CEF29FFD"
+                ),
+                nonce_indices: &[0, 4],
+                key_indices: &[0, 3],
+                tweaks: &[(0, true), (1, false), (2, true)],
+                psig_indices: &[6, 7],
+                expected: hex!(
+                    "839B08820B681DBA8DAF4CC7B104E8F2638F9388F8D7A555DC17B6E6971D7426
+                     CE07BF6AB01F1DB50E4E33719295F4094572B79868E440FB3DEFD3FAC1DB589E"
+                ),
+            },
+        ];
+
+        for vector in &vectors {
+            let psigs = vector
+                .psig_indices
+                .iter()
+                .map(|&i| PartialSignature::from_bytes(&PSIGS[i]).unwrap())
+                .collect::<Vec<_>>();
+
+            let sig = session(vector).aggregate(&psigs).unwrap();
+            assert_eq!(sig.as_ref(), &vector.expected);
+            assert!(key_agg(vector)
+                .verifying_key()
+                .verify_prehashed(&MSG, &sig)
+                .is_ok());
+        }
+
+        // partial signature of signer 1 (`psig_indices: [7, 8]`) exceeds the
+        // group size
+        assert!(PartialSignature::from_bytes(&PSIGS[7]).is_ok());
+        assert!(PartialSignature::from_bytes(&PSIGS[8]).is_err());
+    }
+
+    #[test]
+    fn sign_and_aggregate() {
+        let secret_keys = [
+            SecretKey::random(&mut OsRng),
+            SecretKey::random(&mut OsRng),
+            SecretKey::random(&mut OsRng),
+        ];
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| sk.public_key())
+            .collect::<Vec<_>>();
+
+        let key_agg = KeyAggContext::new(&public_keys).unwrap();
+        let tweaked = key_agg
+            .with_plain_tweak(&[0x11; 32])
+            .unwrap()
+            .with_xonly_tweak(&[0x22; 32])
+            .unwrap();
+
+        for key_agg in [key_agg, tweaked] {
+            let mut secnonces = Vec::new();
+            let mut pubnonces = Vec::new();
+
+            for (sk, pk) in secret_keys.iter().zip(&public_keys) {
+                let (secnonce, pubnonce) =
+                    nonce_gen(&mut OsRng, pk, Some(sk), Some(&key_agg), Some(&MSG), None).unwrap();
+                secnonces.push(secnonce);
+                pubnonces.push(pubnonce);
+            }
+
+            let session = Session::new(&key_agg, &AggNonce::new(&pubnonces), &MSG);
+            let psigs = secnonces
+                .into_iter()
+                .zip(&secret_keys)
+                .map(|(secnonce, sk)| session.sign(secnonce, sk).unwrap())
+                .collect::<Vec<_>>();
+
+            for ((psig, pubnonce), pk) in psigs.iter().zip(&pubnonces).zip(&public_keys) {
+                assert!(session.verify_partial(psig, pubnonce, pk).is_ok());
+            }
+
+            let sig = session.aggregate(&psigs).unwrap();
+            assert!(key_agg.verifying_key().verify_prehashed(&MSG, &sig).is_ok());
+
+            // a missing partial signature results in an invalid signature
+            let sig = session.aggregate(&psigs[1..]).unwrap();
+            assert!(key_agg
+                .verifying_key()
+                .verify_prehashed(&MSG, &sig)
+                .is_err());
+        }
+    }
+}
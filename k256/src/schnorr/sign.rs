@@ -1,6 +1,9 @@
 //! Taproot Schnorr signing key.
 
-use super::{tagged_hash, Signature, VerifyingKey, AUX_TAG, CHALLENGE_TAG, NONCE_TAG};
+use super::{
+    tagged_hash, taproot_tweak, tweak_scalar, Signature, VerifyingKey, AUX_TAG, CHALLENGE_TAG,
+    NONCE_TAG,
+};
 use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
 use ecdsa_core::signature::{
     digest::{consts::U32, FixedOutput},
@@ -71,6 +74,32 @@ impl SigningKey {
         &self.verifying_key
     }
 
+    /// Add the tweak `t` to this signing key, resulting in the signing key
+    /// for the tweaked verifying key `P + t×G`.
+    ///
+    /// This is the [`VerifyingKey::tweak_add`] counterpart for signing keys,
+    /// which negates the result where necessary to have an even `y`
+    /// coordinate as required by BIP340.
+    pub fn tweak_add(&self, tweak: &[u8; 32]) -> Result<Self> {
+        let t = tweak_scalar(tweak)?;
+        let mut tweaked = (*self.secret_key + t).to_bytes();
+        let signing_key = Self::from_bytes(&tweaked);
+        tweaked.zeroize();
+        signing_key
+    }
+
+    /// Compute the [BIP341] Taproot output signing key for this internal
+    /// signing key, committing to the optional script tree `merkle_root`.
+    ///
+    /// Signatures produced by the returned key can be used for key path
+    /// spends of the output key computed by [`VerifyingKey::taproot_tweak`].
+    ///
+    /// [BIP341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+    pub fn taproot_tweak(&self, merkle_root: Option<[u8; 32]>) -> Result<Self> {
+        let t = taproot_tweak(&self.verifying_key, merkle_root)?;
+        self.tweak_add(&t.to_bytes().into())
+    }
+
     /// Compute Schnorr signature.
     ///
     /// # ⚠️ Warning
@@ -1,6 +1,6 @@
 //! Taproot Schnorr verifying key.
 
-use super::{tagged_hash, Signature, CHALLENGE_TAG};
+use super::{tagged_hash, taproot_tweak, tweak_scalar, Signature, CHALLENGE_TAG};
 use crate::{AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar};
 use ecdsa_core::signature::{DigestVerifier, Error, Result, Verifier};
 use elliptic_curve::{
@@ -55,6 +55,64 @@ impl VerifyingKey {
         Ok(())
     }
 
+    /// Add the tweak `t` to this verifying key, resulting in the x-only key
+    /// `P + t×G`.
+    pub fn tweak_add(&self, tweak: &[u8; 32]) -> Result<Self> {
+        let tweaked = self.tweak_add_point(tweak)?;
+        Self::from_bytes(&tweaked.x.to_bytes())
+    }
+
+    /// Compute the point `P + t×G`, keeping its `y` coordinate.
+    fn tweak_add_point(&self, tweak: &[u8; 32]) -> Result<AffinePoint> {
+        let t = tweak_scalar(tweak)?;
+        let tweaked = ProjectivePoint::lincomb(
+            &self.inner.to_projective(),
+            &Scalar::ONE,
+            &ProjectivePoint::GENERATOR,
+            &t,
+        );
+
+        let tweaked = PublicKey::from_affine(tweaked.to_affine()).map_err(|_| Error::new())?;
+        Ok(*tweaked.as_affine())
+    }
+
+    /// Compute the [BIP341] Taproot output key for this internal key,
+    /// committing to the optional script tree `merkle_root`.
+    ///
+    /// With no `merkle_root` the output key can only be spent using the key
+    /// path, see [`SigningKey::taproot_tweak`](super::SigningKey::taproot_tweak).
+    ///
+    /// [BIP341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+    pub fn taproot_tweak(&self, merkle_root: Option<[u8; 32]>) -> Result<Self> {
+        let t = taproot_tweak(self, merkle_root)?;
+        self.tweak_add(&t.to_bytes().into())
+    }
+
+    /// Check that this [BIP341] Taproot output key commits to the given
+    /// internal key and optional script tree `merkle_root`.
+    ///
+    /// `output_parity` is the parity of the `y` coordinate of the tweaked
+    /// output point (i.e. `true` if it is odd), as encoded in the control
+    /// block of a script path spend.
+    ///
+    /// [BIP341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+    pub fn verify_taproot_commitment(
+        &self,
+        internal_key: &VerifyingKey,
+        merkle_root: Option<[u8; 32]>,
+        output_parity: bool,
+    ) -> Result<()> {
+        let t = taproot_tweak(internal_key, merkle_root)?;
+        let tweaked = internal_key.tweak_add_point(&t.to_bytes().into())?;
+        let y_is_odd = tweaked.y.normalize().is_odd();
+
+        if tweaked.x.to_bytes() == self.to_bytes() && bool::from(y_is_odd) == output_parity {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
     /// Borrow the inner [`AffinePoint`] this type wraps.
     pub fn as_affine(&self) -> &AffinePoint {
         self.inner.as_affine()
@@ -8,14 +8,14 @@ use p256::FieldElement;
 
 fn test_field_element_x() -> FieldElement {
     FieldElement::from_sec1(
-        &hex!("1ccbe91c075fc7f4f033bfa248db8fccd3565de94bbfb12f3c59ff46c271bf83").into(),
+        hex!("1ccbe91c075fc7f4f033bfa248db8fccd3565de94bbfb12f3c59ff46c271bf83").into(),
     )
     .unwrap()
 }
 
 fn test_field_element_y() -> FieldElement {
     FieldElement::from_sec1(
-        &hex!("ce4014c68811f9a21a1fdb2c0e6113e06db7ca93b7404e78dc7ccd5ca89a4ca9").into(),
+        hex!("ce4014c68811f9a21a1fdb2c0e6113e06db7ca93b7404e78dc7ccd5ca89a4ca9").into(),
     )
     .unwrap()
 }
@@ -41,11 +41,17 @@ fn bench_field_element_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a,
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_field_element_invert_vartime<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_field_element_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_field_element(c: &mut Criterion) {
     let mut group = c.benchmark_group("field element operations");
     bench_field_element_mul(&mut group);
     bench_field_element_square(&mut group);
     bench_field_element_invert(&mut group);
+    bench_field_element_invert_vartime(&mut group);
     bench_field_element_sqrt(&mut group);
     group.finish();
 }
@@ -11,7 +11,10 @@ use crate::NistP256;
 use elliptic_curve::{
     AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
 };
-use weierstrass::WeierstrassCurve;
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+#[cfg(feature = "precomputed-tables")]
+use {alloc::boxed::Box, once_cell::race::OnceBox, weierstrass::BasepointTable};
 
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = weierstrass::AffinePoint<NistP256>;
@@ -19,6 +22,22 @@ pub type AffinePoint = weierstrass::AffinePoint<NistP256>;
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = weierstrass::ProjectivePoint<NistP256>;
 
+/// Scalar blinded with a randomly generated masking value.
+pub type BlindedScalar = weierstrass::BlindedScalar<Scalar>;
+
+/// Lazily computed multiples of the P-256 generator.
+///
+/// `OnceBox` works without `std`. Threads racing on the first use may each
+/// compute the table, in which case all but one of them are dropped.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: OnceBox<BasepointTable<NistP256, 65>> = OnceBox::new();
+
+/// Returns the multiples of the P-256 generator, computing them on first use.
+#[cfg(feature = "precomputed-tables")]
+fn basepoint_table() -> &'static BasepointTable<NistP256, 65> {
+    BASEPOINT_TABLE.get_or_init(|| Box::new(BasepointTable::new()))
+}
+
 impl WeierstrassCurve for NistP256 {
     type FieldElement = FieldElement;
 
@@ -30,6 +49,7 @@ impl WeierstrassCurve for NistP256 {
         .sub(&FieldElement::ONE)
         .sub(&FieldElement::ONE)
         .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
 
     const EQUATION_B: FieldElement = FieldElement::from_be_hex(
         "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
@@ -51,6 +71,16 @@ impl WeierstrassCurve for NistP256 {
             "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
         ),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        basepoint_table().mul(k)
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    fn generator_odd_multiples() -> Option<&'static [AffinePoint]> {
+        Some(basepoint_table().odd_multiples())
+    }
 }
 
 impl AffineArithmetic for NistP256 {
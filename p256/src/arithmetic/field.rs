@@ -14,6 +14,7 @@ use elliptic_curve::{
     ff::{Field, PrimeField},
     subtle::{Choice, ConstantTimeEq, CtOption},
 };
+use safegcd::Inverter;
 
 /// Constant representing the modulus
 /// p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1
@@ -24,6 +25,9 @@ pub const MODULUS: U256 =
 const R_2: U256 =
     U256::from_be_hex("00000004fffffffdfffffffffffffffefffffffbffffffff0000000000000003");
 
+/// Inverter for the field modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U256::LIMBS }, 5> = Inverter::new(MODULUS.as_words());
+
 /// An element in the finite field modulo p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1.
 ///
 /// The internal representation is in little-endian order. Elements are always in
@@ -80,27 +84,23 @@ impl FieldElement {
 
     /// Returns the multiplicative inverse of self, if self is non-zero.
     pub fn invert(&self) -> CtOption<Self> {
-        // We need to find b such that b * a ≡ 1 mod p. As we are in a prime
-        // field, we can apply Fermat's Little Theorem:
-        //
-        //    a^p         ≡ a mod p
-        //    a^(p-1)     ≡ 1 mod p
-        //    a^(p-2) * a ≡ 1 mod p
-        //
-        // Thus inversion can be implemented with a single exponentiation.
-
-        let t111 = self.mul(&self.mul(&self.square()).square());
-        let t111111 = t111.mul(t111.sqn(3));
-        let x15 = t111111.sqn(6).mul(t111111).sqn(3).mul(t111);
-        let x16 = x15.square().mul(self);
-        let i53 = x16.sqn(16).mul(x16).sqn(15);
-        let x47 = x15.mul(i53);
-        let inverse = x47
-            .mul(i53.sqn(17).mul(self).sqn(143).mul(x47).sqn(47))
-            .sqn(2)
-            .mul(self);
-
-        CtOption::new(inverse, !self.is_zero())
+        let inverse = INVERTER.invert(self.to_canonical().as_words());
+        CtOption::new(
+            Self::from_uint_unchecked(U256::from_words(inverse)),
+            !self.is_zero(),
+        )
+    }
+
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = INVERTER.invert_vartime(self.to_canonical().as_words());
+        CtOption::new(
+            Self::from_uint_unchecked(U256::from_words(inverse)),
+            !self.is_zero(),
+        )
     }
 
     /// Returns the square root of self mod p, or `None` if no square root exists.
@@ -296,6 +296,21 @@ mod tests {
         assert_eq!(two * &inv_two, one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(FieldElement::zero().invert_vartime().is_none()));
+
+        let one = FieldElement::one();
+        let mut x = -one;
+
+        for _ in 0..100 {
+            let inv = x.invert().unwrap();
+            assert_eq!(x * inv, one);
+            assert_eq!(x.invert_vartime().unwrap(), inv);
+            x = x.square() + one;
+        }
+    }
+
     #[test]
     fn sqrt() {
         let one = FieldElement::one();
@@ -1,7 +1,5 @@
 //! Scalar field arithmetic modulo n = 115792089210356248762697446949407573529996955224135760342422259061068512044369
 
-pub mod blinded;
-
 #[cfg_attr(target_pointer_width = "32", path = "scalar/scalar32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "scalar/scalar64.rs")]
 mod scalar_impl;
@@ -22,6 +20,7 @@ use elliptic_curve::{
     zeroize::DefaultIsZeroes,
     Curve, IsHigh, ScalarCore,
 };
+use safegcd::Inverter;
 
 #[cfg(feature = "bits")]
 use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
@@ -36,6 +35,9 @@ pub(crate) const MODULUS: U256 = NistP256::ORDER;
 /// `MODULUS / 2`
 const FRAC_MODULUS_2: Scalar = Scalar(MODULUS.shr_vartime(1));
 
+/// Inverter for the scalar modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U256::LIMBS }, 5> = Inverter::new(MODULUS.as_words());
+
 /// MU = floor(2^512 / n)
 ///    = 115792089264276142090721624801893421302707618245269942344307673200490803338238
 ///    = 0x100000000fffffffffffffffeffffffff43190552df1a6c21012ffd85eedf9bfe
@@ -123,75 +125,17 @@ impl Scalar {
 
     /// Returns the multiplicative inverse of self, if self is non-zero
     pub fn invert(&self) -> CtOption<Self> {
-        // We need to find b such that b * a ≡ 1 mod p. As we are in a prime
-        // field, we can apply Fermat's Little Theorem:
-        //
-        //    a^p         ≡ a mod p
-        //    a^(p-1)     ≡ 1 mod p
-        //    a^(p-2) * a ≡ 1 mod p
-        //
-        // Thus inversion can be implemented with a single exponentiation.
-        //
-        // This is `n - 2`, so the top right two digits are `4f` instead of `51`.
-        let inverse = self.pow_vartime(&[
-            0xf3b9_cac2_fc63_254f,
-            0xbce6_faad_a717_9e84,
-            0xffff_ffff_ffff_ffff,
-            0xffff_ffff_0000_0000,
-        ]);
-
-        CtOption::new(inverse, !self.is_zero())
+        let inverse = INVERTER.invert(self.0.as_words());
+        CtOption::new(Self(U256::from_words(inverse)), !self.is_zero())
     }
 
-    /// Faster inversion using Stein's algorithm
-    #[allow(non_snake_case)]
+    /// Returns the multiplicative inverse of self, if self is non-zero.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
     pub fn invert_vartime(&self) -> CtOption<Self> {
-        // https://link.springer.com/article/10.1007/s13389-016-0135-4
-
-        let mut u = *self;
-        // currently an invalid scalar
-        let mut v = Scalar(NistP256::ORDER);
-        let mut A = Self::one();
-        let mut C = Self::zero();
-
-        while !bool::from(u.is_zero()) {
-            // u-loop
-            while bool::from(u.is_even()) {
-                u.shr1();
-
-                let was_odd: bool = A.is_odd().into();
-                A.shr1();
-
-                if was_odd {
-                    A += FRAC_MODULUS_2;
-                    A += Self::one();
-                }
-            }
-
-            // v-loop
-            while bool::from(v.is_even()) {
-                v.shr1();
-
-                let was_odd: bool = C.is_odd().into();
-                C.shr1();
-
-                if was_odd {
-                    C += FRAC_MODULUS_2;
-                    C += Self::one();
-                }
-            }
-
-            // sub-step
-            if u >= v {
-                u -= &v;
-                A -= &C;
-            } else {
-                v -= &u;
-                C -= &A;
-            }
-        }
-
-        CtOption::new(C, !self.is_zero())
+        let inverse = INVERTER.invert_vartime(self.0.as_words());
+        CtOption::new(Self(U256::from_words(inverse)), !self.is_zero())
     }
 
     /// Is integer representing equivalence class odd?
@@ -203,11 +147,6 @@ impl Scalar {
     pub fn is_even(&self) -> Choice {
         !self.is_odd()
     }
-
-    /// Shift right by one bit
-    fn shr1(&mut self) {
-        self.0 >>= 1;
-    }
 }
 
 impl Field for Scalar {
@@ -673,6 +612,21 @@ mod tests {
         assert_eq!(three * &inv_minus_three, -one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(Scalar::zero().invert_vartime().is_none()));
+
+        let one = Scalar::one();
+        let mut x = -one;
+
+        for _ in 0..100 {
+            let inv = x.invert().unwrap();
+            assert_eq!(x * inv, one);
+            assert_eq!(x.invert_vartime().unwrap(), inv);
+            x = x.square() + one;
+        }
+    }
+
     /// Basic tests that sqrt works.
     #[test]
     fn sqrt() {
@@ -37,11 +37,42 @@
 //! ```
 
 pub use elliptic_curve::ecdh::diffie_hellman;
+pub use weierstrass::ecdh::HardenedDiffieHellman;
 
 use crate::NistP256;
 
 /// NIST P-256 Ephemeral Diffie-Hellman Secret.
-pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP256>;
+pub type EphemeralSecret = weierstrass::ecdh::EphemeralSecret<NistP256>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP256>;
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret, HardenedDiffieHellman, SharedSecret};
+    use crate::NonZeroScalar;
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn hardened_matches_diffie_hellman() {
+        let secret = NonZeroScalar::random(&mut OsRng);
+        let public = EphemeralSecret::random(&mut OsRng).public_key();
+
+        let expected = diffie_hellman(secret, public.as_affine());
+        let actual = SharedSecret::diffie_hellman_hardened(secret, public.as_affine(), &mut OsRng);
+        assert_eq!(expected.raw_secret_bytes(), actual.raw_secret_bytes());
+    }
+
+    #[test]
+    fn ephemeral_secret_hardened() {
+        let alice_secret = EphemeralSecret::random(&mut OsRng);
+        let bob_secret = EphemeralSecret::random(&mut OsRng);
+
+        let alice_shared = alice_secret.diffie_hellman(&bob_secret.public_key());
+        let bob_shared = bob_secret.diffie_hellman_hardened(&alice_secret.public_key(), &mut OsRng);
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+    }
+}
@@ -41,12 +41,25 @@
 
 pub use ecdsa_core::signature::{self, Error};
 
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
 use super::NistP256;
 
 #[cfg(feature = "ecdsa")]
 use {
-    crate::{AffinePoint, Scalar},
-    ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
+    crate::{AffinePoint, FieldBytes, ProjectivePoint, Scalar, U256},
+    ecdsa_core::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{
+        ops::{LinearCombination, Reduce},
+        AffineXCoordinate, FieldSize, ScalarCore,
+    },
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
 };
 
 /// ECDSA/P-256 signature (fixed-size)
@@ -72,15 +85,63 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
 }
 
 #[cfg(feature = "ecdsa")]
-impl SignPrimitive<NistP256> for Scalar {}
+impl Rfc6979Curve for NistP256 {
+    const REDUCE_PREHASH: bool = false;
+}
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<NistP256> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<NistP256>>,
+        U256: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<NistP256>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<NistP256, D>(self, z, ad)
+    }
+}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP256> for AffinePoint {}
+impl VerifyPrimitive<NistP256> for AffinePoint {
+    fn verify_prehashed(&self, z: FieldBytes, sig: &Signature) -> Result<(), Error> {
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(z);
+        let (r, s) = sig.split_scalars();
+
+        // The signature is public, so `s` can be inverted in variable time
+        let s_inv = s.invert_vartime().unwrap();
+        let u1 = z * s_inv;
+        let u2 = *r * s_inv;
+        let x = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &u1,
+            &ProjectivePoint::from(*self),
+            &u2,
+        )
+        .to_affine()
+        .x();
+
+        if <Scalar as Reduce<U256>>::from_be_bytes_reduced(x) == *r {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
     use crate::{
-        ecdsa::{signature::Signer, SigningKey},
+        ecdsa::{
+            signature::{hazmat::PrehashSigner, Signer},
+            HardenedSigner, Signature, SigningKey,
+        },
         test_vectors::ecdsa::ECDSA_TEST_VECTORS,
         BlindedScalar, Scalar,
     };
@@ -112,6 +173,34 @@ mod tests {
         );
     }
 
+    /// RFC6979 nonces are derived from the prehash as-is like in the `ecdsa`
+    /// crate, including when it's larger than the order `n`, rather than
+    /// from the prehash reduced modulo `n`.
+    #[test]
+    fn rfc6979_prehash_above_order() {
+        let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let prehash = [0xff; 32];
+
+        let d = Scalar::from_repr(GenericArray::clone_from_slice(x)).unwrap();
+        let k = hex!("766d8b826064b7f27df663b2b78697ed61f9ad41adf6ea302e4c065283e7508b");
+        let k = Scalar::from_repr(GenericArray::clone_from_slice(&k)).unwrap();
+        let z = GenericArray::clone_from_slice(&prehash);
+        let expected = d.try_sign_prehashed(k, z).unwrap().0;
+        assert_eq!(
+            expected.as_ref(),
+            &hex!(
+                "a38b4bf5013627c24aadc72c653ac4d1afadf8a570960b1c4d066c5cfc609584
+                16a2094352198c79ebcb9e52de2fd3b02ab5667ac88ea519d45aecd1d8864e42"
+            )[..]
+        );
+
+        let signature: Signature = signer.sign_prehash(&prehash).unwrap();
+        assert_eq!(signature, expected);
+        let hardened = signer.sign_prehash_hardened(&mut OsRng, &prehash);
+        assert_eq!(hardened.unwrap(), expected);
+    }
+
     #[test]
     fn scalar_blinding() {
         let vector = &ECDSA_TEST_VECTORS[0];
@@ -125,6 +214,21 @@ mod tests {
         assert_eq!(vector.s, sig.s().to_bytes().as_slice());
     }
 
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let msg = b"ECDSA proves knowledge of a secret number in the context of a single message";
+        assert_eq!(
+            signing_key.sign(msg),
+            signing_key.sign_hardened(&mut OsRng, msg)
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
     mod sign {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP256};
         ecdsa_core::new_signing_test!(NistP256, ECDSA_TEST_VECTORS);
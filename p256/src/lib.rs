@@ -19,6 +19,9 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(feature = "precomputed-tables")]
+extern crate alloc;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
@@ -37,10 +40,7 @@ pub mod test_vectors;
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{
-    scalar::{blinded::BlindedScalar, Scalar},
-    AffinePoint, ProjectivePoint,
-};
+pub use arithmetic::{scalar::Scalar, AffinePoint, BlindedScalar, ProjectivePoint};
 
 #[cfg(feature = "expose-field")]
 pub use arithmetic::field::FieldElement;
@@ -4,10 +4,12 @@
 
 use elliptic_curve::{
     group::{ff::PrimeField, GroupEncoding},
+    ops::LinearCombination,
     sec1::{self, ToEncodedPoint},
 };
 use p256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
 use p256::{AffinePoint, ProjectivePoint, Scalar};
+use rand_core::OsRng;
 
 /// Assert that the provided projective point matches the given test vector.
 // TODO(tarcieri): use coordinate APIs. See zkcrypto/group#30
@@ -153,8 +155,83 @@ fn test_vector_scalar_mult() {
     }
 }
 
+#[test]
+fn generator_mul_vs_windowed_mul() {
+    // `[2k] G` goes through `mul_by_generator`, while `[k] 2G` is computed
+    // using the generic windowed multiplication.
+    let generator = ProjectivePoint::GENERATOR;
+    let double = generator.double();
+
+    for k in MUL_TEST_VECTORS
+        .iter()
+        .map(|(k, _, _)| Scalar::from_repr((*k).into()).unwrap())
+        .chain([Scalar::ZERO, Scalar::ONE, -Scalar::ONE])
+    {
+        assert_eq!(generator * (k + k), double * k);
+    }
+}
+
+#[test]
+fn lincomb_vs_mul() {
+    let generator = ProjectivePoint::GENERATOR;
+    let scalars = MUL_TEST_VECTORS
+        .iter()
+        .map(|(k, _, _)| Scalar::from_repr((*k).into()).unwrap())
+        .chain([Scalar::ZERO, Scalar::ONE, -Scalar::ONE])
+        .collect::<Vec<_>>();
+
+    for (k, l) in scalars.iter().zip(scalars.iter().rev()) {
+        let q = generator * l;
+
+        for (x, y) in [(generator, q), (q, generator), (q, q.double())] {
+            assert_eq!(ProjectivePoint::lincomb(&x, k, &y, l), x * k + y * l);
+        }
+    }
+}
+
+#[test]
+fn mul_hardened_vs_mul() {
+    let generator = ProjectivePoint::GENERATOR;
+    let double = generator.double();
+
+    for k in MUL_TEST_VECTORS
+        .iter()
+        .map(|(k, _, _)| Scalar::from_repr((*k).into()).unwrap())
+        .chain([Scalar::ZERO, Scalar::ONE, -Scalar::ONE])
+    {
+        for point in [generator, double, ProjectivePoint::IDENTITY] {
+            assert_eq!(point.mul_hardened(&k, &mut OsRng), point * k);
+        }
+    }
+}
+
 #[test]
 fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
     assert_eq!([0; 33], ProjectivePoint::IDENTITY.to_bytes().as_slice());
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn multiscalar_mul_vartime() {
+    let k = Scalar::from_repr(MUL_TEST_VECTORS[0].0.into()).unwrap();
+    let mut point = ProjectivePoint::GENERATOR;
+    let mut scalar = -Scalar::ONE;
+    let mut pairs = Vec::new();
+
+    // Cover both the Straus and Pippenger code paths
+    for n in [0, 1, 2, 3, 17, 65, 200] {
+        while pairs.len() < n {
+            pairs.push((point, scalar));
+            point = point.double() + ProjectivePoint::GENERATOR;
+            scalar = scalar * k + Scalar::ONE;
+        }
+
+        let expected = pairs
+            .iter()
+            .map(|(point, scalar)| point * scalar)
+            .sum::<ProjectivePoint>();
+
+        assert_eq!(ProjectivePoint::multiscalar_mul_vartime(&pairs), expected);
+    }
+}
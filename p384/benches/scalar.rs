@@ -4,7 +4,10 @@ use criterion::{
     criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
 };
 use hex_literal::hex;
-use p384::{elliptic_curve::group::ff::PrimeField, ProjectivePoint, Scalar};
+use p384::{
+    elliptic_curve::{group::ff::PrimeField, ops::LinearCombination},
+    ProjectivePoint, Scalar,
+};
 
 fn test_scalar_x() -> Scalar {
     Scalar::from_repr(
@@ -19,12 +22,29 @@ fn test_scalar_y() -> Scalar {
 }
 
 fn bench_point_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
-    let p = ProjectivePoint::GENERATOR;
+    let p = ProjectivePoint::GENERATOR.double();
     let m = test_scalar_x();
     let s = Scalar::from_repr(m.into()).unwrap();
     group.bench_function("point-scalar mul", |b| b.iter(|| &p * &s));
 }
 
+fn bench_generator_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let p = ProjectivePoint::GENERATOR;
+    let m = test_scalar_x();
+    let s = Scalar::from_repr(m.into()).unwrap();
+    group.bench_function("generator-scalar mul", |b| b.iter(|| p * s));
+}
+
+fn bench_point_lincomb<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let p = ProjectivePoint::GENERATOR;
+    let q = p.double();
+    let k = test_scalar_x();
+    let l = test_scalar_y();
+    group.bench_function("lincomb", |b| {
+        b.iter(|| ProjectivePoint::lincomb(&p, &k, &q, &l))
+    });
+}
+
 fn bench_scalar_sub<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
@@ -53,9 +73,16 @@ fn bench_scalar_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_scalar_invert_vartime<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_scalar_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
 fn bench_point(c: &mut Criterion) {
     let mut group = c.benchmark_group("point operations");
     bench_point_mul(&mut group);
+    bench_generator_mul(&mut group);
+    bench_point_lincomb(&mut group);
     group.finish();
 }
 
@@ -66,6 +93,7 @@ fn bench_scalar(c: &mut Criterion) {
     bench_scalar_mul(&mut group);
     bench_scalar_negate(&mut group);
     bench_scalar_invert(&mut group);
+    bench_scalar_invert_vartime(&mut group);
     group.finish();
 }
 
@@ -9,6 +9,8 @@
 mod macros;
 
 pub(crate) mod field;
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
 pub(crate) mod scalar;
 
 use self::{field::FieldElement, scalar::Scalar};
@@ -16,7 +18,10 @@ use crate::NistP384;
 use elliptic_curve::{
     AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
 };
-use weierstrass::WeierstrassCurve;
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+#[cfg(feature = "precomputed-tables")]
+use {alloc::boxed::Box, once_cell::race::OnceBox, weierstrass::BasepointTable};
 
 /// Elliptic curve point in affine coordinates.
 pub type AffinePoint = weierstrass::AffinePoint<NistP384>;
@@ -24,6 +29,22 @@ pub type AffinePoint = weierstrass::AffinePoint<NistP384>;
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = weierstrass::ProjectivePoint<NistP384>;
 
+/// Scalar blinded with a randomly generated masking value.
+pub type BlindedScalar = weierstrass::BlindedScalar<Scalar>;
+
+/// Lazily computed multiples of the P-384 generator.
+///
+/// `OnceBox` works without `std`. Threads racing on the first use may each
+/// compute the table, in which case all but one of them are dropped.
+#[cfg(feature = "precomputed-tables")]
+static BASEPOINT_TABLE: OnceBox<BasepointTable<NistP384, 97>> = OnceBox::new();
+
+/// Returns the multiples of the P-384 generator, computing them on first use.
+#[cfg(feature = "precomputed-tables")]
+fn basepoint_table() -> &'static BasepointTable<NistP384, 97> {
+    BASEPOINT_TABLE.get_or_init(|| Box::new(BasepointTable::new()))
+}
+
 impl WeierstrassCurve for NistP384 {
     type FieldElement = FieldElement;
 
@@ -35,6 +56,7 @@ impl WeierstrassCurve for NistP384 {
         .sub(&FieldElement::ONE)
         .sub(&FieldElement::ONE)
         .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
 
     /// b = b3312fa7 e23ee7e4 988e056b e3f82d19 181d9c6e fe814112
     ///     0314088f 5013875a c656398d 8a2ed19d 2a85c8ed d3ec2aef
@@ -57,6 +79,16 @@ impl WeierstrassCurve for NistP384 {
         FieldElement::from_be_hex("aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7"),
         FieldElement::from_be_hex("3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(k: &Scalar) -> ProjectivePoint {
+        basepoint_table().mul(k)
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    fn generator_odd_multiples() -> Option<&'static [AffinePoint]> {
+        Some(basepoint_table().odd_multiples())
+    }
 }
 
 impl AffineArithmetic for NistP384 {
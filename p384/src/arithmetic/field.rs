@@ -32,11 +32,15 @@ use elliptic_curve::{
     ff::PrimeField,
     subtle::{Choice, ConstantTimeEq, CtOption},
 };
+use safegcd::Inverter;
 
 /// Constant representing the modulus
 /// p = 2^{384} − 2^{128} − 2^{96} + 2^{32} − 1
 pub(crate) const MODULUS: U384 = U384::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff");
 
+/// Inverter for the field modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U384::LIMBS }, 7> = Inverter::new(MODULUS.as_words());
+
 /// Element of the secp384r1 base field used for curve coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement(pub(super) U384);
@@ -87,6 +91,18 @@ impl FieldElement {
         CtOption::new(Self(ret.into()), !self.is_zero())
     }
 
+    /// Compute [`FieldElement`] inversion: `1 / self`.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = INVERTER.invert_vartime(self.to_canonical().as_words());
+        CtOption::new(
+            Self::from_uint_unchecked(U384::from_words(inverse)),
+            !self.is_zero(),
+        )
+    }
+
     /// Returns the square root of self mod p, or `None` if no square root
     /// exists.
     pub fn sqrt(&self) -> CtOption<Self> {
@@ -186,6 +202,21 @@ mod tests {
         assert_eq!(three * &inv_minus_three, -one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(FieldElement::ZERO.invert_vartime().is_none()));
+
+        let one = FieldElement::ONE;
+        let mut x = -one;
+
+        for _ in 0..100 {
+            let inv = x.invert().unwrap();
+            assert_eq!(x * inv, one);
+            assert_eq!(x.invert_vartime().unwrap(), inv);
+            x = x.square() + one;
+        }
+    }
+
     #[test]
     fn sqrt() {
         let one = FieldElement::ONE;
@@ -21,6 +21,7 @@ use elliptic_curve::{
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption},
     Curve as _, Error, IsHigh, Result, ScalarCore,
 };
+use safegcd::Inverter;
 
 #[cfg(feature = "bits")]
 use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
@@ -31,6 +32,9 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 #[cfg(doc)]
 use core::ops::{Add, Mul, Sub};
 
+/// Inverter for the scalar modulus using the safegcd algorithm
+const INVERTER: Inverter<{ U384::LIMBS }, 7> = Inverter::new(NistP384::ORDER.as_words());
+
 /// Scalars are elements in the finite field modulo `n`.
 ///
 /// # Trait impls
@@ -102,6 +106,18 @@ impl Scalar {
         CtOption::new(Self(ret.into()), !self.is_zero())
     }
 
+    /// Compute [`Scalar`] inversion: `1 / self`.
+    ///
+    /// **This operation is variable time with respect to self.** It must only
+    /// be used with public inputs.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        let inverse = INVERTER.invert_vartime(self.to_canonical().as_words());
+        CtOption::new(
+            Self::from_uint_unchecked(U384::from_words(inverse)),
+            !self.is_zero(),
+        )
+    }
+
     /// Compute modular square root.
     pub fn sqrt(&self) -> CtOption<Self> {
         // p mod 4 = 3 -> compute sqrt(x) using x^((p+1)/4) =
@@ -354,6 +370,21 @@ mod tests {
         assert_eq!(three * inv_minus_three, -one);
     }
 
+    #[test]
+    fn invert_vartime() {
+        assert!(bool::from(Scalar::ZERO.invert_vartime().is_none()));
+
+        let one = Scalar::ONE;
+        let mut x = -one;
+
+        for _ in 0..100 {
+            let inv = x.invert().unwrap();
+            assert_eq!(x * inv, one);
+            assert_eq!(x.invert_vartime().unwrap(), inv);
+            x = x.square() + one;
+        }
+    }
+
     /// Basic tests that sqrt works.
     #[test]
     fn sqrt() {
@@ -38,10 +38,24 @@
 //! ```
 
 pub use ecdsa_core::signature::{self, Error};
+
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
 #[cfg(feature = "ecdsa")]
 use {
-    crate::{AffinePoint, Scalar},
-    ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
+    crate::{AffinePoint, FieldBytes, ProjectivePoint, Scalar, U384},
+    ecdsa_core::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset},
+        RecoveryId,
+    },
+    elliptic_curve::{
+        ops::{LinearCombination, Reduce},
+        AffineXCoordinate, FieldSize, ScalarCore,
+    },
+    weierstrass::ecdsa::{hazmat::sign_prehashed_rfc6979, Rfc6979Curve},
 };
 
 use super::NistP384;
@@ -69,17 +83,67 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP384 {
 }
 
 #[cfg(feature = "ecdsa")]
-impl SignPrimitive<NistP384> for Scalar {}
+impl Rfc6979Curve for NistP384 {
+    const REDUCE_PREHASH: bool = false;
+}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP384> for AffinePoint {}
+impl SignPrimitive<NistP384> for Scalar {
+    fn try_sign_prehashed_rfc6979<D>(
+        &self,
+        z: FieldBytes,
+        ad: &[u8],
+    ) -> Result<(Signature, Option<RecoveryId>), Error>
+    where
+        Self: From<ScalarCore<NistP384>>,
+        U384: for<'a> From<&'a Self>,
+        D: Digest
+            + BlockSizeUser
+            + FixedOutput<OutputSize = FieldSize<NistP384>>
+            + FixedOutputReset,
+    {
+        sign_prehashed_rfc6979::<NistP384, D>(self, z, ad)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<NistP384> for AffinePoint {
+    fn verify_prehashed(&self, z: FieldBytes, sig: &Signature) -> Result<(), Error> {
+        let z = <Scalar as Reduce<U384>>::from_be_bytes_reduced(z);
+        let (r, s) = sig.split_scalars();
+
+        // The signature is public, so `s` can be inverted in variable time
+        let s_inv = s.invert_vartime().unwrap();
+        let u1 = z * s_inv;
+        let u2 = *r * s_inv;
+        let x = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &u1,
+            &ProjectivePoint::from(*self),
+            &u2,
+        )
+        .to_affine()
+        .x();
+
+        if <Scalar as Reduce<U384>>::from_be_bytes_reduced(x) == *r {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
     use crate::{
-        ecdsa::{signature::Signer, SigningKey},
+        ecdsa::{
+            signature::{hazmat::PrehashSigner, Signer},
+            HardenedSigner, Signature, SigningKey,
+        },
+        test_vectors::ecdsa::ECDSA_TEST_VECTORS,
         SecretKey,
     };
+    use elliptic_curve::rand_core::OsRng;
     use hex_literal::hex;
 
     // Test vector from RFC 6979 Appendix 2.6 (NIST P-384 + SHA-384)
@@ -121,6 +185,21 @@ mod tests {
         assert_eq!(sigk.to_bytes(), seck.to_be_bytes());
     }
 
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let msg = b"ECDSA proves knowledge of a secret number in the context of a single message";
+        assert_eq!(
+            signing_key.sign(msg),
+            signing_key.sign_hardened(&mut OsRng, msg)
+        );
+
+        let prehash = ECDSA_TEST_VECTORS[0].m;
+        let signature: Signature = signing_key.sign_prehash(prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
     mod sign {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP384};
         ecdsa_core::new_signing_test!(NistP384, ECDSA_TEST_VECTORS);
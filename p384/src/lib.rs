@@ -19,6 +19,9 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(feature = "precomputed-tables")]
+extern crate alloc;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
@@ -37,7 +40,7 @@ pub mod test_vectors;
 pub use elliptic_curve::{self, bigint::U384};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{scalar::Scalar, AffinePoint, ProjectivePoint};
+pub use arithmetic::{scalar::Scalar, AffinePoint, BlindedScalar, ProjectivePoint};
 
 #[cfg(feature = "expose-field")]
 pub use arithmetic::field::FieldElement;
@@ -0,0 +1,82 @@
+//! Pure Rust implementation of group operations on secp521r1.
+//!
+//! Curve parameters can be found in FIPS 186-4: Digital Signature Standard
+//! (DSS): <https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.186-4.pdf>
+//!
+//! See section D.1.2.5: Curve P-521.
+
+#[macro_use]
+mod macros;
+
+pub(crate) mod field;
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
+pub(crate) mod scalar;
+
+use self::{field::FieldElement, scalar::Scalar};
+use crate::NistP521;
+use elliptic_curve::{
+    AffineArithmetic, PrimeCurveArithmetic, ProjectiveArithmetic, ScalarArithmetic,
+};
+use weierstrass::{EquationAShape, WeierstrassCurve};
+
+/// Elliptic curve point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<NistP521>;
+
+/// Elliptic curve point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<NistP521>;
+
+/// Scalar blinded with a randomly generated masking value.
+pub type BlindedScalar = weierstrass::BlindedScalar<Scalar>;
+
+impl WeierstrassCurve for NistP521 {
+    type FieldElement = FieldElement;
+
+    const ZERO: FieldElement = FieldElement::ZERO;
+    const ONE: FieldElement = FieldElement::ONE;
+
+    /// a = -3 (0x1ff ffffffff ... ffffffff fffffffc)
+    const EQUATION_A: FieldElement = FieldElement::ZERO
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE)
+        .sub(&FieldElement::ONE);
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::MinusThree;
+
+    /// b = 051 953eb961 8e1c9a1f 929a21a0 b68540ee a2da725b 99b315f3
+    ///     b8b48991 8ef109e1 56193951 ec7e937b 1652c0bd 3bb1bf07
+    ///     3573df88 3d2c34f1 ef451fd4 6b503f00
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex("0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00");
+
+    /// Base point of P-521.
+    ///
+    /// Defined in FIPS 186-4 § D.1.2.5:
+    ///
+    /// ```text
+    /// Gₓ = 0c6 858e06b7 0404e9cd 9e3ecb66 2395b442 9c648139
+    ///      053fb521 f828af60 6b4d3dba a14b5e77 efe75928 fe1dc127
+    ///      a2ffa8de 3348b3c1 856a429b f97e7e31 c2e5bd66
+    /// Gᵧ = 118 39296a78 9a3bc004 5c8a5fb4 2c7d1bd9 98f54449
+    ///      579b4468 17afbd17 273e662c 97ee7299 5ef42640 c550b901
+    ///      3fad0761 353c7086 a272c240 88be9476 9fd16650
+    /// ```
+    const GENERATOR: (FieldElement, FieldElement) = (
+        FieldElement::from_be_hex("00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"),
+        FieldElement::from_be_hex("011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650"),
+    );
+}
+
+impl AffineArithmetic for NistP521 {
+    type AffinePoint = AffinePoint;
+}
+
+impl ProjectiveArithmetic for NistP521 {
+    type ProjectivePoint = ProjectivePoint;
+}
+
+impl PrimeCurveArithmetic for NistP521 {
+    type CurveGroup = ProjectivePoint;
+}
+
+impl ScalarArithmetic for NistP521 {
+    type Scalar = Scalar;
+}
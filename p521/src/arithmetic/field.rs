@@ -0,0 +1,607 @@
+//! Field arithmetic modulo p = 2^{521} − 1
+//!
+//! Arithmetic implementations are extracted Rust code from the Coq fiat-crypto
+//! libraries.
+//!
+//! Unlike the other NIST curves, the P-521 base field uses fiat-crypto's
+//! unsaturated Solinas backend, where elements are represented as nine limbs
+//! of 58/57 bits. Operations alternate between "tight" and "loose" bounds:
+//! [`FieldElement`] always holds a tight element, while
+//! [`LooseFieldElement`] is the intermediate result of an addition or
+//! subtraction that has not yet been carried.
+//!
+//! # License
+//!
+//! Copyright (c) 2015-2020 the fiat-crypto authors
+//!
+//! fiat-crypto is distributed under the terms of the MIT License, the
+//! Apache License (Version 2.0), and the BSD 1-Clause License;
+//! users may pick which license to apply.
+
+#![allow(
+    clippy::should_implement_trait,
+    clippy::suspicious_op_assign_impl,
+    clippy::unused_unit,
+    clippy::unnecessary_cast,
+    clippy::too_many_arguments,
+    clippy::identity_op
+)]
+
+// NOTE: the unsaturated backend is used on both 32-bit and 64-bit targets.
+#[path = "field/p521_64.rs"]
+mod field_impl;
+mod loose;
+
+pub(crate) use self::loose::LooseFieldElement;
+
+use self::field_impl::*;
+use crate::{FieldBytes, U528};
+use core::{
+    fmt::{self, Debug},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    ff::{Field, PrimeField},
+    rand_core::RngCore,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption},
+    zeroize::DefaultIsZeroes,
+    Error, Result,
+};
+
+/// Constant representing the modulus
+/// p = 2^{521} − 1
+pub(crate) const MODULUS: U528 = U528::from_be_hex("01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+
+/// Element of the secp521r1 base field used for curve coordinates.
+#[derive(Clone, Copy)]
+pub struct FieldElement(pub(super) fiat_p521_tight_field_element);
+
+impl FieldElement {
+    /// Zero element.
+    pub const ZERO: Self = Self::from_uint_unchecked(U528::ZERO);
+
+    /// Multiplicative identity.
+    pub const ONE: Self = Self::from_uint_unchecked(U528::ONE);
+
+    /// Create a [`FieldElement`] from a canonical big-endian representation.
+    pub fn from_be_bytes(repr: FieldBytes) -> CtOption<Self> {
+        Self::from_uint(U528::from_be_byte_array(repr))
+    }
+
+    /// Decode [`FieldElement`] from a big endian byte slice.
+    pub fn from_be_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != U528::BYTE_SIZE {
+            return Err(Error);
+        }
+
+        Option::from(Self::from_be_bytes(FieldBytes::clone_from_slice(slice))).ok_or(Error)
+    }
+
+    /// Create a [`FieldElement`] from a canonical little-endian representation.
+    pub fn from_le_bytes(repr: FieldBytes) -> CtOption<Self> {
+        Self::from_uint(U528::from_le_byte_array(repr))
+    }
+
+    /// Decode [`FieldElement`] from a little endian byte slice.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != U528::BYTE_SIZE {
+            return Err(Error);
+        }
+
+        Option::from(Self::from_le_bytes(FieldBytes::clone_from_slice(slice))).ok_or(Error)
+    }
+
+    /// Decode [`FieldElement`] from [`U528`].
+    ///
+    /// Returns `None` if the integer is not in the range `[0, p)`.
+    pub fn from_uint(uint: U528) -> CtOption<Self> {
+        let is_some = uint.ct_lt(&MODULUS);
+        CtOption::new(Self::from_uint_unchecked(uint), is_some)
+    }
+
+    /// Parse a [`FieldElement`] from big endian hex-encoded bytes.
+    ///
+    /// Does *not* perform a check that the field element does not overflow the modulus.
+    ///
+    /// This method is primarily intended for defining internal constants.
+    pub(crate) const fn from_be_hex(hex: &str) -> Self {
+        Self::from_uint_unchecked(U528::from_be_hex(hex))
+    }
+
+    /// Decode [`FieldElement`] from [`U528`].
+    ///
+    /// Does *not* perform a check that the field element does not overflow the modulus.
+    ///
+    /// Used incorrectly this can lead to invalid results!
+    pub(crate) const fn from_uint_unchecked(w: U528) -> Self {
+        Self(fiat_p521_from_bytes(&w.to_le_bytes()))
+    }
+
+    /// Returns the big-endian encoding of this [`FieldElement`].
+    pub fn to_be_bytes(self) -> FieldBytes {
+        self.to_canonical().to_be_byte_array()
+    }
+
+    /// Returns the little-endian encoding of this [`FieldElement`].
+    pub fn to_le_bytes(self) -> FieldBytes {
+        FieldBytes::clone_from_slice(&fiat_p521_to_bytes(&self.0))
+    }
+
+    /// Fully reduce this [`FieldElement`], returning a [`U528`] in canonical
+    /// form.
+    #[inline]
+    pub const fn to_canonical(self) -> U528 {
+        U528::from_le_bytes(fiat_p521_to_bytes(&self.0))
+    }
+
+    /// Parse the given byte array as an SEC1-encoded field element.
+    ///
+    /// Returns `None` if the byte array does not contain a big-endian integer in
+    /// the range `[0, p)`.
+    pub fn from_sec1(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Returns the SEC1 encoding of this field element.
+    pub fn to_sec1(self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    /// Determine if this [`FieldElement`] is odd in the SEC1 sense: `self mod 2 == 1`.
+    ///
+    /// # Returns
+    ///
+    /// If odd, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_odd(&self) -> Choice {
+        Choice::from(fiat_p521_to_bytes(&self.0)[0] & 1)
+    }
+
+    /// Determine if this [`FieldElement`] is even in the SEC1 sense: `self mod 2 == 0`.
+    ///
+    /// # Returns
+    ///
+    /// If even, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_even(&self) -> Choice {
+        !self.is_odd()
+    }
+
+    /// Determine if this [`FieldElement`] is zero.
+    ///
+    /// # Returns
+    ///
+    /// If zero, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    /// Add elements.
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self(fiat_p521_carry_add(&self.0, &rhs.0))
+    }
+
+    /// Double element (add it to itself).
+    #[must_use]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Subtract elements.
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        Self(fiat_p521_carry_sub(&self.0, &rhs.0))
+    }
+
+    /// Multiply elements.
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        self.relax().mul(&rhs.relax())
+    }
+
+    /// Negate element.
+    pub const fn neg(&self) -> Self {
+        Self(fiat_p521_carry_opp(&self.0))
+    }
+
+    /// Compute modular square.
+    #[must_use]
+    pub const fn square(&self) -> Self {
+        self.relax().square()
+    }
+
+    /// Add elements without carrying, returning a [`LooseFieldElement`].
+    #[allow(dead_code)]
+    pub(crate) const fn add_loose(&self, rhs: &Self) -> LooseFieldElement {
+        LooseFieldElement(fiat_p521_add(&self.0, &rhs.0))
+    }
+
+    /// Subtract elements without carrying, returning a [`LooseFieldElement`].
+    #[allow(dead_code)]
+    pub(crate) const fn sub_loose(&self, rhs: &Self) -> LooseFieldElement {
+        LooseFieldElement(fiat_p521_sub(&self.0, &rhs.0))
+    }
+
+    /// Relax a tight field element into a loose one.
+    pub(crate) const fn relax(&self) -> LooseFieldElement {
+        LooseFieldElement(fiat_p521_relax(&self.0))
+    }
+
+    /// Compute [`FieldElement`] inversion: `1 / self`.
+    pub fn invert(&self) -> CtOption<Self> {
+        // Computes self^(p - 2) using an addition chain from
+        // github.com/mmcloughlin/addchain
+        let z = self.square();
+        let z = self.mul(&z);
+        let t0 = z.sqn(2);
+        let z = z.mul(&t0);
+        let t0 = z.sqn(4);
+        let z = z.mul(&t0);
+        let t0 = z.sqn(8);
+        let z = z.mul(&t0);
+        let t0 = z.sqn(16);
+        let z = z.mul(&t0);
+        let t0 = z.sqn(32);
+        let z = z.mul(&t0);
+        let t0 = z.square();
+        let t0 = self.mul(&t0);
+        let t0 = t0.sqn(64);
+        let z = z.mul(&t0);
+        let t0 = z.square();
+        let t0 = self.mul(&t0);
+        let t0 = t0.sqn(129);
+        let z = z.mul(&t0);
+        let t0 = z.square();
+        let t0 = self.mul(&t0);
+        let t0 = t0.sqn(259);
+        let z = z.mul(&t0);
+        let z = z.sqn(2);
+        CtOption::new(self.mul(&z), !self.is_zero())
+    }
+
+    /// Returns the square root of self mod p, or `None` if no square root
+    /// exists.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // p mod 4 = 3 -> compute sqrt(x) using x^((p+1)/4) = x^(2^519)
+        let sqrt = self.sqn(519);
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    /// Returns self^(2^n) mod p.
+    fn sqn(&self, n: usize) -> Self {
+        let mut x = *self;
+        for _ in 0..n {
+            x = x.square();
+        }
+        x
+    }
+}
+
+impl AsRef<fiat_p521_tight_field_element> for FieldElement {
+    fn as_ref(&self) -> &fiat_p521_tight_field_element {
+        &self.0
+    }
+}
+
+impl Debug for FieldElement {
+    /// Field elements are printed as canonical big endian hex, since the
+    /// unsaturated limbs of two equal elements may differ.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FieldElement(0x")?;
+
+        for byte in self.to_be_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl Default for FieldElement {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Eq for FieldElement {}
+
+impl PartialEq for FieldElement {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.ct_eq(rhs).into()
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self::from_uint_unchecked(U528::from_u64(n))
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = Self::ZERO;
+
+        for i in 0..ret.0.len() {
+            ret.0[i] = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+
+        ret
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Compare canonical encodings, since the limbs are unsaturated
+        let a = fiat_p521_to_bytes(&self.0);
+        let b = fiat_p521_to_bytes(&other.0);
+        a.ct_eq(&b)
+    }
+}
+
+impl DefaultIsZeroes for FieldElement {}
+
+impl Field for FieldElement {
+    fn random(mut rng: impl RngCore) -> Self {
+        // NOTE: can't use ScalarCore::random due to CryptoRng bound
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_be_bytes(bytes).into() {
+                return fe;
+            }
+        }
+    }
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_zero(&self) -> Choice {
+        Self::ZERO.ct_eq(self)
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const NUM_BITS: u32 = 521;
+    const CAPACITY: u32 = 520;
+    const S: u32 = 1;
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn multiplicative_generator() -> Self {
+        3u64.into()
+    }
+
+    fn root_of_unity() -> Self {
+        // p - 1
+        -Self::ONE
+    }
+}
+
+//
+// `core::ops` impls
+//
+
+impl Add for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn add(self, rhs: FieldElement) -> FieldElement {
+        FieldElement::add(&self, &rhs)
+    }
+}
+
+impl Add<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn add(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::add(&self, rhs)
+    }
+}
+
+impl Add<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn add(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::add(self, rhs)
+    }
+}
+
+impl AddAssign<FieldElement> for FieldElement {
+    #[inline]
+    fn add_assign(&mut self, other: FieldElement) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<&FieldElement> for FieldElement {
+    #[inline]
+    fn add_assign(&mut self, other: &FieldElement) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn sub(self, rhs: FieldElement) -> FieldElement {
+        FieldElement::sub(&self, &rhs)
+    }
+}
+
+impl Sub<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn sub(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::sub(&self, rhs)
+    }
+}
+
+impl Sub<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn sub(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::sub(self, rhs)
+    }
+}
+
+impl SubAssign<FieldElement> for FieldElement {
+    #[inline]
+    fn sub_assign(&mut self, other: FieldElement) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<&FieldElement> for FieldElement {
+    #[inline]
+    fn sub_assign(&mut self, other: &FieldElement) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn mul(self, rhs: FieldElement) -> FieldElement {
+        FieldElement::mul(&self, &rhs)
+    }
+}
+
+impl Mul<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn mul(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::mul(&self, rhs)
+    }
+}
+
+impl Mul<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn mul(self, rhs: &FieldElement) -> FieldElement {
+        FieldElement::mul(self, rhs)
+    }
+}
+
+impl MulAssign<FieldElement> for FieldElement {
+    #[inline]
+    fn mul_assign(&mut self, other: FieldElement) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<&FieldElement> for FieldElement {
+    #[inline]
+    fn mul_assign(&mut self, other: &FieldElement) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    #[inline]
+    fn neg(self) -> FieldElement {
+        FieldElement::neg(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldElement;
+    use crate::{FieldBytes, U528};
+    use elliptic_curve::ff::PrimeField;
+    use hex_literal::hex;
+
+    /// Basic tests that field inversion works.
+    #[test]
+    fn invert() {
+        let one = FieldElement::ONE;
+        assert_eq!(one.invert().unwrap(), one);
+
+        let three = one + one + one;
+        let inv_three = three.invert().unwrap();
+        assert_eq!(three * inv_three, one);
+
+        let minus_three = -three;
+        let inv_minus_three = minus_three.invert().unwrap();
+        assert_eq!(inv_minus_three, -inv_three);
+        assert_eq!(three * inv_minus_three, -one);
+
+        assert!(bool::from(FieldElement::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn sqrt() {
+        let one = FieldElement::ONE;
+        let two = one + one;
+        let four = two.square();
+        assert_eq!(four.sqrt().unwrap(), two);
+    }
+
+    #[test]
+    fn root_of_unity() {
+        let root = FieldElement::root_of_unity();
+        assert_eq!(root.square(), FieldElement::ONE);
+        assert_ne!(root, FieldElement::ONE);
+    }
+
+    #[test]
+    fn repr_roundtrip() {
+        let bytes = hex!("00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66");
+        let fe = FieldElement::from_repr(FieldBytes::clone_from_slice(&bytes)).unwrap();
+        assert_eq!(fe.to_repr().as_slice(), &bytes);
+    }
+
+    /// Non-canonical encodings (greater than or equal to `p`) are rejected.
+    #[test]
+    fn decode_overflowing_field_element() {
+        let modulus = super::MODULUS.to_be_bytes();
+        assert!(bool::from(
+            FieldElement::from_repr(FieldBytes::clone_from_slice(&modulus)).is_none()
+        ));
+
+        let max = U528::MAX.to_be_bytes();
+        assert!(bool::from(
+            FieldElement::from_repr(FieldBytes::clone_from_slice(&max)).is_none()
+        ));
+    }
+}
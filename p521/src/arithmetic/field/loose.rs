@@ -0,0 +1,43 @@
+//! "Loose" field elements.
+
+use super::{field_impl::*, FieldElement};
+
+/// Field element whose limbs are within fiat-crypto's "loose" bounds, i.e.
+/// the unreduced result of an addition, subtraction or negation.
+///
+/// The only operations available on loose elements are those which produce
+/// a tight [`FieldElement`].
+#[derive(Clone, Copy)]
+pub(crate) struct LooseFieldElement(pub(super) fiat_p521_loose_field_element);
+
+impl LooseFieldElement {
+    /// Reduce into a tight field element.
+    #[allow(dead_code)]
+    pub(crate) const fn carry(&self) -> FieldElement {
+        FieldElement(fiat_p521_carry(&self.0))
+    }
+
+    /// Multiply two loose field elements, returning a tight result.
+    pub(crate) const fn mul(&self, rhs: &Self) -> FieldElement {
+        FieldElement(fiat_p521_carry_mul(&self.0, &rhs.0))
+    }
+
+    /// Square a loose field element, returning a tight result.
+    pub(crate) const fn square(&self) -> FieldElement {
+        FieldElement(fiat_p521_carry_square(&self.0))
+    }
+}
+
+impl From<FieldElement> for LooseFieldElement {
+    #[inline]
+    fn from(tight: FieldElement) -> LooseFieldElement {
+        tight.relax()
+    }
+}
+
+impl From<LooseFieldElement> for FieldElement {
+    #[inline]
+    fn from(loose: LooseFieldElement) -> FieldElement {
+        loose.carry()
+    }
+}
@@ -0,0 +1,689 @@
+//! secp521r1 scalar field elements.
+
+#![allow(clippy::unusual_byte_groupings)]
+
+// NOTE: fiat-crypto's 64-bit output is used on all targets. Conversions to
+// and from `U528` go through the little endian byte encoding, so they are
+// independent of the target's word size.
+#[path = "scalar/p521_scalar_64.rs"]
+#[allow(clippy::too_many_arguments)]
+mod scalar_impl;
+
+use self::scalar_impl::*;
+use crate::{FieldBytes, NistP521, SecretKey, U528};
+use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    ff::{Field, PrimeField},
+    ops::Reduce,
+    rand_core::RngCore,
+    subtle::{
+        Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+        CtOption,
+    },
+    zeroize::DefaultIsZeroes,
+    Curve as _, Error, IsHigh, Result, ScalarCore,
+};
+
+#[cfg(feature = "bits")]
+use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+#[cfg(doc)]
+use core::ops::{Add, Mul, Sub};
+
+/// Scalars are elements in the finite field modulo `n`.
+///
+/// # Trait impls
+///
+/// Much of the important functionality of scalars is provided by traits from
+/// the [`ff`](https://docs.rs/ff/) crate, which is re-exported as
+/// `p521::elliptic_curve::ff`:
+///
+/// - [`Field`](https://docs.rs/ff/latest/ff/trait.Field.html) -
+///   represents elements of finite fields and provides:
+///   - [`Field::random`](https://docs.rs/ff/latest/ff/trait.Field.html#tymethod.random) -
+///     generate a random scalar
+///   - `double`, `square`, and `invert` operations
+///   - Bounds for [`Add`], [`Sub`], [`Mul`], and [`Neg`] (as well as `*Assign` equivalents)
+///   - Bounds for [`ConditionallySelectable`] from the `subtle` crate
+/// - [`PrimeField`](https://docs.rs/ff/latest/ff/trait.PrimeField.html) -
+///   represents elements of prime fields and provides:
+///   - `from_repr`/`to_repr` for converting field elements from/to big integers.
+///   - `multiplicative_generator` and `root_of_unity` constants.
+/// - [`PrimeFieldBits`](https://docs.rs/ff/latest/ff/trait.PrimeFieldBits.html) -
+///   operations over field elements represented as bits (requires `bits` feature)
+///
+/// Please see the documentation for the relevant traits for more information.
+///
+/// # `serde` support
+///
+/// When the `serde` feature of this crate is enabled, the `Serialize` and
+/// `Deserialize` traits are impl'd for this type.
+///
+/// The serialization is a fixed-width big endian encoding. When used with
+/// textual formats, the binary data is encoded as hexadecimal.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+pub struct Scalar(fiat_p521_scalar_montgomery_domain_field_element);
+
+impl Scalar {
+    /// Zero element.
+    pub const ZERO: Self = Self::from_uint_unchecked(U528::ZERO);
+
+    /// Multiplicative identity.
+    pub const ONE: Self = Self::from_uint_unchecked(U528::ONE);
+
+    /// `2^s` root of unity.
+    pub const ROOT_OF_UNITY: Self = Self::from_be_hex("009a0a650d44b28c17f3d708ad2fa8c4fbc7e6000d7c12dafa92fcc5673a3055276d535f79ff391dcdbcd998b7836647d3a72472b3da861ac810a7f9c7b7b63e2205");
+
+    /// Create a [`Scalar`] from a canonical big-endian representation.
+    pub fn from_be_bytes(repr: FieldBytes) -> CtOption<Self> {
+        Self::from_uint(U528::from_be_byte_array(repr))
+    }
+
+    /// Decode [`Scalar`] from a big endian byte slice.
+    pub fn from_be_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != U528::BYTE_SIZE {
+            return Err(Error);
+        }
+
+        Option::from(Self::from_be_bytes(FieldBytes::clone_from_slice(slice))).ok_or(Error)
+    }
+
+    /// Create a [`Scalar`] from a canonical little-endian representation.
+    pub fn from_le_bytes(repr: FieldBytes) -> CtOption<Self> {
+        Self::from_uint(U528::from_le_byte_array(repr))
+    }
+
+    /// Decode [`Scalar`] from a little endian byte slice.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != U528::BYTE_SIZE {
+            return Err(Error);
+        }
+
+        Option::from(Self::from_le_bytes(FieldBytes::clone_from_slice(slice))).ok_or(Error)
+    }
+
+    /// Decode [`Scalar`] from [`U528`] converting it into Montgomery form:
+    ///
+    /// ```text
+    /// w * R^2 * R^-1 mod n = wR mod n
+    /// ```
+    pub fn from_uint(uint: U528) -> CtOption<Self> {
+        let is_some = uint.ct_lt(&NistP521::ORDER);
+        CtOption::new(Self::from_uint_unchecked(uint), is_some)
+    }
+
+    /// Parse a [`Scalar`] from big endian hex-encoded bytes.
+    ///
+    /// Does *not* perform a check that the field element does not overflow the order.
+    ///
+    /// This method is primarily intended for defining internal constants.
+    #[allow(dead_code)]
+    pub(crate) const fn from_be_hex(hex: &str) -> Self {
+        Self::from_uint_unchecked(U528::from_be_hex(hex))
+    }
+
+    /// Decode [`Scalar`] from [`U528`] converting it into Montgomery form.
+    ///
+    /// Does *not* perform a check that the field element does not overflow the order.
+    ///
+    /// Used incorrectly this can lead to invalid results!
+    pub(crate) const fn from_uint_unchecked(w: U528) -> Self {
+        Self(fiat_p521_scalar_to_montgomery(
+            &fiat_p521_scalar_from_bytes(&w.to_le_bytes()),
+        ))
+    }
+
+    /// Returns the big-endian encoding of this [`Scalar`].
+    pub fn to_be_bytes(self) -> FieldBytes {
+        self.to_canonical().to_be_byte_array()
+    }
+
+    /// Returns the little-endian encoding of this [`Scalar`].
+    pub fn to_le_bytes(self) -> FieldBytes {
+        self.to_canonical().to_le_byte_array()
+    }
+
+    /// Translate [`Scalar`] out of the Montgomery domain, returning a
+    /// [`U528`] in canonical form.
+    #[inline]
+    pub const fn to_canonical(self) -> U528 {
+        U528::from_le_bytes(fiat_p521_scalar_to_bytes(
+            &fiat_p521_scalar_from_montgomery(&self.0),
+        ))
+    }
+
+    /// Determine if this [`Scalar`] is odd in the SEC1 sense: `self mod 2 == 1`.
+    ///
+    /// # Returns
+    ///
+    /// If odd, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_odd(&self) -> Choice {
+        Choice::from((fiat_p521_scalar_from_montgomery(&self.0)[0] & 1) as u8)
+    }
+
+    /// Determine if this [`Scalar`] is even in the SEC1 sense: `self mod 2 == 0`.
+    ///
+    /// # Returns
+    ///
+    /// If even, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_even(&self) -> Choice {
+        !self.is_odd()
+    }
+
+    /// Determine if this [`Scalar`] is zero.
+    ///
+    /// # Returns
+    ///
+    /// If zero, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    /// Add elements.
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self(fiat_p521_scalar_add(&self.0, &rhs.0))
+    }
+
+    /// Double element (add it to itself).
+    #[must_use]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Subtract elements.
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        Self(fiat_p521_scalar_sub(&self.0, &rhs.0))
+    }
+
+    /// Multiply elements.
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        Self(fiat_p521_scalar_mul(&self.0, &rhs.0))
+    }
+
+    /// Negate element.
+    pub const fn neg(&self) -> Self {
+        Self(fiat_p521_scalar_opp(&self.0))
+    }
+
+    /// Compute modular square.
+    #[must_use]
+    pub const fn square(&self) -> Self {
+        Self(fiat_p521_scalar_square(&self.0))
+    }
+
+    /// Compute [`Scalar`] inversion: `1 / self`.
+    pub fn invert(&self) -> CtOption<Self> {
+        let ret = impl_field_invert!(
+            fiat_p521_scalar_from_montgomery(&self.0),
+            Self::ONE.0,
+            521,
+            64,
+            9,
+            fiat_p521_scalar_mul,
+            fiat_p521_scalar_opp,
+            fiat_p521_scalar_divstep_precomp,
+            fiat_p521_scalar_divstep,
+            fiat_p521_scalar_msat,
+            fiat_p521_scalar_selectznz,
+        );
+        CtOption::new(Self(ret), !self.is_zero())
+    }
+
+    /// Compute modular square root.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // n mod 8 = 1 -> compute sqrt(x) using Tonelli-Shanks, where
+        // w = x^((t - 1) / 2) and t = (n - 1) / 2^3
+        //
+        // Note: `pow_vartime` is constant-time with respect to `self`
+        let w = self.pow_vartime(&[
+            0xebb6fb71e9138640,
+            0x03bb5c9b8899c47a,
+            0xb7fcc0148f709a5d,
+            0xa51868783bf2f966,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x000000000000001f,
+        ]);
+
+        let mut v = Self::S;
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = Self::ROOT_OF_UNITY;
+
+        for max_v in (1..=Self::S).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v = Choice::from(1);
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.ct_eq(&Self::ONE);
+                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
+            z = z.square();
+            b *= z;
+            v = k;
+        }
+
+        CtOption::new(x, x.square().ct_eq(self))
+    }
+
+    /// Returns `self^exp`, where `exp` is a little-endian integer exponent.
+    ///
+    /// **This operation is variable time with respect to the exponent.**
+    ///
+    /// If the exponent is fixed, this operation is effectively constant time.
+    pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+        let mut res = Self::ONE;
+
+        for e in exp.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.square();
+
+                if ((*e >> i) & 1) == 1 {
+                    res *= self;
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Returns the SEC1 encoding of this scalar.
+    ///
+    /// Required for running test vectors.
+    #[cfg(test)]
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+}
+
+impl AsRef<fiat_p521_scalar_montgomery_domain_field_element> for Scalar {
+    fn as_ref(&self) -> &fiat_p521_scalar_montgomery_domain_field_element {
+        &self.0
+    }
+}
+
+impl Default for Scalar {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Eq for Scalar {}
+
+impl PartialEq for Scalar {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.ct_eq(rhs).into()
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = Self::ZERO;
+
+        for i in 0..ret.0.len() {
+            ret.0[i] = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+
+        ret
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl DefaultIsZeroes for Scalar {}
+
+impl Field for Scalar {
+    fn random(mut rng: impl RngCore) -> Self {
+        // NOTE: can't use ScalarCore::random due to CryptoRng bound
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(scalar) = Self::from_be_bytes(bytes).into() {
+                return scalar;
+            }
+        }
+    }
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_zero(&self) -> Choice {
+        Self::ZERO.ct_eq(self)
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
+
+weierstrass::impl_field_op!(Scalar, U528, Add, add, fiat_p521_scalar_add);
+weierstrass::impl_field_op!(Scalar, U528, Sub, sub, fiat_p521_scalar_sub);
+weierstrass::impl_field_op!(Scalar, U528, Mul, mul, fiat_p521_scalar_mul);
+
+impl AddAssign<Scalar> for Scalar {
+    #[inline]
+    fn add_assign(&mut self, other: Scalar) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<&Scalar> for Scalar {
+    #[inline]
+    fn add_assign(&mut self, other: &Scalar) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign<Scalar> for Scalar {
+    #[inline]
+    fn sub_assign(&mut self, other: Scalar) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<&Scalar> for Scalar {
+    #[inline]
+    fn sub_assign(&mut self, other: &Scalar) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<&Scalar> for Scalar {
+    #[inline]
+    fn mul_assign(&mut self, other: &Scalar) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign for Scalar {
+    #[inline]
+    fn mul_assign(&mut self, other: Scalar) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    #[inline]
+    fn neg(self) -> Scalar {
+        Scalar::neg(&self)
+    }
+}
+
+impl IsHigh for Scalar {
+    fn is_high(&self) -> Choice {
+        const MODULUS_SHR1: U528 = NistP521::ORDER.shr_vartime(1);
+        self.to_canonical().ct_gt(&MODULUS_SHR1)
+    }
+}
+
+impl PrimeField for Scalar {
+    type Repr = FieldBytes;
+
+    const CAPACITY: u32 = 520;
+    const NUM_BITS: u32 = 521;
+    const S: u32 = 3;
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.to_be_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.is_odd()
+    }
+
+    fn multiplicative_generator() -> Self {
+        3u64.into()
+    }
+
+    fn root_of_unity() -> Self {
+        Self::ROOT_OF_UNITY
+    }
+}
+
+#[cfg(feature = "bits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
+impl PrimeFieldBits for Scalar {
+    type ReprBits = fiat_p521_scalar_montgomery_domain_field_element;
+
+    fn to_le_bits(&self) -> ScalarBits {
+        fiat_p521_scalar_from_montgomery(&self.0).into()
+    }
+
+    fn char_le_bits() -> ScalarBits {
+        fiat_p521_scalar_from_bytes(&NistP521::ORDER.to_le_bytes()).into()
+    }
+}
+
+impl Reduce<U528> for Scalar {
+    fn from_uint_reduced(w: U528) -> Self {
+        // Unlike the other NIST curves, `U528` can hold values much larger
+        // than the order, so a full modular reduction is required.
+        Self::from_uint_unchecked(w.reduce(&NistP521::ORDER).unwrap())
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Scalar {
+        Self::from_uint_unchecked(U528::from_u64(n))
+    }
+}
+
+impl From<ScalarCore<NistP521>> for Scalar {
+    fn from(w: ScalarCore<NistP521>) -> Self {
+        Scalar::from(&w)
+    }
+}
+
+impl From<&ScalarCore<NistP521>> for Scalar {
+    fn from(w: &ScalarCore<NistP521>) -> Scalar {
+        Scalar::from_uint_unchecked(*w.as_uint())
+    }
+}
+
+impl From<Scalar> for ScalarCore<NistP521> {
+    fn from(scalar: Scalar) -> ScalarCore<NistP521> {
+        ScalarCore::from(&scalar)
+    }
+}
+
+impl From<&Scalar> for ScalarCore<NistP521> {
+    fn from(scalar: &Scalar) -> ScalarCore<NistP521> {
+        ScalarCore::new(scalar.into()).unwrap()
+    }
+}
+
+impl From<Scalar> for FieldBytes {
+    fn from(scalar: Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl From<&Scalar> for FieldBytes {
+    fn from(scalar: &Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl From<Scalar> for U528 {
+    fn from(scalar: Scalar) -> U528 {
+        U528::from(&scalar)
+    }
+}
+
+impl From<&Scalar> for U528 {
+    fn from(scalar: &Scalar) -> U528 {
+        scalar.to_canonical()
+    }
+}
+
+impl From<&SecretKey> for Scalar {
+    fn from(secret_key: &SecretKey) -> Scalar {
+        *secret_key.to_nonzero_scalar()
+    }
+}
+
+impl TryFrom<U528> for Scalar {
+    type Error = Error;
+
+    fn try_from(w: U528) -> Result<Self> {
+        Option::from(Self::from_uint(w)).ok_or(Error)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        ScalarCore::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Ok(ScalarCore::deserialize(deserializer)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scalar;
+    use crate::{FieldBytes, NistP521, U528};
+    use elliptic_curve::{
+        ff::{Field, PrimeField},
+        ops::Reduce,
+        Curve, IsHigh,
+    };
+
+    #[test]
+    fn from_to_bytes_roundtrip() {
+        let k: u64 = 42;
+        let mut bytes = FieldBytes::default();
+        bytes[58..].copy_from_slice(k.to_be_bytes().as_ref());
+
+        let scalar = Scalar::from_repr(bytes).unwrap();
+        assert_eq!(bytes, scalar.to_be_bytes());
+        assert_eq!(scalar, Scalar::from(k));
+    }
+
+    /// Basic tests that multiplication works.
+    #[test]
+    fn multiply() {
+        let one = Scalar::one();
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
+
+        let minus_two = -two;
+        let minus_three = -three;
+        assert_eq!(two, -minus_two);
+
+        assert_eq!(minus_three * minus_two, minus_two * minus_three);
+        assert_eq!(six, minus_two * minus_three);
+    }
+
+    /// Basic tests that scalar inversion works.
+    #[test]
+    fn invert() {
+        let one = Scalar::one();
+        let three = one + one + one;
+        let inv_three = three.invert().unwrap();
+        assert_eq!(three * inv_three, one);
+
+        let minus_three = -three;
+        let inv_minus_three = minus_three.invert().unwrap();
+        assert_eq!(inv_minus_three, -inv_three);
+        assert_eq!(three * inv_minus_three, -one);
+
+        assert!(bool::from(Scalar::ZERO.invert().is_none()));
+    }
+
+    /// Basic tests that sqrt works.
+    #[test]
+    fn sqrt() {
+        for &n in &[1u64, 4, 9, 16, 25, 36, 49, 64] {
+            let scalar = Scalar::from(n);
+            let sqrt = scalar.sqrt().unwrap();
+            assert_eq!(sqrt.square(), scalar);
+        }
+
+        // 3 is the multiplicative generator, hence a non-residue
+        assert!(bool::from(Scalar::from(3).sqrt().is_none()));
+    }
+
+    #[test]
+    fn root_of_unity() {
+        let root = Scalar::root_of_unity();
+        assert_eq!(root.square().square().square(), Scalar::ONE);
+        assert_ne!(root.square().square(), Scalar::ONE);
+    }
+
+    #[test]
+    fn is_high() {
+        let half = Scalar::from_uint(NistP521::ORDER.shr_vartime(1)).unwrap();
+        assert!(!bool::from(half.is_high()));
+        assert!(bool::from((half + Scalar::ONE).is_high()));
+    }
+
+    /// `U528` values can be much larger than the order, and must be fully
+    /// reduced.
+    #[test]
+    fn reduce_max() {
+        let reduced = Scalar::from_uint_reduced(U528::MAX);
+        let expected = U528::MAX.reduce(&NistP521::ORDER).unwrap();
+        assert_eq!(reduced.to_canonical(), expected);
+        assert_eq!(Scalar::from_uint_reduced(NistP521::ORDER), Scalar::ZERO);
+    }
+}
@@ -0,0 +1,811 @@
+//! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! To use it, you will need to enable one of the two following Cargo features:
+//!
+//! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
+//!   ECDSA/P-521 signature). Does not require the `arithmetic` feature. This is
+//!   useful for 3rd-party crates which wish to use the `Signature` type for
+//!   interoperability purposes (particularly in conjunction with the
+//!   [`signature::Signer`] trait. Example use cases for this include other
+//!   software implementations of ECDSA/P-521 and wrappers for cloud KMS
+//!   services or hardware devices (HSM or crypto hardware wallet).
+//! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
+//!   [`VerifyingKey`] types which natively implement ECDSA/P-521 signing and
+//!   verification.
+//!
+//! Unlike the other NIST curves, P-521's field size (66 bytes) does not match
+//! the output size of its associated digest (SHA-512, 64 bytes), so the
+//! [`SigningKey`] and [`VerifyingKey`] types are thin wrappers around the
+//! generic types from the `ecdsa` crate which implement RFC6979 and the
+//! [`signature`] traits for SHA-512 specifically.
+//!
+//! ## Signing/Verification Example
+//!
+//! This example requires the `ecdsa` Cargo feature is enabled:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p521::ecdsa::{signature::Signer, Signature, SigningKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Signing
+//! let signing_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//! let signature: Signature = signing_key.sign(message);
+//!
+//! // Verification
+//! use p521::ecdsa::{signature::Verifier, VerifyingKey};
+//!
+//! let verifying_key = VerifyingKey::from(&signing_key); // Serialize with `::to_encoded_point()`
+//! assert!(verifying_key.verify(message, &signature).is_ok());
+//! # }
+//! ```
+
+pub use ecdsa_core::signature::{self, Error};
+
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub use weierstrass::ecdsa::HardenedSigner;
+
+#[cfg(feature = "sha512")]
+use {
+    crate::{FieldBytes, U528},
+    ecdsa_core::hazmat::DigestPrimitive,
+    sha2::Sha512,
+};
+
+#[cfg(feature = "ecdsa")]
+use {
+    crate::{
+        AffinePoint, BlindedScalar, EncodedPoint, NonZeroScalar, PublicKey, Scalar, SecretKey,
+    },
+    core::fmt::{self, Debug},
+    ecdsa_core::{
+        hazmat::{SignPrimitive, VerifyPrimitive},
+        signature::{
+            digest::{Digest, FixedOutput},
+            hazmat::{PrehashSigner, PrehashVerifier},
+            rand_core::{CryptoRng, RngCore},
+            DigestSigner, DigestVerifier, Keypair, RandomizedDigestSigner, RandomizedSigner,
+            Signer, Verifier,
+        },
+    },
+    elliptic_curve::{
+        ops::Reduce,
+        subtle::{Choice, ConstantTimeEq},
+        zeroize::Zeroize,
+    },
+    rfc6979::HmacDrbg,
+    weierstrass::ecdsa::hazmat::sign_prehashed_hardened,
+};
+
+#[cfg(feature = "pkcs8")]
+use crate::pkcs8::{self, DecodePrivateKey, DecodePublicKey};
+
+#[cfg(feature = "pem")]
+use {
+    crate::pkcs8::{EncodePrivateKey, EncodePublicKey, SecretDocument},
+    core::str::FromStr,
+};
+
+#[cfg(all(feature = "pem", feature = "serde"))]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+use super::NistP521;
+
+/// ECDSA/P-521 signature (fixed-size)
+pub type Signature = ecdsa_core::Signature<NistP521>;
+
+/// ECDSA/P-521 signature (ASN.1 DER encoded)
+pub type DerSignature = ecdsa_core::der::Signature<NistP521>;
+
+#[cfg(feature = "sha512")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha512")))]
+impl DigestPrimitive for NistP521 {
+    type Digest = Sha512;
+
+    /// Compute field bytes for a prehash (message digest).
+    ///
+    /// P-521's order is 521 bits, which is not a whole number of bytes, so
+    /// prehashes longer than that must be truncated to their leftmost 521
+    /// bits as described in RFC6979 § 2.3.2 (`bits2int`), rather than to
+    /// their leftmost 66 bytes.
+    fn prehash_to_field_bytes(prehash: &[u8]) -> signature::Result<FieldBytes> {
+        // Minimum allowed prehash size is half the field size
+        if prehash.len() < U528::BYTE_SIZE / 2 {
+            return Err(Error::new());
+        }
+
+        let mut bytes = [0u8; U528::BYTE_SIZE];
+
+        if prehash.len() * 8 <= 521 {
+            bytes[(U528::BYTE_SIZE - prehash.len())..].copy_from_slice(prehash);
+        } else {
+            bytes.copy_from_slice(&prehash[..U528::BYTE_SIZE]);
+            bytes = U528::from_be_bytes(bytes)
+                .shr_vartime(U528::BIT_SIZE - 521)
+                .to_be_bytes();
+        }
+
+        Ok(FieldBytes::clone_from_slice(&bytes))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<NistP521> for Scalar {}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<NistP521> for AffinePoint {}
+
+/// ECDSA/P-521 signing key
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct SigningKey {
+    /// Generic signing key from the `ecdsa` crate.
+    inner: ecdsa_core::SigningKey<NistP521>,
+
+    /// Verifying key which corresponds to this signing key.
+    verifying_key: VerifyingKey,
+}
+
+#[cfg(feature = "ecdsa")]
+impl SigningKey {
+    /// Generate a cryptographically random [`SigningKey`].
+    pub fn random(rng: impl CryptoRng + RngCore) -> Self {
+        ecdsa_core::SigningKey::random(rng).into()
+    }
+
+    /// Initialize signing key from a raw scalar serialized as a byte slice.
+    pub fn from_bytes(bytes: &[u8]) -> signature::Result<Self> {
+        ecdsa_core::SigningKey::from_bytes(bytes).map(Into::into)
+    }
+
+    /// Serialize this [`SigningKey`] as bytes
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.inner.to_bytes()
+    }
+
+    /// Borrow the secret [`NonZeroScalar`] value for this key.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn as_nonzero_scalar(&self) -> &NonZeroScalar {
+        self.inner.as_nonzero_scalar()
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this [`SigningKey`].
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.verifying_key
+    }
+
+    /// Compute the ephemeral scalar `k` for the field-sized prehash `z`
+    /// deterministically as described in RFC6979 § 3.2 using HMAC-SHA-512,
+    /// with optional additional data `ad` (RFC6979 § 3.6).
+    fn rfc6979_nonce(&self, z: &FieldBytes, ad: &[u8]) -> Scalar {
+        Self::next_scalar(&mut self.rfc6979_drbg(z, ad))
+    }
+
+    /// Instantiate HMAC_DRBG with the inputs described in RFC6979 § 3.2.
+    fn rfc6979_drbg(&self, z: &FieldBytes, ad: &[u8]) -> HmacDrbg<Sha512> {
+        let d: &Scalar = self.as_nonzero_scalar().as_ref();
+        let mut x = d.to_be_bytes();
+        let h = <Scalar as Reduce<U528>>::from_be_bytes_reduced(*z).to_be_bytes();
+        let drbg = HmacDrbg::new(&x, &h, ad);
+        x.zeroize();
+        drbg
+    }
+
+    /// Generate the next non-zero scalar less than `n` from HMAC_DRBG.
+    ///
+    /// `generate_k` from the `rfc6979` crate can't be used here as it expects
+    /// the digest output to be the same size as the field, whereas for P-521
+    /// each 66-byte block of HMAC_DRBG output must be truncated to 521 bits.
+    fn next_scalar(drbg: &mut HmacDrbg<Sha512>) -> Scalar {
+        loop {
+            let mut t = [0u8; U528::BYTE_SIZE];
+            drbg.fill_bytes(&mut t);
+
+            let k = U528::from_be_bytes(t).shr_vartime(U528::BIT_SIZE - 521);
+            t.zeroize();
+            let k =
+                Option::<Scalar>::from(Scalar::from_uint(k)).filter(|k| !bool::from(k.is_zero()));
+
+            if let Some(k) = k {
+                return k;
+            }
+        }
+    }
+
+    /// Sign the given field-sized prehash `z` using the RFC6979 nonce.
+    ///
+    /// `k` is inverted as a [`BlindedScalar`], masked with the next scalar
+    /// output by the same HMAC_DRBG instance.
+    fn sign_prehashed_rfc6979(&self, z: FieldBytes, ad: &[u8]) -> signature::Result<Signature> {
+        let d: &Scalar = self.as_nonzero_scalar().as_ref();
+        let mut drbg = self.rfc6979_drbg(&z, ad);
+        let k = Self::next_scalar(&mut drbg);
+        let mask = Self::next_scalar(&mut drbg);
+        Ok(d.try_sign_prehashed(BlindedScalar::with_mask(k, mask), z)?
+            .0)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl AsRef<VerifyingKey> for SigningKey {
+    fn as_ref(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl ConstantTimeEq for SigningKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.inner.ct_eq(&other.inner)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DigestSigner<Sha512, Signature> for SigningKey {
+    /// Sign message digest using a deterministic ephemeral scalar (`k`)
+    /// computed using the algorithm described in [RFC6979 § 3.2].
+    ///
+    /// [RFC6979 § 3.2]: https://tools.ietf.org/html/rfc6979#section-3
+    fn try_sign_digest(&self, msg_digest: Sha512) -> signature::Result<Signature> {
+        self.sign_prehash(&msg_digest.finalize_fixed())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Keypair<Signature> for SigningKey {
+    type VerifyingKey = VerifyingKey;
+}
+
+#[cfg(feature = "ecdsa")]
+impl PrehashSigner<Signature> for SigningKey {
+    fn sign_prehash(&self, prehash: &[u8]) -> signature::Result<Signature> {
+        let z = NistP521::prehash_to_field_bytes(prehash)?;
+        self.sign_prehashed_rfc6979(z, &[])
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Signer<Signature> for SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> signature::Result<Signature> {
+        self.try_sign_digest(Sha512::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl HardenedSigner<Signature> for SigningKey {
+    fn try_sign_hardened(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+    ) -> signature::Result<Signature> {
+        self.sign_prehash_hardened(rng, &Sha512::digest(msg))
+    }
+
+    fn sign_prehash_hardened(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        prehash: &[u8],
+    ) -> signature::Result<Signature> {
+        let z = NistP521::prehash_to_field_bytes(prehash)?;
+        let k = self.rfc6979_nonce(&z, &[]);
+        sign_prehashed_hardened(self.as_nonzero_scalar().as_ref(), k, z, rng)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedDigestSigner<Sha512, Signature> for SigningKey {
+    /// Sign message prehash using an ephemeral scalar (`k`) derived according
+    /// to a variant of RFC 6979 (Section 3.6) which supplies additional
+    /// entropy from an RNG.
+    fn try_sign_digest_with_rng(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        msg_digest: Sha512,
+    ) -> signature::Result<Signature> {
+        let mut ad = FieldBytes::default();
+        rng.fill_bytes(&mut ad);
+
+        let z = NistP521::prehash_to_field_bytes(&msg_digest.finalize_fixed())?;
+        self.sign_prehashed_rfc6979(z, &ad)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedSigner<Signature> for SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+    ) -> signature::Result<Signature> {
+        self.try_sign_digest_with_rng(rng, Sha512::new_with_prefix(msg))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<NonZeroScalar> for SigningKey {
+    fn from(secret_scalar: NonZeroScalar) -> Self {
+        ecdsa_core::SigningKey::from(secret_scalar).into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SecretKey> for SigningKey {
+    fn from(secret_key: SecretKey) -> Self {
+        Self::from(&secret_key)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SecretKey> for SigningKey {
+    fn from(secret_key: &SecretKey) -> Self {
+        ecdsa_core::SigningKey::from(secret_key).into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SigningKey> for SecretKey {
+    fn from(key: SigningKey) -> Self {
+        key.inner.into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SigningKey> for SecretKey {
+    fn from(key: &SigningKey) -> Self {
+        (&key.inner).into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<ecdsa_core::SigningKey<NistP521>> for SigningKey {
+    fn from(inner: ecdsa_core::SigningKey<NistP521>) -> Self {
+        let verifying_key = VerifyingKey(inner.verifying_key());
+        Self {
+            inner,
+            verifying_key,
+        }
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<SigningKey> for ecdsa_core::SigningKey<NistP521> {
+    fn from(key: SigningKey) -> Self {
+        key.inner
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> signature::Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pkcs8"))))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for SigningKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        ecdsa_core::SigningKey::try_from(private_key_info).map(Into::into)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pkcs8"))))]
+impl DecodePrivateKey for SigningKey {}
+
+#[cfg(all(feature = "ecdsa", feature = "pem"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pem"))))]
+impl EncodePrivateKey for SigningKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        self.inner.to_pkcs8_der()
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pem"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pem"))))]
+impl FromStr for SigningKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> signature::Result<Self> {
+        Self::from_pkcs8_pem(s).map_err(|_| Error::new())
+    }
+}
+
+/// ECDSA/P-521 verification key (i.e. public key)
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct VerifyingKey(ecdsa_core::VerifyingKey<NistP521>);
+
+#[cfg(feature = "ecdsa")]
+impl VerifyingKey {
+    /// Initialize [`VerifyingKey`] from a SEC1-encoded public key.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> signature::Result<Self> {
+        ecdsa_core::VerifyingKey::from_sec1_bytes(bytes).map(Self)
+    }
+
+    /// Initialize [`VerifyingKey`] from an affine point.
+    ///
+    /// Returns an [`Error`] if the given affine point is the additive identity
+    /// (a.k.a. point at infinity).
+    pub fn from_affine(affine: AffinePoint) -> signature::Result<Self> {
+        ecdsa_core::VerifyingKey::from_affine(affine).map(Self)
+    }
+
+    /// Initialize [`VerifyingKey`] from an [`EncodedPoint`].
+    pub fn from_encoded_point(public_key: &EncodedPoint) -> signature::Result<Self> {
+        ecdsa_core::VerifyingKey::from_encoded_point(public_key).map(Self)
+    }
+
+    /// Serialize this [`VerifyingKey`] as a SEC1 [`EncodedPoint`], optionally
+    /// applying point compression.
+    pub fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.0.to_encoded_point(compress)
+    }
+
+    /// Borrow the inner [`AffinePoint`] for this public key.
+    pub fn as_affine(&self) -> &AffinePoint {
+        self.0.as_affine()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl AsRef<AffinePoint> for VerifyingKey {
+    fn as_ref(&self) -> &AffinePoint {
+        self.as_affine()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DigestVerifier<Sha512, Signature> for VerifyingKey {
+    fn verify_digest(&self, msg_digest: Sha512, signature: &Signature) -> signature::Result<()> {
+        self.verify_prehash(&msg_digest.finalize_fixed(), signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl PrehashVerifier<Signature> for VerifyingKey {
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> signature::Result<()> {
+        self.0.verify_prehash(prehash, signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Verifier<Signature> for VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> signature::Result<()> {
+        self.verify_digest(Sha512::new_with_prefix(msg), signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&SigningKey> for VerifyingKey {
+    fn from(signing_key: &SigningKey) -> VerifyingKey {
+        signing_key.verifying_key()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<PublicKey> for VerifyingKey {
+    fn from(public_key: PublicKey) -> VerifyingKey {
+        Self(public_key.into())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&PublicKey> for VerifyingKey {
+    fn from(public_key: &PublicKey) -> VerifyingKey {
+        Self(public_key.into())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<VerifyingKey> for PublicKey {
+    fn from(verifying_key: VerifyingKey) -> PublicKey {
+        verifying_key.0.into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&VerifyingKey> for PublicKey {
+    fn from(verifying_key: &VerifyingKey) -> PublicKey {
+        (&verifying_key.0).into()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<&VerifyingKey> for EncodedPoint {
+    fn from(verifying_key: &VerifyingKey) -> EncodedPoint {
+        verifying_key.to_encoded_point(false)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<ecdsa_core::VerifyingKey<NistP521>> for VerifyingKey {
+    fn from(inner: ecdsa_core::VerifyingKey<NistP521>) -> Self {
+        Self(inner)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl From<VerifyingKey> for ecdsa_core::VerifyingKey<NistP521> {
+    fn from(verifying_key: VerifyingKey) -> Self {
+        verifying_key.0
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> signature::Result<Self> {
+        Self::from_sec1_bytes(bytes)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pkcs8"))))]
+impl TryFrom<pkcs8::SubjectPublicKeyInfo<'_>> for VerifyingKey {
+    type Error = pkcs8::spki::Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> pkcs8::spki::Result<Self> {
+        ecdsa_core::VerifyingKey::try_from(spki).map(Self)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pkcs8"))))]
+impl DecodePublicKey for VerifyingKey {}
+
+#[cfg(all(feature = "ecdsa", feature = "pem"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pem"))))]
+impl EncodePublicKey for VerifyingKey {
+    fn to_public_key_der(&self) -> pkcs8::spki::Result<pkcs8::Document> {
+        self.0.to_public_key_der()
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pem"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "pem"))))]
+impl FromStr for VerifyingKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> signature::Result<Self> {
+        Self::from_public_key_pem(s).map_err(|_| Error::new())
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pem", feature = "serde"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "ecdsa", feature = "pem", feature = "serde")))
+)]
+impl Serialize for VerifyingKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "pem", feature = "serde"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "ecdsa", feature = "pem", feature = "serde")))
+)]
+impl<'de> Deserialize<'de> for VerifyingKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        ecdsa_core::VerifyingKey::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use crate::{
+        ecdsa::{
+            signature::{hazmat::PrehashSigner, Signer, Verifier},
+            HardenedSigner, SigningKey, VerifyingKey,
+        },
+        SecretKey,
+    };
+    use ecdsa_core::hazmat::DigestPrimitive;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha512};
+
+    // Test vector from RFC 6979 Appendix 2.7 (NIST P-521 + SHA-512)
+    // <https://tools.ietf.org/html/rfc6979#appendix-A.2.7>
+    #[test]
+    fn rfc6979() {
+        let x = &hex!("00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "00c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e377fa
+                00617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e44da4a67a"
+            )[..]
+        );
+        let signature = signer.sign(b"test");
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "013e99020abf5cee7525d16b69b229652ab6bdf2affcaef38773b4b7d08725f10cdb93482fdcc54edcee91eca4166b2a7c6265ef0ce2bd7051b7cef945babd47ee6d
+                01fbd0013c674aa79cb39849527916ce301c66ea7ce8b80682786ad60f98f7e78a19ca69eff5c57400e3b3a0ad66ce0978214d13baf4e9ac60752f7b155e2de4dce3"
+            )[..]
+        );
+    }
+
+    #[test]
+    fn signing_secret_key_equivalent() {
+        let raw_sk = hex!("00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538");
+
+        let sigk = SigningKey::from_bytes(raw_sk.as_slice()).unwrap();
+        let seck = SecretKey::from_be_bytes(raw_sk.as_slice()).unwrap();
+
+        assert_eq!(sigk.to_bytes().as_slice(), &raw_sk);
+        assert_eq!(sigk.to_bytes(), seck.to_be_bytes());
+        assert_eq!(sigk.verifying_key(), VerifyingKey::from(seck.public_key()));
+    }
+
+    #[test]
+    fn sign_prehash_matches_sign() {
+        let x = &hex!("00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let prehash = Sha512::digest(b"sample");
+        let signature = signer.sign_prehash(&prehash).unwrap();
+        assert_eq!(signature, signer.sign(b"sample"));
+        assert!(signer.verifying_key().verify(b"sample", &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_hardened() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let msg = b"ECDSA proves knowledge of a secret number in the context of a single message";
+        assert_eq!(
+            signing_key.sign(msg),
+            signing_key.sign_hardened(&mut OsRng, msg)
+        );
+
+        let prehash = [0xffu8; 66];
+        let signature = signing_key.sign_prehash(&prehash).unwrap();
+        let hardened = signing_key.sign_prehash_hardened(&mut OsRng, &prehash);
+        assert_eq!(signature, hardened.unwrap());
+    }
+
+    /// Prehashes longer than 521 bits are truncated to their leftmost 521
+    /// bits, not to their leftmost 66 bytes.
+    #[test]
+    fn prehash_bits2int_truncation() {
+        let mut prehash = [0xffu8; 66];
+        prehash[65] = 0x80;
+        let z = crate::NistP521::prehash_to_field_bytes(&prehash).unwrap();
+        let mut expected = [0xffu8; 66];
+        expected[0] = 0x01;
+        assert_eq!(z.as_slice(), &expected);
+
+        let z = crate::NistP521::prehash_to_field_bytes(&[0xff; 64]).unwrap();
+        assert_eq!(&z[..2], &[0, 0]);
+        assert_eq!(&z[2..], &[0xff; 64]);
+
+        assert!(crate::NistP521::prehash_to_field_bytes(&[0xff; 32]).is_err());
+    }
+
+    mod sign {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
+        ecdsa_core::new_signing_test!(NistP521, ECDSA_TEST_VECTORS);
+    }
+
+    mod verify {
+        use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
+        ecdsa_core::new_verification_test!(NistP521, ECDSA_TEST_VECTORS);
+    }
+
+    /// Wycheproof ECDSA/P-521/SHA-512 vectors.
+    ///
+    /// `ecdsa_core::new_wycheproof_test!` can't be used here as it requires
+    /// the generic `ecdsa::VerifyingKey` to impl `Verifier`, which it doesn't
+    /// for P-521 (see the module-level documentation).
+    mod wycheproof {
+        use crate::{
+            ecdsa::{signature::Verifier, Signature, VerifyingKey},
+            EncodedPoint, FieldBytes,
+        };
+        use blobby::Blob5Iterator;
+
+        /// Build a field element but allow for too-short input (left pad with
+        /// zeros) or too-long input (check excess leftmost bytes are zeros).
+        fn element_from_padded_slice(data: &[u8]) -> FieldBytes {
+            let point_len = FieldBytes::default().len();
+            if data.len() >= point_len {
+                let offset = data.len() - point_len;
+                for v in data.iter().take(offset) {
+                    assert_eq!(*v, 0, "EcdsaVerifier: point too large");
+                }
+                FieldBytes::clone_from_slice(&data[offset..])
+            } else {
+                let mut bytes = FieldBytes::default();
+                bytes[(point_len - data.len())..].copy_from_slice(data);
+                bytes
+            }
+        }
+
+        fn run_test(
+            wx: &[u8],
+            wy: &[u8],
+            msg: &[u8],
+            sig: &[u8],
+            pass: bool,
+        ) -> Option<&'static str> {
+            let x = element_from_padded_slice(wx);
+            let y = element_from_padded_slice(wy);
+            let q_encoded = EncodedPoint::from_affine_coordinates(&x, &y, false);
+            let verifying_key = VerifyingKey::from_encoded_point(&q_encoded).unwrap();
+
+            let sig = match Signature::from_der(sig) {
+                Ok(s) => s,
+                Err(_) if !pass => return None,
+                Err(_) => return Some("failed to parse signature ASN.1"),
+            };
+
+            match verifying_key.verify(msg, &sig) {
+                Ok(_) if pass => None,
+                Ok(_) => Some("signature verify unexpectedly succeeded"),
+                Err(_) if !pass => None,
+                Err(_) => Some("signature verify failed"),
+            }
+        }
+
+        #[test]
+        fn wycheproof() {
+            let data = include_bytes!("test_vectors/data/wycheproof.blb");
+
+            for (i, row) in Blob5Iterator::new(data).unwrap().enumerate() {
+                let [wx, wy, msg, sig, status] = row.unwrap();
+                let pass = match status[0] {
+                    0 => false,
+                    1 => true,
+                    _ => panic!("invalid value for pass flag"),
+                };
+                if let Some(desc) = run_test(wx, wy, msg, sig, pass) {
+                    panic!(
+                        "\n\
+                         Failed test №{}: {}\n\
+                         wx:\t{:?}\n\
+                         wy:\t{:?}\n\
+                         msg:\t{:?}\n\
+                         sig:\t{:?}\n\
+                         pass:\t{}\n",
+                        i, desc, wx, wy, msg, sig, pass,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -8,8 +8,32 @@
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa-core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa-core")))]
+pub mod ecdsa;
+
+#[cfg(any(feature = "test-vectors", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+
+mod uint;
+
+pub use crate::uint::U528;
 pub use elliptic_curve::{self, bigint::U576};
 
+#[cfg(feature = "arithmetic")]
+pub use arithmetic::{scalar::Scalar, AffinePoint, BlindedScalar, ProjectivePoint};
+
+#[cfg(feature = "expose-field")]
+pub use arithmetic::field::FieldElement;
+
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
 pub use elliptic_curve::pkcs8;
@@ -21,11 +45,14 @@ use elliptic_curve::{consts::U66, generic_array::GenericArray};
 pub struct NistP521;
 
 impl elliptic_curve::Curve for NistP521 {
-    /// 521-bit integer type used for internally representing field elements.
-    type UInt = U576;
+    /// 528-bit integer type used for internally representing field elements.
+    ///
+    /// P-521 field elements are serialized as 66 bytes, which is not a whole
+    /// number of limbs, so [`U528`] is used rather than [`U576`].
+    type UInt = U528;
 
     /// Order of NIST P-521's elliptic curve group (i.e. scalar modulus).
-    const ORDER: U576 = U576::from_be_hex("00000000000001fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409");
+    const ORDER: U528 = U528::from_be_hex("01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409");
 }
 
 impl elliptic_curve::PrimeCurve for NistP521 {}
@@ -63,5 +90,21 @@ pub type FieldBytes = elliptic_curve::FieldBytes<NistP521>;
 /// NIST P-521 SEC1 encoded point.
 pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<NistP521>;
 
+/// Non-zero NIST P-521 scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<NistP521>;
+
+/// NIST P-521 public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<NistP521>;
+
 /// NIST P-521 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<NistP521>;
+
+#[cfg(not(feature = "arithmetic"))]
+impl elliptic_curve::sec1::ValidatePublicKey for NistP521 {}
+
+/// Bit representation of a NIST P-521 scalar field element.
+#[cfg(feature = "bits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
+pub type ScalarBits = elliptic_curve::ScalarBits<NistP521>;
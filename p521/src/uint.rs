@@ -0,0 +1,477 @@
+//! 528-bit unsigned integer type.
+//!
+//! NIST P-521 field elements and scalars are serialized as 66-byte
+//! (528-bit) big endian integers, which is not a whole number of limbs on
+//! either 32-bit or 64-bit platforms. This module provides a thin wrapper
+//! around [`U576`] whose serialized form is exactly 66 bytes, so it can be
+//! used as [`Curve::UInt`][`elliptic_curve::Curve::UInt`] for [`NistP521`].
+//!
+//! [`NistP521`]: crate::NistP521
+
+use core::ops::{BitAnd, BitOr, BitXor, Div, Not, Rem, Shl, Shr};
+use elliptic_curve::{
+    bigint::{
+        AddMod, ArrayEncoding, ByteArray, CheckedAdd, CheckedMul, CheckedSub, Encoding, Integer,
+        Limb, NegMod, NonZero, Random, RandomMod, SubMod, Word, Zero, U576,
+    },
+    consts::U66,
+    rand_core::{CryptoRng, RngCore},
+    subtle::{
+        Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+        CtOption,
+    },
+    zeroize::Zeroize,
+};
+
+/// Number of limbs in the inner [`U576`].
+const LIMBS: usize = U576::LIMBS;
+
+/// Number of bytes in the serialized representation.
+const BYTES: usize = 66;
+
+/// Number of hex digits which fit in a single limb.
+const LIMB_HEX_DIGITS: usize = Limb::BIT_SIZE / 4;
+
+/// 528-bit unsigned big integer.
+///
+/// Values are always less than `2^528`, which allows them to be losslessly
+/// serialized as 66 bytes, the size of a NIST P-521 field element.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct U528(U576);
+
+impl U528 {
+    /// The value `0`.
+    pub const ZERO: Self = Self(U576::ZERO);
+
+    /// The value `1`.
+    pub const ONE: Self = Self(U576::ONE);
+
+    /// Maximum value this integer can express: `2^528 - 1`.
+    pub const MAX: Self = Self(U576::MAX.shr_vartime(U576::BIT_SIZE - Self::BIT_SIZE));
+
+    /// Size of this integer in bits.
+    pub const BIT_SIZE: usize = BYTES * 8;
+
+    /// Size of this integer in bytes.
+    pub const BYTE_SIZE: usize = BYTES;
+
+    /// Create a new [`U528`] from the provided [`U576`], returning `None` if
+    /// it exceeds `2^528 - 1`.
+    pub fn new(uint: U576) -> CtOption<Self> {
+        let is_too_large = uint.ct_gt(&Self::MAX.0);
+        CtOption::new(Self(uint), !is_too_large)
+    }
+
+    /// Create a new [`U528`] from a `u64`.
+    pub const fn from_u64(n: u64) -> Self {
+        Self(U576::from_u64(n))
+    }
+
+    /// Create a new [`U528`] from an array of limb-sized words.
+    ///
+    /// Bits above the 528th are discarded.
+    pub const fn from_words(words: [Word; LIMBS]) -> Self {
+        Self(U576::from_words(words).wrapping_and(&Self::MAX.0))
+    }
+
+    /// Borrow the inner limbs as an array of [`Word`]s.
+    pub const fn as_words(&self) -> &[Word; LIMBS] {
+        self.0.as_words()
+    }
+
+    /// Create an array of [`Word`]s from this [`U528`].
+    pub const fn to_words(self) -> [Word; LIMBS] {
+        self.0.to_words()
+    }
+
+    /// Convert this [`U528`] into a [`U576`].
+    pub const fn to_uint(self) -> U576 {
+        self.0
+    }
+
+    /// Create a new [`U528`] from the provided big endian hex string.
+    ///
+    /// Panics if the string is not exactly 132 hex digits.
+    pub const fn from_be_hex(hex: &str) -> Self {
+        let bytes = hex.as_bytes();
+        assert!(
+            bytes.len() == BYTES * 2,
+            "hex string is not the expected size"
+        );
+
+        let mut words = [0 as Word; LIMBS];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let digit = decode_hex_digit(bytes[bytes.len() - i - 1]);
+            words[i / LIMB_HEX_DIGITS] |= (digit as Word) << ((i % LIMB_HEX_DIGITS) * 4);
+            i += 1;
+        }
+
+        Self(U576::from_words(words))
+    }
+
+    /// Serialize this [`U528`] as little endian bytes.
+    pub const fn to_le_bytes(&self) -> [u8; BYTES] {
+        let words = self.0.as_words();
+        let mut bytes = [0u8; BYTES];
+        let mut i = 0;
+
+        while i < BYTES {
+            let word = words[i / Limb::BYTE_SIZE];
+            bytes[i] = (word >> ((i % Limb::BYTE_SIZE) * 8)) as u8;
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Serialize this [`U528`] as big endian bytes.
+    pub const fn to_be_bytes(&self) -> [u8; BYTES] {
+        let le_bytes = self.to_le_bytes();
+        let mut bytes = [0u8; BYTES];
+        let mut i = 0;
+
+        while i < BYTES {
+            bytes[i] = le_bytes[BYTES - i - 1];
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Decode a [`U528`] from little endian bytes.
+    pub const fn from_le_bytes(bytes: [u8; BYTES]) -> Self {
+        let mut words = [0 as Word; LIMBS];
+        let mut i = 0;
+
+        while i < BYTES {
+            words[i / Limb::BYTE_SIZE] |= (bytes[i] as Word) << ((i % Limb::BYTE_SIZE) * 8);
+            i += 1;
+        }
+
+        Self(U576::from_words(words))
+    }
+
+    /// Decode a [`U528`] from big endian bytes.
+    pub const fn from_be_bytes(bytes: [u8; BYTES]) -> Self {
+        let mut le_bytes = [0u8; BYTES];
+        let mut i = 0;
+
+        while i < BYTES {
+            le_bytes[i] = bytes[BYTES - i - 1];
+            i += 1;
+        }
+
+        Self::from_le_bytes(le_bytes)
+    }
+
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    pub const fn shr_vartime(&self, shift: usize) -> Self {
+        Self(self.0.shr_vartime(shift))
+    }
+
+    /// Computes `self % modulus`, returning `None` if `modulus` is zero.
+    ///
+    /// This is variable time with respect to `modulus` *ONLY*.
+    pub fn reduce(&self, modulus: &Self) -> CtOption<Self> {
+        self.0.reduce(&modulus.0).map(Self)
+    }
+}
+
+/// Decode a single hex digit in a `const fn`.
+const fn decode_hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex digit"),
+    }
+}
+
+impl AsRef<[Limb]> for U528 {
+    fn as_ref(&self) -> &[Limb] {
+        self.0.as_ref()
+    }
+}
+
+impl BitAnd for U528 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0.bitand(&rhs.0))
+    }
+}
+
+impl BitOr for U528 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0.bitor(&rhs.0))
+    }
+}
+
+impl BitXor for U528 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0.bitxor(&rhs.0))
+    }
+}
+
+impl Not for U528 {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(self.0.not().bitand(&Self::MAX.0))
+    }
+}
+
+impl Shl<usize> for U528 {
+    type Output = Self;
+
+    fn shl(self, rhs: usize) -> Self {
+        Self((self.0 << rhs).bitand(&Self::MAX.0))
+    }
+}
+
+impl Shr<usize> for U528 {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self {
+        Self(self.0 >> rhs)
+    }
+}
+
+impl CheckedAdd<&U528> for U528 {
+    type Output = Self;
+
+    fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_add(&rhs.0).and_then(Self::new)
+    }
+}
+
+impl CheckedSub<&U528> for U528 {
+    type Output = Self;
+
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_sub(&rhs.0).map(Self)
+    }
+}
+
+impl CheckedMul<&U528> for U528 {
+    type Output = Self;
+
+    fn checked_mul(&self, rhs: &Self) -> CtOption<Self> {
+        self.0.checked_mul(&rhs.0).and_then(Self::new)
+    }
+}
+
+impl Div<NonZero<U528>> for U528 {
+    type Output = Self;
+
+    fn div(self, rhs: NonZero<U528>) -> Self {
+        let rhs: &Self = &rhs;
+        Self(self.0.wrapping_div(&rhs.0))
+    }
+}
+
+impl Rem<NonZero<U528>> for U528 {
+    type Output = Self;
+
+    fn rem(self, rhs: NonZero<U528>) -> Self {
+        let rhs: &Self = &rhs;
+        Self(self.0.wrapping_rem(&rhs.0))
+    }
+}
+
+impl ConditionallySelectable for U528 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(U576::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for U528 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeGreater for U528 {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.0.ct_gt(&other.0)
+    }
+}
+
+impl ConstantTimeLess for U528 {
+    fn ct_lt(&self, other: &Self) -> Choice {
+        self.0.ct_lt(&other.0)
+    }
+}
+
+impl From<u64> for U528 {
+    fn from(n: u64) -> Self {
+        Self::from_u64(n)
+    }
+}
+
+impl From<U528> for U576 {
+    fn from(n: U528) -> U576 {
+        n.0
+    }
+}
+
+impl Zero for U528 {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl Integer for U528 {
+    const ONE: Self = Self::ONE;
+    const MAX: Self = Self::MAX;
+
+    fn is_odd(&self) -> Choice {
+        self.0.is_odd()
+    }
+}
+
+impl Encoding for U528 {
+    const BIT_SIZE: usize = Self::BIT_SIZE;
+    const BYTE_SIZE: usize = Self::BYTE_SIZE;
+
+    type Repr = [u8; BYTES];
+
+    fn from_be_bytes(bytes: Self::Repr) -> Self {
+        Self::from_be_bytes(bytes)
+    }
+
+    fn from_le_bytes(bytes: Self::Repr) -> Self {
+        Self::from_le_bytes(bytes)
+    }
+
+    fn to_be_bytes(&self) -> Self::Repr {
+        self.to_be_bytes()
+    }
+
+    fn to_le_bytes(&self) -> Self::Repr {
+        self.to_le_bytes()
+    }
+}
+
+impl ArrayEncoding for U528 {
+    type ByteSize = U66;
+
+    fn from_be_byte_array(bytes: ByteArray<Self>) -> Self {
+        let mut repr = [0u8; BYTES];
+        repr.copy_from_slice(&bytes);
+        Self::from_be_bytes(repr)
+    }
+
+    fn from_le_byte_array(bytes: ByteArray<Self>) -> Self {
+        let mut repr = [0u8; BYTES];
+        repr.copy_from_slice(&bytes);
+        Self::from_le_bytes(repr)
+    }
+
+    fn to_be_byte_array(&self) -> ByteArray<Self> {
+        ByteArray::<Self>::clone_from_slice(&self.to_be_bytes())
+    }
+
+    fn to_le_byte_array(&self) -> ByteArray<Self> {
+        ByteArray::<Self>::clone_from_slice(&self.to_le_bytes())
+    }
+}
+
+impl AddMod for U528 {
+    type Output = Self;
+
+    fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+        Self(self.0.add_mod(&rhs.0, &p.0))
+    }
+}
+
+impl SubMod for U528 {
+    type Output = Self;
+
+    fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+        Self(self.0.sub_mod(&rhs.0, &p.0))
+    }
+}
+
+impl NegMod for U528 {
+    type Output = Self;
+
+    fn neg_mod(&self, p: &Self) -> Self {
+        Self(self.0.neg_mod(&p.0))
+    }
+}
+
+impl Random for U528 {
+    fn random(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut bytes = [0u8; BYTES];
+        rng.fill_bytes(&mut bytes);
+        Self::from_le_bytes(bytes)
+    }
+}
+
+impl RandomMod for U528 {
+    fn random_mod(rng: impl CryptoRng + RngCore, modulus: &NonZero<Self>) -> Self {
+        let modulus = NonZero::new(modulus.0).unwrap();
+        Self(U576::random_mod(rng, &modulus))
+    }
+}
+
+impl Zeroize for U528 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::U528;
+    use elliptic_curve::bigint::{CheckedAdd, Encoding, U576};
+    use hex_literal::hex;
+
+    const HEX: &str = "01ff0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40";
+
+    #[test]
+    fn from_be_hex() {
+        let n = U528::from_be_hex(HEX);
+        assert_eq!(
+            n.to_uint(),
+            U576::from_be_hex(&["000000000000", HEX].concat())
+        );
+    }
+
+    #[test]
+    fn be_bytes_roundtrip() {
+        let bytes = hex!("01ff0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40");
+        let n = U528::from_be_bytes(bytes);
+        assert_eq!(n, U528::from_be_hex(HEX));
+        assert_eq!(n.to_be_bytes(), bytes);
+        assert_eq!(<U528 as Encoding>::to_be_bytes(&n), bytes);
+    }
+
+    #[test]
+    fn le_bytes_roundtrip() {
+        let n = U528::from_be_hex(HEX);
+        let mut bytes = n.to_be_bytes();
+        bytes.reverse();
+        assert_eq!(n.to_le_bytes(), bytes);
+        assert_eq!(U528::from_le_bytes(bytes), n);
+    }
+
+    #[test]
+    fn checked_add_overflow() {
+        assert!(bool::from(U528::MAX.checked_add(&U528::ZERO).is_some()));
+        assert!(bool::from(U528::MAX.checked_add(&U528::ONE).is_none()));
+    }
+
+    #[test]
+    fn shl_truncates() {
+        assert_eq!(U528::ONE << 528, U528::ZERO);
+        assert_eq!(!U528::ZERO, U528::MAX);
+    }
+}
@@ -0,0 +1,408 @@
+//! Modular inversion using the Bernstein-Yang "safegcd" algorithm.
+//!
+//! The implementation follows the approach taken by libsecp256k1's `modinv64`:
+//! values are represented as arrays of `L` signed 62-bit limbs, and divsteps
+//! are applied in batches of 62 using only the low limbs of `f` and `g`,
+//! accumulating a transition matrix which is then applied to the full values.
+//!
+//! Two variants are provided:
+//!
+//! - [`Inverter::invert`] performs the worst-case number of divsteps for the
+//!   modulus and executes in constant time with respect to its input.
+//! - [`Inverter::invert_vartime`] stops as soon as the GCD has been found and
+//!   skips over runs of zero bits, which makes it considerably faster, but it
+//!   must only be used with public inputs, e.g. during signature verification.
+//!
+//! Inputs and outputs are little endian arrays of limbs which must be fully
+//! reduced, i.e. less than the modulus `p`, which must be odd. Inverting zero
+//! returns zero.
+//!
+//! See "Fast constant-time gcd computation and modular inversion" by
+//! Daniel J. Bernstein and Bo-Yin Yang: <https://eprint.iacr.org/2019/266>
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+pub use crypto_bigint::Word;
+
+/// Mask for the low 62 bits of a limb.
+const M62: u64 = u64::MAX >> 2;
+
+/// Modular inverter for a fixed modulus.
+///
+/// `LIMBS` is the number of [`Word`]s in the modulus and `L` the number of
+/// signed 62-bit limbs used internally, which must be `bits(p) / 62 + 1`.
+#[derive(Clone, Copy, Debug)]
+pub struct Inverter<const LIMBS: usize, const L: usize> {
+    /// Modulus in signed 62-bit limbs.
+    modulus: [i64; L],
+
+    /// `p^{-1} mod 2^62`.
+    modulus_inv62: u64,
+
+    /// Number of batches of 62 divsteps needed in the worst case.
+    iterations: usize,
+}
+
+/// Transition matrix for a batch of 62 divsteps, scaled by `2^62`.
+#[derive(Clone, Copy)]
+struct Matrix {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+impl<const LIMBS: usize, const L: usize> Inverter<LIMBS, L> {
+    /// Create a new inverter for the given odd modulus.
+    pub const fn new(p: &[Word; LIMBS]) -> Self {
+        let bits = bits_vartime(p);
+        assert!(p[0] & 1 == 1, "modulus must be odd");
+        assert!(bits / 62 + 1 == L, "wrong number of signed limbs");
+
+        // Newton's iteration doubles the number of correct low bits every
+        // round, starting with 3 since `p * p = 1 mod 8` for odd `p`.
+        let modulus = to_signed62::<LIMBS, L>(p);
+        let p0 = modulus[0] as u64;
+        let mut inv = p0;
+        let mut i = 0;
+
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p0.wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // Worst case bound on the number of divsteps from Theorem 11.2 of the
+        // Bernstein-Yang paper, rounded up to a whole number of batches.
+        let divsteps = (49 * bits + 57) / 17;
+
+        Self {
+            modulus,
+            modulus_inv62: inv & M62,
+            iterations: (divsteps + 61) / 62,
+        }
+    }
+
+    /// Compute `x^{-1} mod p` in constant time.
+    pub fn invert(&self, x: &[Word; LIMBS]) -> [Word; LIMBS] {
+        let mut d = [0; L];
+        let mut e = [0; L];
+        let mut f = self.modulus;
+        let mut g = to_signed62::<LIMBS, L>(x);
+        let mut delta = 1;
+        e[0] = 1;
+
+        for _ in 0..self.iterations {
+            let (new_delta, t) = divsteps(delta, f[0] as u64, g[0] as u64);
+            delta = new_delta;
+            self.update_de(&mut d, &mut e, &t);
+            update_fg(&mut f, &mut g, &t);
+        }
+
+        // `f` is now `±1`, or `p` if `x` was zero
+        self.normalize(&mut d, f[L - 1] >> 63);
+        from_signed62(&d)
+    }
+
+    /// Compute `x^{-1} mod p` in variable time.
+    pub fn invert_vartime(&self, x: &[Word; LIMBS]) -> [Word; LIMBS] {
+        let mut d = [0; L];
+        let mut e = [0; L];
+        let mut f = self.modulus;
+        let mut g = to_signed62::<LIMBS, L>(x);
+        let mut delta = 1;
+        e[0] = 1;
+
+        while g.iter().any(|&limb| limb != 0) {
+            let (new_delta, t) = divsteps_vartime(delta, f[0] as u64, g[0] as u64);
+            delta = new_delta;
+            self.update_de(&mut d, &mut e, &t);
+            update_fg(&mut f, &mut g, &t);
+        }
+
+        self.normalize(&mut d, f[L - 1] >> 63);
+        from_signed62(&d)
+    }
+
+    /// Compute `(t * [d, e]) / 2^62 mod p`, keeping `d` and `e` in the range
+    /// `(-2p, p)`.
+    fn update_de(&self, d: &mut [i64; L], e: &mut [i64; L], t: &Matrix) {
+        let Matrix { u, v, q, r } = *t;
+        let p = &self.modulus;
+
+        // Start with `[md, me] = t * [d < 0, e < 0]`, which keeps the result
+        // in range, then correct them so that `t * [d, e] + p * [md, me]` has
+        // 62 zero low bits.
+        let sd = d[L - 1] >> 63;
+        let se = e[L - 1] >> 63;
+        let mut md = (u & sd) + (v & se);
+        let mut me = (q & sd) + (r & se);
+
+        let mut cd = i128::from(u) * i128::from(d[0]) + i128::from(v) * i128::from(e[0]);
+        let mut ce = i128::from(q) * i128::from(d[0]) + i128::from(r) * i128::from(e[0]);
+
+        md -= (self
+            .modulus_inv62
+            .wrapping_mul(cd as u64)
+            .wrapping_add(md as u64)
+            & M62) as i64;
+        me -= (self
+            .modulus_inv62
+            .wrapping_mul(ce as u64)
+            .wrapping_add(me as u64)
+            & M62) as i64;
+
+        cd += i128::from(p[0]) * i128::from(md);
+        ce += i128::from(p[0]) * i128::from(me);
+        debug_assert_eq!(cd as u64 & M62, 0);
+        debug_assert_eq!(ce as u64 & M62, 0);
+        cd >>= 62;
+        ce >>= 62;
+
+        for i in 1..L {
+            cd += i128::from(u) * i128::from(d[i])
+                + i128::from(v) * i128::from(e[i])
+                + i128::from(p[i]) * i128::from(md);
+            ce += i128::from(q) * i128::from(d[i])
+                + i128::from(r) * i128::from(e[i])
+                + i128::from(p[i]) * i128::from(me);
+            d[i - 1] = (cd as u64 & M62) as i64;
+            e[i - 1] = (ce as u64 & M62) as i64;
+            cd >>= 62;
+            ce >>= 62;
+        }
+
+        d[L - 1] = cd as i64;
+        e[L - 1] = ce as i64;
+    }
+
+    /// Bring `r` in the range `(-2p, p)` into `[0, p)`, negating it if `sign`
+    /// is all ones.
+    fn normalize(&self, r: &mut [i64; L], sign: i64) {
+        let p = &self.modulus;
+
+        let cond_add = r[L - 1] >> 63;
+        for (r, p) in r.iter_mut().zip(p.iter()) {
+            *r += p & cond_add;
+            *r = (*r ^ sign) - sign;
+        }
+        propagate_carries(r);
+
+        let cond_add = r[L - 1] >> 63;
+        for (r, p) in r.iter_mut().zip(p.iter()) {
+            *r += p & cond_add;
+        }
+        propagate_carries(r);
+    }
+}
+
+/// Perform 62 divsteps in constant time, using the low 62 bits of `f` and `g`.
+///
+/// Returns the new `delta` and the transition matrix `t` such that
+/// `2^62 * [f', g'] = t * [f, g]`.
+fn divsteps(mut delta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+    let (mut f, mut g) = (f0 as i64, g0 as i64);
+
+    for _ in 0..62 {
+        // If `delta > 0` and `g` is odd, replace
+        // `(delta, f, g, u, v, q, r)` with `(-delta, g, -f, q, r, -u, -v)`
+        let g_odd = -(g & 1);
+        let swap = (delta.wrapping_neg() >> 63) & g_odd;
+
+        delta = (delta ^ swap) - swap;
+        let x = (f ^ g) & swap;
+        f ^= x;
+        g ^= x;
+        g = (g ^ swap).wrapping_sub(swap);
+        let x = (u ^ q) & swap;
+        u ^= x;
+        q ^= x;
+        q = (q ^ swap).wrapping_sub(swap);
+        let x = (v ^ r) & swap;
+        v ^= x;
+        r ^= x;
+        r = (r ^ swap).wrapping_sub(swap);
+
+        // If `g` is odd, add `f` to it, making it even
+        g = g.wrapping_add(f & g_odd);
+        q = q.wrapping_add(u & g_odd);
+        r = r.wrapping_add(v & g_odd);
+
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+        delta += 1;
+    }
+
+    (delta, Matrix { u, v, q, r })
+}
+
+/// Perform 62 divsteps in variable time, using the low 62 bits of `f` and `g`.
+///
+/// Same as [`divsteps`], but handles runs of zero bits in `g` at once.
+fn divsteps_vartime(mut delta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+    let (mut f, mut g) = (f0, g0);
+    let mut i = 62;
+
+    loop {
+        let zeros = g.trailing_zeros().min(i);
+        g >>= zeros;
+        u <<= zeros;
+        v <<= zeros;
+        delta += i64::from(zeros);
+        i -= zeros;
+
+        if i == 0 {
+            break;
+        }
+
+        // `g` is odd here
+        if delta > 0 {
+            delta = -delta;
+            let (f_old, u_old, v_old) = (f, u, v);
+            f = g;
+            g = f_old.wrapping_neg();
+            u = q;
+            v = r;
+            q = -u_old;
+            r = -v_old;
+        }
+
+        g = g.wrapping_add(f);
+        q += u;
+        r += v;
+    }
+
+    (delta, Matrix { u, v, q, r })
+}
+
+/// Compute `(t * [f, g]) / 2^62`, which is exact by construction of `t`.
+fn update_fg<const L: usize>(f: &mut [i64; L], g: &mut [i64; L], t: &Matrix) {
+    let Matrix { u, v, q, r } = *t;
+
+    let mut cf = i128::from(u) * i128::from(f[0]) + i128::from(v) * i128::from(g[0]);
+    let mut cg = i128::from(q) * i128::from(f[0]) + i128::from(r) * i128::from(g[0]);
+    debug_assert_eq!(cf as u64 & M62, 0);
+    debug_assert_eq!(cg as u64 & M62, 0);
+    cf >>= 62;
+    cg >>= 62;
+
+    for i in 1..L {
+        cf += i128::from(u) * i128::from(f[i]) + i128::from(v) * i128::from(g[i]);
+        cg += i128::from(q) * i128::from(f[i]) + i128::from(r) * i128::from(g[i]);
+        f[i - 1] = (cf as u64 & M62) as i64;
+        g[i - 1] = (cg as u64 & M62) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+
+    f[L - 1] = cf as i64;
+    g[L - 1] = cg as i64;
+}
+
+/// Reduce all but the top limb to 62 bits.
+fn propagate_carries<const L: usize>(r: &mut [i64; L]) {
+    for i in 0..(L - 1) {
+        r[i + 1] += r[i] >> 62;
+        r[i] &= M62 as i64;
+    }
+}
+
+/// Number of significant bits in `p`.
+const fn bits_vartime<const LIMBS: usize>(p: &[Word; LIMBS]) -> usize {
+    let mut i = LIMBS;
+
+    while i > 0 && p[i - 1] == 0 {
+        i -= 1;
+    }
+
+    if i == 0 {
+        0
+    } else {
+        i * Word::BITS as usize - p[i - 1].leading_zeros() as usize
+    }
+}
+
+/// Convert a little endian array of limbs into signed 62-bit limbs.
+const fn to_signed62<const LIMBS: usize, const L: usize>(x: &[Word; LIMBS]) -> [i64; L] {
+    let mut ret = [0; L];
+    let mut acc: u128 = 0;
+    let mut acc_bits = 0;
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < LIMBS {
+        acc |= (x[i] as u128) << acc_bits;
+        acc_bits += Word::BITS;
+        i += 1;
+
+        while acc_bits >= 62 && j < L - 1 {
+            ret[j] = (acc as u64 & M62) as i64;
+            acc >>= 62;
+            acc_bits -= 62;
+            j += 1;
+        }
+    }
+
+    ret[j] = acc as i64;
+    ret
+}
+
+/// Convert normalized signed 62-bit limbs into a little endian array of limbs.
+fn from_signed62<const LIMBS: usize, const L: usize>(x: &[i64; L]) -> [Word; LIMBS] {
+    let mut ret = [0; LIMBS];
+    let mut acc: u128 = 0;
+    let mut acc_bits = 0;
+    let mut j = 0;
+
+    for &limb in x.iter() {
+        acc |= (limb as u128) << acc_bits;
+        acc_bits += 62;
+
+        while acc_bits >= Word::BITS && j < LIMBS {
+            ret[j] = acc as Word;
+            acc >>= Word::BITS;
+            acc_bits -= Word::BITS;
+            j += 1;
+        }
+    }
+
+    if j < LIMBS {
+        ret[j] = acc as Word;
+    }
+
+    ret
+}
+
+#[cfg(all(test, target_pointer_width = "64"))]
+mod tests {
+    use super::Inverter;
+
+    /// Largest prime below `2^63`, which needs two signed 62-bit limbs.
+    const P: u64 = (1 << 63) - 25;
+
+    const INVERTER: Inverter<1, 2> = Inverter::new(&[P]);
+
+    #[test]
+    fn invert() {
+        for x in [1, 2, 3, 0xdead_beef, P >> 1, P - 1] {
+            let inv = INVERTER.invert(&[x])[0];
+            assert_eq!(x as u128 * inv as u128 % P as u128, 1);
+            assert_eq!(INVERTER.invert_vartime(&[x]), [inv]);
+        }
+    }
+
+    #[test]
+    fn invert_zero() {
+        assert_eq!(INVERTER.invert(&[0]), [0]);
+        assert_eq!(INVERTER.invert_vartime(&[0]), [0]);
+    }
+}
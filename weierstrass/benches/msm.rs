@@ -0,0 +1,55 @@
+//! Generic multi-scalar multiplication benchmarks
+
+use criterion::{
+    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, BenchmarkId,
+    Criterion,
+};
+
+mod p256 {
+    weierstrass::define_curve! {
+        /// NIST P-256 elliptic curve.
+        pub struct NistP256 {
+            uint: weierstrass::elliptic_curve::bigint::U256,
+            p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+            b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            field_generator: 6,
+            scalar_generator: 7,
+        }
+    }
+}
+
+use self::p256::{ProjectivePoint, Scalar};
+
+fn bench_multiscalar_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let mut p = ProjectivePoint::GENERATOR;
+    let mut s = Scalar::from(0x5eed_u64).invert().unwrap();
+    let pairs = (0..512)
+        .map(|_| {
+            p = p.double();
+            s = s.square() + Scalar::ONE;
+            (p, s)
+        })
+        .collect::<Vec<_>>();
+
+    // Lengths around the switch from Straus to Pippenger
+    for n in [1, 4, 16, 64, 128, 192, 256, 320, 384, 448, 512] {
+        group.bench_with_input(
+            BenchmarkId::new("multiscalar_mul_vartime", n),
+            &pairs[..n],
+            |b, pairs| b.iter(|| ProjectivePoint::multiscalar_mul_vartime(pairs)),
+        );
+    }
+}
+
+fn bench_msm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi-scalar multiplication");
+    bench_multiscalar_mul(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_msm);
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+//! Random blinding support for scalars.
+
+use core::borrow::Borrow;
+use elliptic_curve::{
+    ff::PrimeField,
+    ops::Invert,
+    rand_core::{CryptoRng, RngCore},
+    subtle::CtOption,
+    zeroize::Zeroize,
+};
+
+/// Scalar blinded with a randomly generated masking value.
+///
+/// This provides a randomly blinded impl of [`Invert`] which is useful for
+/// ECDSA ephemeral (`k`) scalars: the inversion is computed on `k * mask`,
+/// so a side channel leaking information about its input only observes a
+/// value which is uncorrelated with `k`.
+#[derive(Clone)]
+pub struct BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    /// Actual scalar value
+    scalar: S,
+
+    /// Mask value
+    mask: S,
+}
+
+impl<S> BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    /// Create a new [`BlindedScalar`] from a scalar and a [`CryptoRng`]
+    pub fn new(scalar: S, rng: impl CryptoRng + RngCore) -> Self {
+        Self::with_mask(scalar, S::random(rng))
+    }
+
+    /// Create a new [`BlindedScalar`] from a scalar and the provided mask.
+    ///
+    /// The mask must be non-zero, uniformly distributed and secret, e.g. the
+    /// HMAC_DRBG output following an RFC6979 nonce when no RNG is available.
+    pub fn with_mask(scalar: S, mask: S) -> Self {
+        Self { scalar, mask }
+    }
+}
+
+impl<S> Borrow<S> for BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    fn borrow(&self) -> &S {
+        &self.scalar
+    }
+}
+
+impl<S> Invert for BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    type Output = CtOption<S>;
+
+    fn invert(&self) -> CtOption<S> {
+        // prevent side channel analysis of scalar inversion by pre-and-post-multiplying
+        // with the random masking scalar
+        (self.scalar * self.mask).invert().map(|s| s * self.mask)
+    }
+}
+
+impl<S> Zeroize for BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+        self.mask.zeroize();
+    }
+}
+
+impl<S> Drop for BlindedScalar<S>
+where
+    S: PrimeField + Zeroize,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlindedScalar;
+    use crate::dev::secp256k1::Scalar;
+    use elliptic_curve::{ff::Field, ops::Invert};
+    use rand_core::OsRng;
+
+    #[test]
+    fn invert() {
+        for _ in 0..16 {
+            let k = Scalar::random(&mut OsRng);
+            let k_inv = k.invert().unwrap();
+            assert_eq!(BlindedScalar::new(k, &mut OsRng).invert().unwrap(), k_inv);
+
+            let mask = Scalar::random(&mut OsRng);
+            assert_eq!(BlindedScalar::with_mask(k, mask).invert().unwrap(), k_inv);
+        }
+
+        let zero = BlindedScalar::new(Scalar::zero(), &mut OsRng);
+        assert!(bool::from(zero.invert().is_none()));
+    }
+}
@@ -0,0 +1,713 @@
+//! Macros for defining new curves from their domain parameters.
+
+/// Define a new short Weierstrass curve from its domain parameters.
+///
+/// All parameters are given as big endian hex strings whose length matches
+/// the size of `uint`, except for `field_generator` and `scalar_generator`
+/// which are `u64` multiplicative generators of the base and scalar fields
+/// (see [`PrimeField::multiplicative_generator`]).
+///
+/// The following items are generated in the current module, which should be
+/// dedicated to the curve:
+///
+/// - The curve type itself, with impls of [`Curve`], [`PrimeCurve`],
+///   [`WeierstrassCurve`] and the [`AffineArithmetic`],
+///   [`ProjectiveArithmetic`], [`PrimeCurveArithmetic`] and
+///   [`ScalarArithmetic`] traits. If an `oid` is given, [`AssociatedOid`] is
+///   impl'd as well, which requires the `pkcs8` feature.
+/// - `FieldElement`: element of the base field.
+/// - `Scalar`: element of the scalar field.
+/// - `AffinePoint` and `ProjectivePoint`.
+/// - `FieldBytes`, `PublicKey` and `SecretKey`.
+///
+/// Field elements and scalars are implemented using the generic
+/// [`montgomery`](crate::montgomery) backend. Curves with `a = -3` or `a = 0`
+/// automatically use the corresponding specialized addition formulas.
+///
+/// The byte length of `p` must match the size of `uint`, since
+/// `elliptic-curve` sizes [`FieldBytes`] and SEC1 [`EncodedPoint`]s by
+/// [`Curve::UInt`], and this is checked at compile time. Curves with a
+/// modulus narrower than a whole number of limbs, e.g. P-224 on 64-bit
+/// platforms, should use a type defined by [`define_uint!`]:
+///
+/// ```
+/// mod p224 {
+///     use weierstrass::elliptic_curve::{bigint::U256, consts::U28};
+///
+///     weierstrass::define_uint! {
+///         /// 224-bit unsigned integer.
+///         pub struct U224(U256, U28);
+///     }
+///
+///     weierstrass::define_curve! {
+///         /// NIST P-224 elliptic curve.
+///         pub struct NistP224 {
+///             uint: U224,
+///             p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+///             n: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+///             a: "fffffffffffffffffffffffffffffffefffffffffffffffffffffffe",
+///             b: "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+///             gx: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+///             gy: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+///             field_generator: 22,
+///             scalar_generator: 2,
+///         }
+///     }
+/// }
+///
+/// use p224::{FieldBytes, ProjectivePoint};
+/// use weierstrass::elliptic_curve::sec1::ToEncodedPoint;
+///
+/// assert_eq!(FieldBytes::default().len(), 28);
+/// assert_eq!(ProjectivePoint::GENERATOR.to_affine().to_encoded_point(true).len(), 29);
+/// ```
+///
+/// Other traits, e.g. `PointCompression`, `JwkParameters` or the ECDSA
+/// primitives, can be impl'd on the generated types as usual. Private `field`
+/// and `scalar` modules are also defined.
+///
+/// # Example
+///
+/// ```
+/// mod p256 {
+///     weierstrass::define_curve! {
+///         /// NIST P-256 elliptic curve.
+///         pub struct NistP256 {
+///             uint: weierstrass::elliptic_curve::bigint::U256,
+///             p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+///             n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+///             a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+///             b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+///             gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+///             gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+///             field_generator: 6,
+///             scalar_generator: 7,
+///         }
+///     }
+/// }
+///
+/// use p256::{NistP256, ProjectivePoint, Scalar};
+/// use weierstrass::{EquationAShape, WeierstrassCurve};
+///
+/// assert_eq!(NistP256::EQUATION_A_SHAPE, EquationAShape::MinusThree);
+///
+/// let two = Scalar::from(2);
+/// assert_eq!(ProjectivePoint::GENERATOR * two, ProjectivePoint::GENERATOR.double());
+/// assert_eq!(two * two.invert().unwrap(), Scalar::ONE);
+/// assert_eq!(two.square().sqrt().unwrap().square(), two.square());
+/// ```
+///
+/// [`PrimeField::multiplicative_generator`]: elliptic_curve::ff::PrimeField::multiplicative_generator
+/// [`FieldBytes`]: elliptic_curve::FieldBytes
+/// [`EncodedPoint`]: elliptic_curve::sec1::EncodedPoint
+/// [`Curve::UInt`]: elliptic_curve::Curve::UInt
+/// [`define_uint!`]: crate::define_uint
+/// [`Curve`]: elliptic_curve::Curve
+/// [`PrimeCurve`]: elliptic_curve::PrimeCurve
+/// [`WeierstrassCurve`]: crate::WeierstrassCurve
+/// [`AffineArithmetic`]: elliptic_curve::AffineArithmetic
+/// [`ProjectiveArithmetic`]: elliptic_curve::ProjectiveArithmetic
+/// [`PrimeCurveArithmetic`]: elliptic_curve::PrimeCurveArithmetic
+/// [`ScalarArithmetic`]: elliptic_curve::ScalarArithmetic
+/// [`AssociatedOid`]: https://docs.rs/const-oid/latest/const_oid/trait.AssociatedOid.html
+#[macro_export]
+macro_rules! define_curve {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $curve:ident {
+            uint: $uint:ty,
+            p: $p:expr,
+            n: $n:expr,
+            a: $a:expr,
+            b: $b:expr,
+            gx: $gx:expr,
+            gy: $gy:expr,
+            field_generator: $field_generator:expr,
+            scalar_generator: $scalar_generator:expr
+            $(, oid: $oid:expr)?
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+        $vis struct $curve;
+
+        const _: () = assert!(
+            (<$uint>::from_be_hex($p).bits_vartime() + 7) / 8
+                == <$uint as $crate::elliptic_curve::bigint::Encoding>::BYTE_SIZE,
+            "the byte length of `p` must match the size of `uint`"
+        );
+
+        $vis use self::{field::FieldElement, scalar::Scalar};
+
+        /// Field element serialized as bytes.
+        $vis type FieldBytes = $crate::elliptic_curve::FieldBytes<$curve>;
+
+        /// Point in affine coordinates.
+        $vis type AffinePoint = $crate::AffinePoint<$curve>;
+
+        /// Point in projective coordinates.
+        $vis type ProjectivePoint = $crate::ProjectivePoint<$curve>;
+
+        /// Public key.
+        $vis type PublicKey = $crate::elliptic_curve::PublicKey<$curve>;
+
+        /// Secret key.
+        $vis type SecretKey = $crate::elliptic_curve::SecretKey<$curve>;
+
+        impl $crate::elliptic_curve::Curve for $curve {
+            type UInt = $uint;
+
+            const ORDER: $uint = <$uint>::from_be_hex($n);
+        }
+
+        impl $crate::elliptic_curve::PrimeCurve for $curve {}
+
+        impl $crate::WeierstrassCurve for $curve {
+            type FieldElement = FieldElement;
+
+            const ZERO: FieldElement = FieldElement::ZERO;
+            const ONE: FieldElement = FieldElement::ONE;
+            const EQUATION_A: FieldElement = FieldElement::from_be_hex($a);
+            const EQUATION_A_SHAPE: $crate::EquationAShape =
+                $crate::EquationAShape::from_coefficient(
+                    <$uint>::from_be_hex($a).as_words(),
+                    <$uint>::from_be_hex($p).as_words(),
+                );
+            const EQUATION_B: FieldElement = FieldElement::from_be_hex($b);
+            const GENERATOR: (FieldElement, FieldElement) = (
+                FieldElement::from_be_hex($gx),
+                FieldElement::from_be_hex($gy),
+            );
+        }
+
+        impl $crate::elliptic_curve::AffineArithmetic for $curve {
+            type AffinePoint = AffinePoint;
+        }
+
+        impl $crate::elliptic_curve::ProjectiveArithmetic for $curve {
+            type ProjectivePoint = ProjectivePoint;
+        }
+
+        impl $crate::elliptic_curve::PrimeCurveArithmetic for $curve {
+            type CurveGroup = ProjectivePoint;
+        }
+
+        impl $crate::elliptic_curve::ScalarArithmetic for $curve {
+            type Scalar = Scalar;
+        }
+
+        $(
+            impl $crate::elliptic_curve::pkcs8::AssociatedOid for $curve {
+                const OID: $crate::elliptic_curve::pkcs8::ObjectIdentifier =
+                    $crate::elliptic_curve::pkcs8::ObjectIdentifier::new_unwrap($oid);
+            }
+        )?
+
+        mod field {
+            use super::*;
+
+            $crate::impl_montgomery_field!(
+                /// Element of the base field.
+                ///
+                /// Elements are always in Montgomery form.
+                FieldElement,
+                FieldBytes,
+                $uint,
+                $p,
+                $field_generator
+            );
+        }
+
+        mod scalar {
+            use super::*;
+            use $crate::elliptic_curve::{
+                ops::Reduce, subtle::ConstantTimeGreater, Error, IsHigh, Result, ScalarCore,
+            };
+
+            $crate::impl_montgomery_field!(
+                /// Element of the scalar field, i.e. an integer modulo the
+                /// order of the curve's group.
+                ///
+                /// Elements are always in Montgomery form.
+                Scalar,
+                FieldBytes,
+                $uint,
+                $n,
+                $scalar_generator
+            );
+
+            impl IsHigh for Scalar {
+                fn is_high(&self) -> Choice {
+                    const MODULUS_SHR1: $uint = MODULUS.shr_vartime(1);
+                    self.to_canonical().ct_gt(&MODULUS_SHR1)
+                }
+            }
+
+            impl Reduce<$uint> for Scalar {
+                fn from_uint_reduced(w: $uint) -> Self {
+                    // Montgomery multiplication by `R^2` fully reduces any
+                    // input less than `R`
+                    Self::from_uint_unchecked(w)
+                }
+            }
+
+            impl From<Scalar> for FieldBytes {
+                fn from(scalar: Scalar) -> Self {
+                    scalar.to_repr()
+                }
+            }
+
+            impl From<&Scalar> for FieldBytes {
+                fn from(scalar: &Scalar) -> Self {
+                    scalar.to_repr()
+                }
+            }
+
+            impl From<Scalar> for $uint {
+                fn from(scalar: Scalar) -> $uint {
+                    scalar.to_canonical()
+                }
+            }
+
+            impl From<&Scalar> for $uint {
+                fn from(scalar: &Scalar) -> $uint {
+                    scalar.to_canonical()
+                }
+            }
+
+            impl core::convert::TryFrom<$uint> for Scalar {
+                type Error = Error;
+
+                fn try_from(w: $uint) -> Result<Self> {
+                    Option::from(Self::from_uint(w)).ok_or(Error)
+                }
+            }
+
+            impl From<ScalarCore<$curve>> for Scalar {
+                fn from(w: ScalarCore<$curve>) -> Self {
+                    Scalar::from(&w)
+                }
+            }
+
+            impl From<&ScalarCore<$curve>> for Scalar {
+                fn from(w: &ScalarCore<$curve>) -> Scalar {
+                    Scalar::from_uint_unchecked(*w.as_uint())
+                }
+            }
+
+            impl From<Scalar> for ScalarCore<$curve> {
+                fn from(scalar: Scalar) -> ScalarCore<$curve> {
+                    ScalarCore::from(&scalar)
+                }
+            }
+
+            impl From<&Scalar> for ScalarCore<$curve> {
+                fn from(scalar: &Scalar) -> ScalarCore<$curve> {
+                    ScalarCore::new(scalar.into()).unwrap()
+                }
+            }
+
+            impl From<&SecretKey> for Scalar {
+                fn from(secret_key: &SecretKey) -> Scalar {
+                    *secret_key.to_nonzero_scalar()
+                }
+            }
+        }
+    };
+}
+
+/// Define a prime field type using the generic [`montgomery`] backend.
+///
+/// Used by [`define_curve!`]. Must be invoked in a dedicated module, since it
+/// defines the free functions and constants passed to
+/// [`impl_field_element!`].
+///
+/// [`montgomery`]: crate::montgomery
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_montgomery_field {
+    (
+        $(#[$attr:meta])*
+        $fe:ident,
+        $bytes:ty,
+        $uint:ty,
+        $modulus:expr,
+        $generator:expr
+    ) => {
+        use core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+        use $crate::elliptic_curve::{
+            bigint::Word,
+            ff::PrimeField,
+            subtle::{Choice, ConstantTimeEq, CtOption},
+        };
+
+        /// Modulus of the field.
+        pub(crate) const MODULUS: $uint = <$uint>::from_be_hex($modulus);
+
+        /// R^2 mod p, where R = 2^(w * LIMBS).
+        const R_2: $uint = <$uint>::from_words($crate::montgomery::r2(MODULUS.as_words()));
+
+        /// -p^{-1} mod 2^w, where w is the limb size in bits.
+        const P_INV: Word = $crate::montgomery::neg_inv(MODULUS.as_words());
+
+        /// Largest `S` such that `2^S` divides `p - 1`.
+        const S: u32 = $crate::montgomery::two_adicity(MODULUS.as_words());
+
+        /// Raw field element.
+        type Words = [Word; <$uint>::LIMBS];
+
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $fe(pub(crate) $uint);
+
+        $crate::impl_field_element!(
+            $fe,
+            $bytes,
+            $uint,
+            MODULUS,
+            Words,
+            from_montgomery,
+            to_montgomery,
+            add,
+            sub,
+            mul,
+            neg,
+            square,
+            invert,
+            sqrt
+        );
+
+        impl $fe {
+            /// `2^S` root of unity.
+            pub const ROOT_OF_UNITY: Self =
+                Self::from_uint_unchecked(<$uint>::from_u64($generator))
+                    .pow_fixed(&MODULUS.shr_vartime(S as usize));
+        }
+
+        impl From<u64> for $fe {
+            fn from(n: u64) -> $fe {
+                Self::from_uint_unchecked(<$uint>::from_u64(n))
+            }
+        }
+
+        impl PrimeField for $fe {
+            type Repr = $bytes;
+
+            const NUM_BITS: u32 = MODULUS.bits_vartime() as u32;
+            const CAPACITY: u32 = Self::NUM_BITS - 1;
+            const S: u32 = S;
+
+            fn from_repr(bytes: $bytes) -> CtOption<Self> {
+                Self::from_be_bytes(bytes)
+            }
+
+            fn to_repr(&self) -> $bytes {
+                self.to_be_bytes()
+            }
+
+            fn is_odd(&self) -> Choice {
+                self.is_odd()
+            }
+
+            fn multiplicative_generator() -> Self {
+                Self::from($generator)
+            }
+
+            fn root_of_unity() -> Self {
+                Self::ROOT_OF_UNITY
+            }
+        }
+
+        const fn from_montgomery(w: &Words) -> Words {
+            $crate::montgomery::from_montgomery(w, MODULUS.as_words(), P_INV)
+        }
+
+        const fn to_montgomery(w: &Words) -> Words {
+            $crate::montgomery::to_montgomery(w, R_2.as_words(), MODULUS.as_words(), P_INV)
+        }
+
+        const fn add(a: &Words, b: &Words) -> Words {
+            $crate::montgomery::add(a, b, MODULUS.as_words())
+        }
+
+        const fn sub(a: &Words, b: &Words) -> Words {
+            $crate::montgomery::sub(a, b, MODULUS.as_words())
+        }
+
+        const fn mul(a: &Words, b: &Words) -> Words {
+            $crate::montgomery::mul(a, b, MODULUS.as_words(), P_INV)
+        }
+
+        const fn neg(w: &Words) -> Words {
+            $crate::montgomery::neg(w, MODULUS.as_words())
+        }
+
+        const fn square(w: &Words) -> Words {
+            $crate::montgomery::square(w, MODULUS.as_words(), P_INV)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EquationAShape, WeierstrassCurve};
+    use elliptic_curve::{
+        ff::{Field, PrimeField},
+        group::Curve,
+        sec1::{EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    };
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Test a curve generated by [`define_curve!`].
+    macro_rules! curve_tests {
+        (
+            $module:ident,
+            $curve:ident,
+            field_bytes_size: $size:expr,
+            equation_a_shape: $shape:expr,
+            generator: $generator:expr,
+            mul: ($k:expr, $kg:expr)
+        ) => {
+            mod $module {
+                use super::*;
+                use crate::dev::$module::{
+                    $curve, AffinePoint, FieldBytes, FieldElement, ProjectivePoint, Scalar,
+                };
+
+                #[test]
+                fn parameters() {
+                    assert_eq!(FieldBytes::default().len(), $size);
+                    assert_eq!($curve::EQUATION_A_SHAPE, $shape);
+                    assert_eq!(FieldElement::NUM_BITS as usize, $size * 8);
+                }
+
+                #[test]
+                fn random() {
+                    for _ in 0..100 {
+                        assert!(!bool::from(FieldElement::random(&mut OsRng).is_zero()));
+                        assert!(!bool::from(Scalar::random(&mut OsRng).is_zero()));
+                    }
+                }
+
+                #[test]
+                fn add_double_mul() {
+                    let g = ProjectivePoint::GENERATOR;
+                    let k = Scalar::random(&mut OsRng);
+                    let l = Scalar::random(&mut OsRng);
+
+                    assert_eq!(g + g, g.double());
+                    assert_eq!(g + AffinePoint::GENERATOR, g.double());
+                    assert_eq!(g.double() + g, g * Scalar::from(3));
+                    assert_eq!(g * -Scalar::ONE, -g);
+                    assert_eq!(g - g, ProjectivePoint::IDENTITY);
+                    assert_eq!(g * (k + l), g * k + g * l);
+                    assert_eq!(g * (k * l), (g * k) * l);
+
+                    let expected = EncodedPoint::<$curve>::from_bytes(&$kg).unwrap();
+                    let expected = AffinePoint::from_encoded_point(&expected).unwrap();
+                    let k = Scalar::from_be_slice(&$k).unwrap();
+                    assert_eq!((g * k).to_affine(), expected);
+                }
+
+                #[test]
+                fn batch_invert() {
+                    let three = FieldElement::ONE.double() + FieldElement::ONE;
+                    let mut elements = [three, FieldElement::ZERO, -three];
+                    assert!(!bool::from(FieldElement::batch_invert(&mut elements)));
+                    assert_eq!(
+                        elements,
+                        [
+                            three.invert().unwrap(),
+                            FieldElement::ZERO,
+                            -three.invert().unwrap()
+                        ]
+                    );
+
+                    let mut scalars = [Scalar::from(3), Scalar::ONE];
+                    assert!(bool::from(Scalar::batch_invert(&mut scalars)));
+                    assert_eq!(scalars, [Scalar::from(3).invert().unwrap(), Scalar::ONE]);
+
+                    #[cfg(feature = "alloc")]
+                    {
+                        let mut elements = [three, FieldElement::ZERO, -three];
+                        let mut slice = elements;
+                        FieldElement::batch_invert(&mut elements);
+                        assert!(!bool::from(FieldElement::batch_invert_slice(&mut slice)));
+                        assert_eq!(slice, elements);
+                        assert!(bool::from(FieldElement::batch_invert_slice(&mut [])));
+                    }
+                }
+
+                #[test]
+                fn batch_normalize() {
+                    let g = ProjectivePoint::GENERATOR;
+                    let points = [
+                        g,
+                        ProjectivePoint::IDENTITY,
+                        g.double(),
+                        g.double() + g,
+                        g - g,
+                        g * Scalar::random(&mut OsRng),
+                    ];
+                    let mut affine = [AffinePoint::GENERATOR; 6];
+                    ProjectivePoint::batch_normalize(&points, &mut affine);
+
+                    for (point, affine) in points.iter().zip(affine.iter()) {
+                        assert_eq!(point.to_affine(), *affine);
+                    }
+                }
+
+                #[test]
+                fn sqrt() {
+                    for _ in 0..10 {
+                        let x = FieldElement::random(&mut OsRng);
+                        let sqrt = x.square().sqrt().unwrap();
+                        assert!(sqrt == x || sqrt == -x);
+
+                        let x = Scalar::random(&mut OsRng);
+                        let sqrt = x.square().sqrt().unwrap();
+                        assert!(sqrt == x || sqrt == -x);
+                    }
+
+                    // generators are non-squares
+                    assert!(bool::from(
+                        FieldElement::multiplicative_generator().sqrt().is_none()
+                    ));
+                    assert!(bool::from(
+                        Scalar::multiplicative_generator().sqrt().is_none()
+                    ));
+                }
+
+                #[test]
+                fn sec1_round_trip() {
+                    let g = AffinePoint::GENERATOR;
+                    let p = (ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng)).to_affine();
+                    assert_eq!(g.to_encoded_point(false).as_bytes(), &$generator[..]);
+
+                    for point in [g, p, AffinePoint::IDENTITY] {
+                        for compress in [false, true] {
+                            let encoded_point = point.to_encoded_point(compress);
+                            assert_eq!(
+                                AffinePoint::from_encoded_point(&encoded_point).unwrap(),
+                                point
+                            );
+                        }
+                    }
+
+                    let (x, _) = $generator[1..].split_at($size);
+                    assert_eq!(FieldElement::from_be_slice(x).unwrap(), g.x);
+                    assert_eq!(FieldElement::from_repr(g.x.to_repr()).unwrap(), g.x);
+                }
+            }
+        };
+    }
+
+    curve_tests!(
+        secp256k1,
+        Secp256k1,
+        field_bytes_size: 32,
+        equation_a_shape: EquationAShape::Zero,
+        generator: hex!(
+            "04"
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        ),
+        mul: (
+            hex!("000000000000000000000000000000000000000000000000018ebbb95eed0e13"),
+            hex!(
+                "04"
+                "a90cc3d3f3e146daadfc74ca1372207cb4b725ae708cef713a98edd73d99ef29"
+                "5a79d6b289610c68bc3b47f3d72f9788a26a06868b4d8e433e1e2ad76fb7dc76"
+            )
+        )
+    );
+
+    curve_tests!(
+        p256,
+        NistP256,
+        field_bytes_size: 32,
+        equation_a_shape: EquationAShape::MinusThree,
+        generator: hex!(
+            "04"
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        ),
+        mul: (
+            hex!("000000000000000000000000000000000000000000000000018ebbb95eed0e13"),
+            hex!(
+                "04"
+                "339150844ec15234807fe862a86be77977dbfb3ae3d96f4c22795513aeaab82f"
+                "b1c14ddfdc8ec1b2583f51e85a5eb3a155840f2034730e9b5ada38b674336a21"
+            )
+        )
+    );
+
+    curve_tests!(
+        brainpoolp384r1,
+        BrainpoolP384r1,
+        field_bytes_size: 48,
+        equation_a_shape: EquationAShape::Generic,
+        generator: hex!(
+            "04"
+            "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e"
+            "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315"
+        ),
+        mul: (
+            hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000018ebbb95eed0e13"),
+            hex!(
+                "04"
+                "8615f1af3624b25454cf688b425e3b7d288e3d978ea75f4ae1f5312155d1ceb1e27bad33e6e90ae66add2814d1df7315"
+                "07dc9028c68569d4163acd9f1911add12207b7e3f95da347f460ddd629b09966defe1b779940db93405e56fa2e17062e"
+            )
+        )
+    );
+
+    curve_tests!(
+        p224,
+        NistP224,
+        field_bytes_size: 28,
+        equation_a_shape: EquationAShape::MinusThree,
+        generator: hex!(
+            "04"
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21"
+            "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34"
+        ),
+        mul: (
+            // RFC 6979 A.2.4
+            hex!("f220266e1105bfe3083e03ec7a3a654651f45e37167e88600bf257c1"),
+            hex!(
+                "04"
+                "00cf08da5ad719e42707fa431292dea11244d64fc51610d94b130d6c"
+                "eeab6f3debe455e3dbf85416f7030cbd94f34f2d6f232c69f3c1385a"
+            )
+        )
+    );
+
+    #[test]
+    fn p224_tonelli_shanks() {
+        use crate::dev::p224::FieldElement;
+
+        assert_eq!(FieldElement::S, 96);
+
+        // the root of unity has order exactly 2^96
+        let mut root = FieldElement::root_of_unity();
+        for _ in 0..95 {
+            root = root.square();
+        }
+        assert_eq!(root, -FieldElement::ONE);
+        assert_eq!(root.square(), FieldElement::ONE);
+
+        // squares of elements of large 2-power order take the most iterations
+        let mut x = FieldElement::root_of_unity();
+        for _ in 0..96 {
+            let sqrt = x.square().sqrt().unwrap();
+            assert!(sqrt == x || sqrt == -x);
+            x = x.square();
+        }
+
+        assert!(bool::from(FieldElement::root_of_unity().sqrt().is_none()));
+        assert_eq!((-FieldElement::ONE).sqrt().unwrap().square(), -FieldElement::ONE);
+    }
+}
@@ -0,0 +1,84 @@
+//! Curves used to test the generic implementation.
+
+#![allow(dead_code)]
+
+/// secp256k1, an `a = 0` curve.
+pub(crate) mod secp256k1 {
+    crate::define_curve! {
+        /// secp256k1 elliptic curve.
+        pub struct Secp256k1 {
+            uint: elliptic_curve::bigint::U256,
+            p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            a: "0000000000000000000000000000000000000000000000000000000000000000",
+            b: "0000000000000000000000000000000000000000000000000000000000000007",
+            gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            field_generator: 3,
+            scalar_generator: 7,
+        }
+    }
+}
+
+/// NIST P-256, an `a = -3` curve.
+pub(crate) mod p256 {
+    crate::define_curve! {
+        /// NIST P-256 elliptic curve.
+        pub struct NistP256 {
+            uint: elliptic_curve::bigint::U256,
+            p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+            b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            field_generator: 6,
+            scalar_generator: 7,
+        }
+    }
+}
+
+/// brainpoolP384r1, a curve with a generic `a` coefficient and whose scalar
+/// field has `n ≡ 5 mod 8`.
+pub(crate) mod brainpoolp384r1 {
+    crate::define_curve! {
+        /// brainpoolP384r1 elliptic curve.
+        pub struct BrainpoolP384r1 {
+            uint: elliptic_curve::bigint::U384,
+            p: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53",
+            n: "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+            a: "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826",
+            b: "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11",
+            gx: "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e",
+            gy: "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315",
+            field_generator: 3,
+            scalar_generator: 2,
+        }
+    }
+}
+
+/// NIST P-224, a curve whose modulus is narrower than its limbs on 64-bit
+/// platforms and whose base field has `S = 96`.
+pub(crate) mod p224 {
+    use elliptic_curve::{bigint::U256, consts::U28};
+
+    crate::define_uint! {
+        /// 224-bit unsigned integer.
+        pub struct U224(U256, U28);
+    }
+
+    crate::define_curve! {
+        /// NIST P-224 elliptic curve.
+        pub struct NistP224 {
+            uint: U224,
+            p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            n: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            a: "fffffffffffffffffffffffffffffffefffffffffffffffffffffffe",
+            b: "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+            gx: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            gy: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+            field_generator: 22,
+            scalar_generator: 2,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module provides the same API as [`elliptic_curve::ecdh`], with
+//! additional methods computing the shared secret using
+//! [`ProjectivePoint::mul_hardened`]. The RNG passed to these methods is only
+//! used for side-channel countermeasures, and the resulting shared secret is
+//! the same as without them.
+
+pub use elliptic_curve::ecdh::{diffie_hellman, SharedSecret};
+
+use crate::{ProjectivePoint, WeierstrassCurve};
+use core::borrow::Borrow;
+use elliptic_curve::{
+    rand_core::{CryptoRng, RngCore},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+    AffinePoint, AffineXCoordinate, NonZeroScalar, PublicKey,
+};
+
+/// Compute a [`SharedSecret`] using side-channel countermeasures.
+pub trait HardenedDiffieHellman<C: WeierstrassCurve>: Sized {
+    /// Compute a Diffie-Hellman shared secret like [`diffie_hellman`], using
+    /// [`ProjectivePoint::mul_hardened`] for the scalar multiplication.
+    fn diffie_hellman_hardened(
+        secret_key: impl Borrow<NonZeroScalar<C>>,
+        public_key: impl Borrow<AffinePoint<C>>,
+        rng: impl CryptoRng + RngCore,
+    ) -> Self;
+}
+
+impl<C: WeierstrassCurve> HardenedDiffieHellman<C> for SharedSecret<C> {
+    fn diffie_hellman_hardened(
+        secret_key: impl Borrow<NonZeroScalar<C>>,
+        public_key: impl Borrow<AffinePoint<C>>,
+        rng: impl CryptoRng + RngCore,
+    ) -> Self {
+        let public_point = ProjectivePoint::<C>::from(*public_key.borrow());
+        let secret_point = public_point
+            .mul_hardened(secret_key.borrow(), rng)
+            .to_affine();
+        SharedSecret::from(secret_point.x())
+    }
+}
+
+/// Ephemeral Diffie-Hellman Secret.
+///
+/// This is the same as [`elliptic_curve::ecdh::EphemeralSecret`], with an
+/// additional [`EphemeralSecret::diffie_hellman_hardened`] method.
+///
+/// # ⚠️ SECURITY WARNING ⚠️
+///
+/// Ephemeral Diffie-Hellman exchanges are unauthenticated and without a
+/// further authentication step are trivially vulnerable to man-in-the-middle
+/// attacks!
+///
+/// These exchanges should be performed in the context of a protocol which
+/// takes further steps to authenticate the peers in a key exchange.
+pub struct EphemeralSecret<C: WeierstrassCurve> {
+    scalar: NonZeroScalar<C>,
+}
+
+impl<C: WeierstrassCurve> EphemeralSecret<C> {
+    /// Generate a cryptographically random [`EphemeralSecret`].
+    pub fn random(rng: impl CryptoRng + RngCore) -> Self {
+        Self {
+            scalar: NonZeroScalar::random(rng),
+        }
+    }
+
+    /// Get the public key associated with this ephemeral secret.
+    pub fn public_key(&self) -> PublicKey<C> {
+        PublicKey::from_secret_scalar(&self.scalar)
+    }
+
+    /// Compute a Diffie-Hellman shared secret from an ephemeral secret and the
+    /// public key of the other participant in the exchange.
+    pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
+        diffie_hellman(&self.scalar, public_key.as_affine())
+    }
+
+    /// Compute the same shared secret as [`EphemeralSecret::diffie_hellman`],
+    /// using [`ProjectivePoint::mul_hardened`] for the scalar multiplication.
+    pub fn diffie_hellman_hardened(
+        &self,
+        public_key: &PublicKey<C>,
+        rng: impl CryptoRng + RngCore,
+    ) -> SharedSecret<C> {
+        SharedSecret::diffie_hellman_hardened(self.scalar, public_key.as_affine(), rng)
+    }
+}
+
+impl<C: WeierstrassCurve> From<&EphemeralSecret<C>> for PublicKey<C> {
+    fn from(ephemeral_secret: &EphemeralSecret<C>) -> Self {
+        ephemeral_secret.public_key()
+    }
+}
+
+impl<C: WeierstrassCurve> Zeroize for EphemeralSecret<C> {
+    fn zeroize(&mut self) {
+        self.scalar.zeroize()
+    }
+}
+
+impl<C: WeierstrassCurve> ZeroizeOnDrop for EphemeralSecret<C> {}
+
+impl<C: WeierstrassCurve> Drop for EphemeralSecret<C> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -0,0 +1,290 @@
+//! ECDSA support.
+
+use crate::WeierstrassCurve;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset};
+use ecdsa_core::{
+    hazmat::{DigestPrimitive, SignPrimitive},
+    signature::Error,
+    Signature, SignatureSize, SigningKey,
+};
+use elliptic_curve::{
+    generic_array::ArrayLength,
+    ops::{Invert, Reduce},
+    rand_core::{CryptoRng, RngCore},
+    subtle::CtOption,
+    FieldSize, PrimeCurve, Scalar, ScalarArithmetic,
+};
+
+/// Curves whose ECDSA nonces are derived as described in [RFC6979 § 3.2].
+///
+/// [RFC6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+pub trait Rfc6979Curve: PrimeCurve + ScalarArithmetic {
+    /// Whether the prehash is reduced modulo `n` before it is passed to
+    /// HMAC_DRBG, as required by `bits2octets` ([RFC6979 § 2.3.4]).
+    ///
+    /// The `ecdsa` crate passes the prehash as-is, so this is `false` for
+    /// curves whose deterministic signatures must match it.
+    ///
+    /// [RFC6979 § 2.3.4]: https://datatracker.ietf.org/doc/html/rfc6979#section-2.3.4
+    const REDUCE_PREHASH: bool;
+}
+
+/// ECDSA signer using side-channel countermeasures.
+///
+/// Signatures are computed with the same RFC6979 nonce as the [`Signer`]
+/// and [`PrehashSigner`] impls of the signing key, so they are identical to
+/// the deterministic ones. The RNG is only used for the countermeasures: `k×G` is computed using
+/// [`ProjectivePoint::mul_hardened`], and `k` is inverted as a
+/// [`BlindedScalar`] with a random mask.
+///
+/// [`BlindedScalar`]: crate::BlindedScalar
+/// [`PrehashSigner`]: ecdsa_core::signature::hazmat::PrehashSigner
+/// [`ProjectivePoint::mul_hardened`]: crate::ProjectivePoint::mul_hardened
+/// [`Signer`]: ecdsa_core::signature::Signer
+pub trait HardenedSigner<S> {
+    /// Sign the given message, hashing it with the curve's default digest.
+    fn try_sign_hardened(&self, rng: impl CryptoRng + RngCore, msg: &[u8]) -> Result<S, Error>;
+
+    /// Sign the given message, hashing it with the curve's default digest.
+    ///
+    /// Panics in the event of a signing error.
+    fn sign_hardened(&self, rng: impl CryptoRng + RngCore, msg: &[u8]) -> S {
+        self.try_sign_hardened(rng, msg)
+            .expect("signature operation failed")
+    }
+
+    /// Sign the given prehashed message digest.
+    fn sign_prehash_hardened(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        prehash: &[u8],
+    ) -> Result<S, Error>;
+}
+
+impl<C> HardenedSigner<Signature<C>> for SigningKey<C>
+where
+    C: WeierstrassCurve + DigestPrimitive + Rfc6979Curve,
+    C::Digest: BlockSizeUser + FixedOutput<OutputSize = FieldSize<C>> + FixedOutputReset,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>> + Reduce<C::UInt> + SignPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_hardened(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+    ) -> Result<Signature<C>, Error> {
+        self.sign_prehash_hardened(rng, &C::Digest::new_with_prefix(msg).finalize_fixed())
+    }
+
+    fn sign_prehash_hardened(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        prehash: &[u8],
+    ) -> Result<Signature<C>, Error> {
+        let z = C::prehash_to_field_bytes(prehash)?;
+        let d = self.as_nonzero_scalar().as_ref();
+        let k = hazmat::rfc6979_nonce::<C, C::Digest>(d, &z, &[]);
+        hazmat::sign_prehashed_hardened(d, k, z, rng)
+    }
+}
+
+/// Low-level ECDSA primitives.
+///
+/// # ⚠️ Warning
+///
+/// These functions are intended for implementing [`SignPrimitive`] and
+/// [`HardenedSigner`] for curves and signing key types which don't use the
+/// default impls, and are easy to misuse: the ephemeral scalar `k` must be
+/// secret and unique per message.
+pub mod hazmat {
+    use super::Rfc6979Curve;
+    use crate::{BlindedScalar, ProjectivePoint, WeierstrassCurve};
+    use digest::{core_api::BlockSizeUser, Digest, FixedOutput, FixedOutputReset};
+    use ecdsa_core::{
+        hazmat::SignPrimitive, signature::Error, RecoveryId, Signature, SignatureSize,
+    };
+    use elliptic_curve::{
+        ff::{Field, PrimeField},
+        generic_array::ArrayLength,
+        ops::{Invert, Reduce},
+        rand_core::{CryptoRng, RngCore},
+        zeroize::Zeroize,
+        AffineXCoordinate, FieldBytes, FieldSize, ProjectiveArithmetic, Scalar,
+    };
+    use rfc6979::HmacDrbg;
+
+    /// Deterministically derive the ephemeral scalar `k` from the secret
+    /// scalar `d` and the prehashed message `z` as described in RFC6979 § 3.2,
+    /// with optional additional data `ad` (RFC6979 § 3.6).
+    ///
+    /// `z` is reduced modulo `n` first if [`Rfc6979Curve::REDUCE_PREHASH`] is
+    /// set, otherwise this is the same as the `ecdsa` crate.
+    pub fn rfc6979_nonce<C, D>(d: &Scalar<C>, z: &FieldBytes<C>, ad: &[u8]) -> Scalar<C>
+    where
+        C: Rfc6979Curve,
+        D: Digest + BlockSizeUser + FixedOutput<OutputSize = FieldSize<C>> + FixedOutputReset,
+        Scalar<C>: Reduce<C::UInt>,
+    {
+        next_scalar::<C, D>(&mut rfc6979_drbg::<C, D>(d, z, ad))
+    }
+
+    /// Sign the prehashed message `z` using the secret scalar `d` and the
+    /// ephemeral scalar `k` computed by [`rfc6979_nonce`].
+    ///
+    /// `k` is inverted as a [`BlindedScalar`], masked with the next scalar
+    /// output by the same HMAC_DRBG instance. This doesn't affect the
+    /// resulting signature.
+    ///
+    /// This is intended for overriding
+    /// [`SignPrimitive::try_sign_prehashed_rfc6979`].
+    pub fn sign_prehashed_rfc6979<C, D>(
+        d: &Scalar<C>,
+        z: FieldBytes<C>,
+        ad: &[u8],
+    ) -> Result<(Signature<C>, Option<RecoveryId>), Error>
+    where
+        C: Rfc6979Curve + ProjectiveArithmetic,
+        D: Digest + BlockSizeUser + FixedOutput<OutputSize = FieldSize<C>> + FixedOutputReset,
+        Scalar<C>: SignPrimitive<C>,
+        SignatureSize<C>: ArrayLength<u8>,
+    {
+        let mut drbg = rfc6979_drbg::<C, D>(d, &z, ad);
+        let k = next_scalar::<C, D>(&mut drbg);
+        let mask = next_scalar::<C, D>(&mut drbg);
+        d.try_sign_prehashed(BlindedScalar::with_mask(k, mask), z)
+    }
+
+    /// Instantiate HMAC_DRBG with the inputs described in RFC6979 § 3.2.
+    fn rfc6979_drbg<C, D>(d: &Scalar<C>, z: &FieldBytes<C>, ad: &[u8]) -> HmacDrbg<D>
+    where
+        C: Rfc6979Curve,
+        D: Digest + BlockSizeUser + FixedOutputReset,
+        Scalar<C>: Reduce<C::UInt>,
+    {
+        let h = if C::REDUCE_PREHASH {
+            Scalar::<C>::from_be_bytes_reduced(z.clone()).to_repr()
+        } else {
+            z.clone()
+        };
+
+        let mut x = d.to_repr();
+        let drbg = HmacDrbg::new(&x, &h, ad);
+        x.as_mut_slice().zeroize();
+        drbg
+    }
+
+    /// Generate the next non-zero scalar less than `n` from HMAC_DRBG, in the
+    /// same way as `rfc6979::generate_k`.
+    fn next_scalar<C, D>(drbg: &mut HmacDrbg<D>) -> Scalar<C>
+    where
+        C: Rfc6979Curve,
+        D: Digest + BlockSizeUser + FixedOutputReset,
+    {
+        loop {
+            let mut bytes = FieldBytes::<C>::default();
+            drbg.fill_bytes(&mut bytes);
+            let k = Scalar::<C>::from_repr(bytes.clone());
+            bytes.as_mut_slice().zeroize();
+
+            if let Some(k) = Option::<Scalar<C>>::from(k) {
+                if !bool::from(k.is_zero()) {
+                    return k;
+                }
+            }
+        }
+    }
+
+    /// Sign the prehashed message `z` using the secret scalar `d` and the
+    /// ephemeral scalar `k`.
+    ///
+    /// This is equivalent to [`SignPrimitive::try_sign_prehashed`], except
+    /// `k×G` is computed using [`ProjectivePoint::mul_hardened`] and `k` is
+    /// inverted as a [`BlindedScalar`]. The provided RNG is only used for
+    /// side-channel countermeasures and doesn't affect the resulting signature.
+    ///
+    /// [`SignPrimitive::try_sign_prehashed`]: ecdsa_core::hazmat::SignPrimitive::try_sign_prehashed
+    pub fn sign_prehashed_hardened<C>(
+        d: &Scalar<C>,
+        k: Scalar<C>,
+        z: FieldBytes<C>,
+        mut rng: impl CryptoRng + RngCore,
+    ) -> Result<Signature<C>, Error>
+    where
+        C: WeierstrassCurve,
+        Scalar<C>: Reduce<C::UInt>,
+        SignatureSize<C>: ArrayLength<u8>,
+    {
+        let k_inv = BlindedScalar::new(k, &mut rng).invert();
+        let k_inv = Option::<Scalar<C>>::from(k_inv).ok_or_else(Error::new)?;
+        let z = Scalar::<C>::from_be_bytes_reduced(z);
+
+        // Compute 𝑹 = 𝑘×𝑮
+        let big_r = ProjectivePoint::<C>::GENERATOR
+            .mul_hardened(&k, &mut rng)
+            .to_affine();
+        let r = Scalar::<C>::from_be_bytes_reduced(big_r.x());
+        let s = k_inv * (z + (r * d));
+
+        if s.is_zero().into() {
+            return Err(Error::new());
+        }
+
+        Signature::from_scalars(r, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hazmat, Rfc6979Curve};
+    use crate::dev::p256::{NistP256, Scalar};
+    use ecdsa_core::hazmat::SignPrimitive;
+    use elliptic_curve::ff::PrimeField;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    impl Rfc6979Curve for NistP256 {
+        const REDUCE_PREHASH: bool = false;
+    }
+
+    impl SignPrimitive<NistP256> for Scalar {}
+
+    /// Test vectors from RFC 6979 § A.2.5 (NIST P-256 + SHA-256).
+    ///
+    /// Blinding the inversion of `k` must not change the signature.
+    #[test]
+    fn sign_prehashed_rfc6979() {
+        let d = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let d = Scalar::from_repr(d.into()).unwrap();
+
+        let vectors: [(&[u8], [u8; 32], [u8; 64]); 2] = [
+            (
+                b"sample",
+                hex!("a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60"),
+                hex!(
+                    "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716
+                    f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+                ),
+            ),
+            (
+                b"test",
+                hex!("d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0"),
+                hex!(
+                    "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367
+                    019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083"
+                ),
+            ),
+        ];
+
+        for (msg, k, sig) in vectors {
+            let z = Sha256::digest(msg);
+            let nonce = hazmat::rfc6979_nonce::<NistP256, Sha256>(&d, &z, &[]);
+            assert_eq!(nonce.to_repr().as_slice(), &k);
+
+            let unblinded = d.try_sign_prehashed(nonce, z).unwrap().0;
+            assert_eq!(unblinded.as_ref(), &sig);
+
+            let blinded = hazmat::sign_prehashed_rfc6979::<NistP256, Sha256>(&d, z, &[]);
+            assert_eq!(blinded.unwrap().0, unblinded);
+        }
+    }
+}
@@ -16,14 +16,26 @@
 /// - `pub fn is_odd`
 /// - `pub fn is_zero`
 /// - `pub fn double`
+/// - `pub fn batch_invert`
+/// - `pub fn batch_invert_slice` (requires the `alloc` feature)
 ///
 /// NOTE: field implementations must provide their own inherent impls of
 /// the following methods in order for the code generated by this macro to
-/// compile:
+/// compile, unless they're generated as described below:
 ///
 /// - `pub fn invert`
 /// - `pub fn sqrt`
 ///
+/// # Optional inherent impls
+/// Passing `invert` and/or `sqrt` as additional trailing arguments generates
+/// constant-time implementations of the corresponding methods:
+///
+/// - `invert`: Fermat inversion, i.e. `self^(p - 2)`.
+/// - `sqrt`: `self^((p + 1) / 4)` if `p ≡ 3 mod 4`, Atkin's algorithm if
+///   `p ≡ 5 mod 8`, and Tonelli-Shanks otherwise. Tonelli-Shanks is driven by
+///   `PrimeField::S` and `PrimeField::root_of_unity`, so the field must also
+///   impl `PrimeField`.
+///
 /// # Trait impls
 /// - `AsRef<$arr>`
 /// - `ConditionallySelectable`
@@ -46,6 +58,91 @@
 /// - `Neg`
 #[macro_export]
 macro_rules! impl_field_element {
+    (@generate invert, $fe:tt, $uint:ty, $modulus:expr) => {
+        impl $fe {
+            /// Compute [`
+            #[doc = stringify!($fe)]
+            /// `] inversion: `1 / self`.
+            pub fn invert(&self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                const P_MINUS_2: $uint = $modulus.wrapping_sub(&<$uint>::from_u8(2));
+                $crate::elliptic_curve::subtle::CtOption::new(
+                    self.pow_fixed(&P_MINUS_2),
+                    !self.is_zero(),
+                )
+            }
+        }
+    };
+    (@generate sqrt, $fe:tt, $uint:ty, $modulus:expr) => {
+        impl $fe {
+            /// Returns the square root of self mod p, or `None` if no square
+            /// root exists.
+            pub fn sqrt(&self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                const P_MOD_8: $crate::elliptic_curve::bigint::Word = $modulus.as_words()[0] & 7;
+
+                let sqrt = if P_MOD_8 & 3 == 3 {
+                    // p mod 4 = 3 -> compute sqrt(x) using x^((p+1)/4)
+                    const P_PLUS_1_DIV_4: $uint = $modulus.shr_vartime(2).wrapping_add(&<$uint>::ONE);
+                    self.pow_fixed(&P_PLUS_1_DIV_4)
+                } else if P_MOD_8 == 5 {
+                    // p mod 8 = 5 -> compute sqrt(x) using Atkin's algorithm:
+                    // t = (2x)^((p-5)/8), i = 2xt^2, sqrt(x) = xt(i - 1)
+                    const P_MINUS_5_DIV_8: $uint = $modulus.shr_vartime(3);
+                    let x2 = self.double();
+                    let t = x2.pow_fixed(&P_MINUS_5_DIV_8);
+                    let i = x2 * t.square();
+                    *self * t * (i - Self::ONE)
+                } else {
+                    self.sqrt_tonelli_shanks()
+                };
+
+                let is_some = $crate::elliptic_curve::subtle::ConstantTimeEq::ct_eq(&sqrt.square(), self);
+                $crate::elliptic_curve::subtle::CtOption::new(sqrt, is_some)
+            }
+
+            /// Constant-time Tonelli-Shanks (<https://eprint.iacr.org/2012/685.pdf>,
+            /// algorithm 5), returning a candidate square root.
+            fn sqrt_tonelli_shanks(&self) -> Self {
+                use $crate::elliptic_curve::{
+                    ff::PrimeField,
+                    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+                };
+
+                // w = x^((t - 1) / 2), where p - 1 = 2^S * t
+                const T_MINUS_1_DIV_2: $uint =
+                    $modulus.shr_vartime(<$fe as PrimeField>::S as usize + 1);
+                let w = self.pow_fixed(&T_MINUS_1_DIV_2);
+
+                let mut v = <Self as PrimeField>::S;
+                let mut x = *self * w;
+                let mut b = x * w;
+                let mut z = <Self as PrimeField>::root_of_unity();
+
+                for max_v in (1..=<Self as PrimeField>::S).rev() {
+                    let mut k = 1;
+                    let mut tmp = b.square();
+                    let mut j_less_than_v = Choice::from(1);
+
+                    for j in 2..max_v {
+                        let tmp_is_one = tmp.ct_eq(&Self::ONE);
+                        let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                        tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                        let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                        j_less_than_v &= !j.ct_eq(&v);
+                        k = u32::conditional_select(&j, &k, tmp_is_one);
+                        z = Self::conditional_select(&z, &new_z, j_less_than_v);
+                    }
+
+                    let result = x * z;
+                    x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
+                    z = z.square();
+                    b *= z;
+                    v = k;
+                }
+
+                x
+            }
+        }
+    };
     (
         $fe:tt,
         $bytes:ty,
@@ -59,7 +156,11 @@ macro_rules! impl_field_element {
         $mul:ident,
         $neg:ident,
         $square:ident
+        $(, $generate:ident)*
     ) => {
+        $($crate::impl_field_element!(@generate $generate, $fe, $uint, $modulus);)*
+        $crate::__impl_batch_invert_slice!($fe);
+
         impl $fe {
             /// Zero element.
             pub const ZERO: Self = Self(<$uint>::ZERO);
@@ -79,9 +180,11 @@ macro_rules! impl_field_element {
             #[doc = stringify!($fe)]
             /// `] from a big endian byte slice.
             pub fn from_be_slice(slice: &[u8]) -> $crate::elliptic_curve::Result<Self> {
-                <$uint as $crate::elliptic_curve::bigint::Encoding>::Repr::try_from(slice)
-                    .ok()
-                    .and_then(|array| Self::from_be_bytes(array.into()).into())
+                if slice.len() != <$uint as $crate::elliptic_curve::bigint::Encoding>::BYTE_SIZE {
+                    return Err($crate::elliptic_curve::Error);
+                }
+
+                Option::from(Self::from_be_bytes(<$bytes>::clone_from_slice(slice)))
                     .ok_or($crate::elliptic_curve::Error)
             }
 
@@ -97,9 +200,11 @@ macro_rules! impl_field_element {
             #[doc = stringify!($fe)]
             /// `] from a little endian byte slice.
             pub fn from_le_slice(slice: &[u8]) -> $crate::elliptic_curve::Result<Self> {
-                <$uint as $crate::elliptic_curve::bigint::Encoding>::Repr::try_from(slice)
-                    .ok()
-                    .and_then(|array| Self::from_le_bytes(array.into()).into())
+                if slice.len() != <$uint as $crate::elliptic_curve::bigint::Encoding>::BYTE_SIZE {
+                    return Err($crate::elliptic_curve::Error);
+                }
+
+                Option::from(Self::from_le_bytes(<$bytes>::clone_from_slice(slice)))
                     .ok_or($crate::elliptic_curve::Error)
             }
 
@@ -259,6 +364,57 @@ macro_rules! impl_field_element {
             pub const fn square(&self) -> Self {
                 Self(<$uint>::from_words($square(self.0.as_words())))
             }
+
+            /// Returns `self^exp`, where `exp` is a fixed (i.e. public) exponent.
+            ///
+            /// **This operation is variable time with respect to the exponent.**
+            #[allow(dead_code)]
+            const fn pow_fixed(&self, exp: &$uint) -> Self {
+                let exp = exp.as_words();
+                let mut res = Self::ONE;
+                let mut i = exp.len();
+
+                while i > 0 {
+                    i -= 1;
+                    let mut j = $crate::elliptic_curve::bigint::Word::BITS;
+
+                    while j > 0 {
+                        j -= 1;
+                        res = Self::square(&res);
+
+                        if (exp[i] >> j) & 1 == 1 {
+                            res = Self::mul(&res, self);
+                        }
+                    }
+                }
+
+                res
+            }
+
+            /// Invert each of the given [`
+            #[doc = stringify!($fe)]
+            /// `]s in place using Montgomery's trick, which requires only a
+            /// single inversion.
+            ///
+            /// Zero-valued elements are left as zero.
+            ///
+            /// # Returns
+            ///
+            /// If all elements were nonzero, return `Choice(1)`.  Otherwise,
+            /// return `Choice(0)`.
+            pub fn batch_invert<const N: usize>(elements: &mut [Self; N]) -> Choice {
+                let all_nonzero = elements
+                    .iter()
+                    .fold(Choice::from(1), |acc, element| acc & !element.is_zero());
+
+                let mut scratch = [Self::ZERO; N];
+                $crate::elliptic_curve::ff::BatchInverter::invert_with_external_scratch(
+                    elements,
+                    &mut scratch,
+                );
+
+                all_nonzero
+            }
         }
 
         impl AsRef<$arr> for $fe {
@@ -310,10 +466,25 @@ macro_rules! impl_field_element {
         impl $crate::elliptic_curve::ff::Field for $fe {
             fn random(mut rng: impl $crate::elliptic_curve::rand_core::RngCore) -> Self {
                 // NOTE: can't use ScalarCore::random due to CryptoRng bound
+                const BITS: usize = $modulus.bits_vartime();
                 let mut bytes = <$bytes>::default();
+                let len = bytes.len();
 
                 loop {
                     rng.fill_bytes(&mut bytes);
+
+                    // Mask off the bits above the modulus, so that each
+                    // candidate is accepted with probability at least 1/2
+                    for (i, byte) in bytes.iter_mut().enumerate() {
+                        let shift = (len - 1 - i) * 8;
+
+                        if shift >= BITS {
+                            *byte = 0;
+                        } else if BITS - shift < 8 {
+                            *byte &= (1 << (BITS - shift)) - 1;
+                        }
+                    }
+
                     if let Some(fe) = Self::from_be_bytes(bytes).into() {
                         return fe;
                     }
@@ -441,3 +612,56 @@ macro_rules! impl_field_op {
         }
     };
 }
+
+/// Emit `batch_invert_slice` for [`impl_field_element!`], which allocates its
+/// scratch space and is therefore only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert_slice {
+    ($fe:tt) => {
+        impl $fe {
+            /// Invert each of the given [`
+            #[doc = stringify!($fe)]
+            /// `]s in place using Montgomery's trick, like
+            /// [`Self::batch_invert`] but for a slice of any length.
+            ///
+            /// Zero-valued elements are left as zero.
+            ///
+            /// # Returns
+            ///
+            /// If all elements were nonzero, return `Choice(1)`.  Otherwise,
+            /// return `Choice(0)`.
+            pub fn batch_invert_slice(
+                elements: &mut [Self],
+            ) -> $crate::elliptic_curve::subtle::Choice {
+                $crate::batch_invert_slice(elements)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert_slice {
+    ($fe:tt) => {};
+}
+
+/// Invert `elements` in place using heap-allocated scratch space.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn batch_invert_slice<F: elliptic_curve::Field>(
+    elements: &mut [F],
+) -> elliptic_curve::subtle::Choice {
+    use elliptic_curve::{ff::BatchInverter, subtle::Choice};
+
+    let all_nonzero = elements
+        .iter()
+        .fold(Choice::from(1), |acc, element| acc & !element.is_zero());
+
+    let mut scratch = alloc::vec![F::zero(); elements.len()];
+    BatchInverter::invert_with_external_scratch(elements, &mut scratch);
+
+    all_nonzero
+}
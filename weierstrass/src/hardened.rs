@@ -0,0 +1,116 @@
+//! Scalar multiplication with side-channel countermeasures.
+
+use crate::{ProjectivePoint, WeierstrassCurve};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, Encoding},
+    ff::Field,
+    rand_core::{CryptoRng, RngCore},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    Scalar,
+};
+
+/// Number of random bytes in the multiple of the group order which is added to
+/// the scalar.
+const BLINDING_BYTES: usize = 8;
+
+/// Maximum size in bytes of a blinded scalar, large enough for 576-bit scalars.
+const MAX_BYTES: usize = 72 + BLINDING_BYTES;
+
+impl<C> ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Returns `[k] self`, using countermeasures against side-channel attacks
+    /// on top of the constant time implementation of [`Mul`]:
+    ///
+    /// - The projective coordinates of `self` are multiplied by a random
+    ///   nonzero field element, so intermediate values can't be predicted
+    ///   from the (public) affine coordinates of the point.
+    /// - A random 64-bit multiple of the group order is added to `k`, so the
+    ///   sequence of digits processed differs on every call.
+    /// - The blinded scalar is recoded into signed radix-16 digits, which are
+    ///   used to select multiples from a table of projective points.
+    ///
+    /// This is slower than [`Mul`], and intended for use with long-term
+    /// secrets on platforms where power or electromagnetic side channels are
+    /// a concern, e.g. by ECDH and ECDSA signing.
+    ///
+    /// [`Mul`]: core::ops::Mul
+    pub fn mul_hardened(&self, k: &Scalar<C>, mut rng: impl CryptoRng + RngCore) -> Self {
+        // Randomize the projective coordinates of the base point (Coron)
+        let lambda = C::FieldElement::random(&mut rng);
+        let lambda = C::FieldElement::conditional_select(&lambda, &C::ONE, lambda.is_zero());
+        let base = Self {
+            x: self.x * lambda,
+            y: self.y * lambda,
+            z: self.z * lambda,
+        };
+
+        let mut table = [base; 8];
+
+        for i in 1..8 {
+            table[i] = table[i - 1].add(&base);
+        }
+
+        // Compute `k + r * n` for a random `r`, which is less than `2^64 * n`
+        let len = C::UInt::BYTE_SIZE + BLINDING_BYTES;
+        assert!(len <= MAX_BYTES, "scalar too large");
+
+        let k = Into::<C::UInt>::into(*k).to_le_byte_array();
+        let n = C::ORDER.to_le_byte_array();
+        let r = rng.next_u64();
+        let mut carry = 0u128;
+        let mut digits = [0i8; 2 * MAX_BYTES + 1];
+
+        for i in 0..len {
+            let k_i = k.get(i).copied().unwrap_or(0);
+            let n_i = n.get(i).copied().unwrap_or(0);
+            carry += u128::from(k_i) + u128::from(n_i) * u128::from(r);
+
+            let byte = carry as u8;
+            digits[2 * i] = (byte & 0xf) as i8;
+            digits[2 * i + 1] = ((byte >> 4) & 0xf) as i8;
+            carry >>= 8;
+        }
+
+        // Recenter the radix-16 digits into the range `[-8, 8)`
+        let digits = &mut digits[..(2 * len + 1)];
+
+        for i in 0..(2 * len) {
+            let carry = (digits[i] + 8) >> 4;
+            digits[i] -= carry << 4;
+            digits[i + 1] += carry;
+        }
+
+        let mut acc = Self::IDENTITY;
+
+        for digit in digits.iter().rev() {
+            acc = acc.double().double().double().double();
+            acc += select(&table, *digit);
+        }
+
+        acc
+    }
+}
+
+/// Given `-8 <= x <= 8`, returns `[x]P` from the table `[1P, 2P, ..., 8P]` in
+/// constant time.
+fn select<C>(table: &[ProjectivePoint<C>; 8], x: i8) -> ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    debug_assert!((-8..=8).contains(&x));
+
+    // Compute xabs = |x|
+    let xmask = x >> 7;
+    let xabs = (x + xmask) ^ xmask;
+
+    let mut t = ProjectivePoint::IDENTITY;
+
+    for (j, point) in (1u8..).zip(table.iter()) {
+        t.conditional_assign(point, (xabs as u8).ct_eq(&j));
+    }
+
+    t.conditional_assign(&-t, Choice::from((xmask & 1) as u8));
+    t
+}
@@ -0,0 +1,64 @@
+//! Hash-to-curve helpers.
+
+use elliptic_curve::hash2curve::OsswuMap;
+
+/// Optimized simplified Shallue-van de Woestijne-Ulas map, as described in
+/// RFC 9380 § 6.6.2.
+///
+/// This is the same as the provided [`OsswuMap::osswu`] method, except the
+/// `c1 = (p - 3) / 4` exponent is passed as a slice of little endian 64-bit
+/// words rather than read from [`OsswuMapParams::c1`], which is limited to
+/// 256 bits. Curves whose base field is wider than that should override
+/// [`OsswuMap::osswu`] with a call to this function.
+///
+/// Like the provided method, this requires `p = 3 mod 4`.
+///
+/// [`OsswuMapParams::c1`]: elliptic_curve::hash2curve::OsswuMapParams::c1
+pub fn osswu<F>(u: &F, c1: &[u64]) -> (F, F)
+where
+    F: OsswuMap,
+{
+    let params = F::PARAMS;
+
+    let tv1 = u.square(); // u^2
+    let tv3 = params.z * tv1; // Z * u^2
+    let mut tv2 = tv3.square(); // tv3^2
+    let mut xd = tv2 + tv3; // tv3^2 + tv3
+    let x1n = params.map_b * (xd + F::one()); // B * (xd + 1)
+    xd *= -params.map_a; // -A * xd
+
+    let tv = params.z * params.map_a;
+    xd.conditional_assign(&tv, xd.is_zero());
+
+    tv2 = xd.square(); // xd^2
+    let gxd = tv2 * xd; // xd^3
+    tv2 *= params.map_a; // A * tv2
+
+    let mut gx1 = x1n * (tv2 + x1n.square()); // x1n * (tv2 + x1n^2)
+    tv2 = gxd * params.map_b; // B * gxd
+    gx1 += tv2; // gx1 + tv2
+
+    let mut tv4 = gxd.square(); // gxd^2
+    tv2 = gx1 * gxd; // gx1 * gxd
+    tv4 *= tv2;
+
+    let y1 = tv4.pow_vartime(c1) * tv2; // tv4^c1 * tv2
+    let x2n = tv3 * x1n; // tv3 * x1n
+
+    let y2 = y1 * params.c2 * tv1 * u; // y1 * c2 * tv1 * u
+
+    tv2 = y1.square() * gxd; // y1^2 * gxd
+
+    let e2 = tv2.ct_eq(&gx1);
+
+    // if e2, x = x1, else x = x2
+    let mut x = F::conditional_select(&x2n, &x1n, e2);
+    // xn / xd
+    x *= xd.invert().unwrap();
+
+    // if e2, y = y1, else y = y2
+    let mut y = F::conditional_select(&y2, &y1, e2);
+
+    y.conditional_assign(&-y, u.sgn0() ^ y.sgn0());
+    (x, y)
+}
@@ -8,14 +8,58 @@
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod affine;
+mod blinded;
+mod curve;
 mod field;
+mod hardened;
+#[cfg(feature = "alloc")]
+mod msm;
 mod projective;
+mod table;
+mod wnaf;
+
+#[cfg(test)]
+mod dev;
+
+pub mod montgomery;
+#[doc(hidden)]
+pub mod uint;
+
+#[cfg(feature = "ecdh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub mod ecdsa;
 
-pub use crate::{affine::AffinePoint, projective::ProjectivePoint};
+#[cfg(feature = "hash2curve")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hash2curve")))]
+pub mod hash2curve;
+
+pub use crate::{
+    affine::AffinePoint,
+    blinded::BlindedScalar,
+    projective::ProjectivePoint,
+    table::LookupTable,
+};
 pub use elliptic_curve::{self, Field, FieldBytes, PrimeCurve, PrimeField};
 
-use elliptic_curve::{AffineArithmetic, ProjectiveArithmetic, ScalarArithmetic};
+#[cfg(feature = "alloc")]
+pub use crate::{msm::pippenger_vartime, table::BasepointTable};
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use crate::field::batch_invert_slice;
+
+use elliptic_curve::{
+    bigint::Word,
+    AffineArithmetic, ProjectiveArithmetic, Scalar, ScalarArithmetic,
+};
 
 /// Weierstrass curve parameters.
 pub trait WeierstrassCurve:
@@ -38,9 +82,82 @@ pub trait WeierstrassCurve:
     /// Coefficient `a` in the curve equation.
     const EQUATION_A: Self::FieldElement;
 
+    /// Shape of the `a` coefficient, used to select specialized point
+    /// arithmetic formulas.
+    ///
+    /// Must be consistent with [`WeierstrassCurve::EQUATION_A`].
+    const EQUATION_A_SHAPE: EquationAShape = EquationAShape::Generic;
+
     /// Coefficient `b` in the curve equation.
     const EQUATION_B: Self::FieldElement;
 
     /// Generator point's affine coordinates: (x, y).
     const GENERATOR: (Self::FieldElement, Self::FieldElement);
+
+    /// Compute `[k] G`, where `G` is the generator.
+    ///
+    /// Used whenever [`ProjectivePoint::GENERATOR`] is multiplied by a scalar.
+    /// Curves can override this to use a precomputed [`BasepointTable`].
+    fn mul_by_generator(k: &Scalar<Self>) -> ProjectivePoint<Self> {
+        ProjectivePoint::GENERATOR.mul_windowed(k)
+    }
+
+    /// Odd multiples `[1, 3, 5, ..., 2^(w-1) - 1] G` of the generator, used
+    /// by the variable-time wNAF implementation of [`LinearCombination`].
+    ///
+    /// The number of entries must be a power of two `2^(w-2)`, where `w` is
+    /// the wNAF window size, and at most 64 (`w <= 8`). Curves can override
+    /// this to return [`BasepointTable::odd_multiples`].
+    ///
+    /// [`LinearCombination`]: elliptic_curve::ops::LinearCombination
+    fn generator_odd_multiples() -> Option<&'static [AffinePoint<Self>]> {
+        None
+    }
+}
+
+/// Shape of the `a` coefficient in the curve equation.
+///
+/// Curves with `a = -3` or `a = 0` can use cheaper complete addition formulas
+/// than curves with an arbitrary `a` (Renes-Costello-Batina 2015 §3.2, §3.3).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EquationAShape {
+    /// Arbitrary `a` coefficient.
+    Generic,
+
+    /// `a = -3`.
+    MinusThree,
+
+    /// `a = 0`.
+    Zero,
+}
+
+impl EquationAShape {
+    /// Determine the shape of the coefficient `a` from its canonical (i.e. not
+    /// Montgomery form) little endian limbs and the field modulus `p`.
+    pub const fn from_coefficient<const LIMBS: usize>(
+        a: &[Word; LIMBS],
+        p: &[Word; LIMBS],
+    ) -> Self {
+        let mut three = [0; LIMBS];
+        three[0] = 3;
+        let minus_three = montgomery::neg(&three, p);
+
+        let mut is_zero = true;
+        let mut is_minus_three = true;
+        let mut i = 0;
+
+        while i < LIMBS {
+            is_zero &= a[i] == 0;
+            is_minus_three &= a[i] == minus_three[i];
+            i += 1;
+        }
+
+        if is_zero {
+            EquationAShape::Zero
+        } else if is_minus_three {
+            EquationAShape::MinusThree
+        } else {
+            EquationAShape::Generic
+        }
+    }
 }
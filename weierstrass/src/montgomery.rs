@@ -0,0 +1,246 @@
+//! Generic Montgomery arithmetic over little endian arrays of limbs.
+//!
+//! These functions are suitable for use with [`impl_field_element!`] for
+//! moduli which don't have a special form that a dedicated (e.g. fiat-crypto
+//! generated) implementation could take advantage of.
+//!
+//! All functions are `const fn` so they can be used to compute constants, and
+//! execute in constant time with respect to their inputs, but not with respect
+//! to the modulus or exponents.
+//!
+//! Inputs must be fully reduced, i.e. less than the modulus `p`, which must be
+//! odd. Outputs are always fully reduced.
+//!
+//! [`impl_field_element!`]: crate::impl_field_element
+
+use elliptic_curve::bigint::{Limb, Word};
+
+/// Compute `-p^{-1} mod 2^w` for the given modulus, where `w` is the limb
+/// size in bits.
+///
+/// This is the constant `p'` used when performing Montgomery reduction.
+pub const fn neg_inv<const LIMBS: usize>(p: &[Word; LIMBS]) -> Word {
+    // Newton's iteration doubles the number of correct low bits every round.
+    let mut inv: Word = 1;
+    let mut i = 0;
+
+    while i < Word::BITS.trailing_zeros() {
+        inv = inv.wrapping_mul((2 as Word).wrapping_sub(p[0].wrapping_mul(inv)));
+        i += 1;
+    }
+
+    inv.wrapping_neg()
+}
+
+/// Compute `R^2 mod p`, where `R = 2^(w * LIMBS)`.
+///
+/// This is the constant needed by [`to_montgomery`]. It's computed by
+/// repeated doubling, so it's only intended to be evaluated at compile time.
+pub const fn r2<const LIMBS: usize>(p: &[Word; LIMBS]) -> [Word; LIMBS] {
+    let mut w = [0; LIMBS];
+    w[0] = 1;
+    let mut i = 0;
+
+    while i < 2 * LIMBS * Word::BITS as usize {
+        w = add(&w, &w, p);
+        i += 1;
+    }
+
+    w
+}
+
+/// Compute the largest `s` such that `2^s` divides `p - 1`.
+///
+/// This is the constant `S` of [`PrimeField`](elliptic_curve::ff::PrimeField).
+pub const fn two_adicity<const LIMBS: usize>(p: &[Word; LIMBS]) -> u32 {
+    // `p` is odd, so `p - 1` just clears the lowest bit
+    let mut s = 0;
+    let mut i = 0;
+
+    while i < LIMBS {
+        let w = if i == 0 { p[0] & !1 } else { p[i] };
+
+        if w != 0 {
+            return s + w.trailing_zeros();
+        }
+
+        s += Word::BITS;
+        i += 1;
+    }
+
+    s
+}
+
+/// Returns `a + b mod p`.
+pub const fn add<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    b: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+) -> [Word; LIMBS] {
+    let mut w = [0; LIMBS];
+    let mut carry = Limb::ZERO;
+    let mut i = 0;
+
+    while i < LIMBS {
+        let (l, c) = Limb(a[i]).adc(Limb(b[i]), carry);
+        w[i] = l.0;
+        carry = c;
+        i += 1;
+    }
+
+    sub_modulus(&w, carry, p)
+}
+
+/// Returns `a - b mod p`.
+pub const fn sub<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    b: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+) -> [Word; LIMBS] {
+    let mut w = [0; LIMBS];
+    let mut borrow = Limb::ZERO;
+    let mut i = 0;
+
+    while i < LIMBS {
+        let (l, bw) = Limb(a[i]).sbb(Limb(b[i]), borrow);
+        w[i] = l.0;
+        borrow = bw;
+        i += 1;
+    }
+
+    // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+    // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the
+    // modulus.
+    let mut carry = Limb::ZERO;
+    i = 0;
+
+    while i < LIMBS {
+        let (l, c) = Limb(w[i]).adc(Limb(p[i] & borrow.0), carry);
+        w[i] = l.0;
+        carry = c;
+        i += 1;
+    }
+
+    w
+}
+
+/// Returns `-a mod p`.
+pub const fn neg<const LIMBS: usize>(a: &[Word; LIMBS], p: &[Word; LIMBS]) -> [Word; LIMBS] {
+    sub(&[0; LIMBS], a, p)
+}
+
+/// Returns `a * b * R^{-1} mod p`, where `R = 2^(w * LIMBS)`.
+///
+/// Uses the Coarsely Integrated Operand Scanning (CIOS) method described in
+/// "Analyzing and Comparing Montgomery Multiplication Algorithms"
+/// (Koç, Acar, Kaliski 1996).
+///
+/// `p_inv` must be the output of [`neg_inv`] for `p`.
+pub const fn mul<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    b: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+    p_inv: Word,
+) -> [Word; LIMBS] {
+    let mut t = [0; LIMBS];
+    let mut t_hi = Limb::ZERO;
+    let mut i = 0;
+
+    while i < LIMBS {
+        // t += a * b[i]
+        let mut carry = Limb::ZERO;
+        let mut j = 0;
+
+        while j < LIMBS {
+            let (l, c) = Limb(t[j]).mac(Limb(a[j]), Limb(b[i]), carry);
+            t[j] = l.0;
+            carry = c;
+            j += 1;
+        }
+
+        let (hi, hi_carry) = t_hi.adc(carry, Limb::ZERO);
+
+        // t = (t + m * p) / 2^w, where m is chosen so the low limb vanishes
+        let m = Limb(t[0].wrapping_mul(p_inv));
+        let (_, mut carry) = Limb(t[0]).mac(m, Limb(p[0]), Limb::ZERO);
+        j = 1;
+
+        while j < LIMBS {
+            let (l, c) = Limb(t[j]).mac(m, Limb(p[j]), carry);
+            t[j - 1] = l.0;
+            carry = c;
+            j += 1;
+        }
+
+        let (l, c) = hi.adc(carry, Limb::ZERO);
+        t[LIMBS - 1] = l.0;
+        t_hi = hi_carry.wrapping_add(c);
+        i += 1;
+    }
+
+    // Result may be within `p` of the correct value
+    sub_modulus(&t, t_hi, p)
+}
+
+/// Returns `a^2 * R^{-1} mod p`.
+pub const fn square<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+    p_inv: Word,
+) -> [Word; LIMBS] {
+    mul(a, a, p, p_inv)
+}
+
+/// Translate a value into the Montgomery domain: `a * R mod p`.
+///
+/// `r2` must be `R^2 mod p`.
+pub const fn to_montgomery<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    r2: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+    p_inv: Word,
+) -> [Word; LIMBS] {
+    mul(a, r2, p, p_inv)
+}
+
+/// Translate a value out of the Montgomery domain: `a * R^{-1} mod p`.
+pub const fn from_montgomery<const LIMBS: usize>(
+    a: &[Word; LIMBS],
+    p: &[Word; LIMBS],
+    p_inv: Word,
+) -> [Word; LIMBS] {
+    let mut one = [0; LIMBS];
+    one[0] = 1;
+    mul(a, &one, p, p_inv)
+}
+
+/// Subtract `p` from the `LIMBS + 1` limb value `(hi, w)` if it is greater
+/// than or equal to `p`, where `(hi, w) < 2p`.
+const fn sub_modulus<const LIMBS: usize>(
+    w: &[Word; LIMBS],
+    hi: Limb,
+    p: &[Word; LIMBS],
+) -> [Word; LIMBS] {
+    let mut r = [0; LIMBS];
+    let mut borrow = Limb::ZERO;
+    let mut i = 0;
+
+    while i < LIMBS {
+        let (l, bw) = Limb(w[i]).sbb(Limb(p[i]), borrow);
+        r[i] = l.0;
+        borrow = bw;
+        i += 1;
+    }
+
+    // If `(hi, w) < p` the subtraction underflowed, in which case `mask` is
+    // 0xfff...fff and the original value is selected.
+    let (_, mask) = hi.sbb(Limb::ZERO, borrow);
+    i = 0;
+
+    while i < LIMBS {
+        r[i] = (w[i] & mask.0) | (r[i] & !mask.0);
+        i += 1;
+    }
+
+    r
+}
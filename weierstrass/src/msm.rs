@@ -0,0 +1,206 @@
+//! Variable-time multi-scalar multiplication.
+
+use crate::{
+    wnaf::{self, OddMultiples, Wnaf, MAX_DIGITS, MAX_WINDOW},
+    ProjectivePoint, WeierstrassCurve,
+};
+use alloc::{vec, vec::Vec};
+use core::borrow::Borrow;
+use elliptic_curve::{bigint::ArrayEncoding, ff::PrimeField, group::Group, Scalar};
+
+/// Number of terms above which the bucket method is used instead of Straus.
+///
+/// See the `multiscalar_mul_vartime` benchmarks in `benches/msm.rs`.
+const PIPPENGER_THRESHOLD: usize = 256;
+
+impl<C> ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Calculates `sum(x_i * k_i)` for the given `(x_i, k_i)` pairs.
+    ///
+    /// Uses interleaved wNAF (Straus) for a small number of terms and the
+    /// bucket method (Pippenger) for a large number of terms.
+    ///
+    /// This is **not** constant time, and must only be used with public
+    /// scalars, e.g. for batch verification.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn multiscalar_mul_vartime<I, T>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<(Self, Scalar<C>)>,
+    {
+        let (points, scalars): (Vec<_>, Vec<_>) = pairs
+            .into_iter()
+            .map(|pair| {
+                let (point, scalar) = pair.borrow();
+                (*point, *scalar)
+            })
+            .unzip();
+
+        if points.len() > PIPPENGER_THRESHOLD {
+            let scalars = scalars
+                .iter()
+                .map(|scalar| Into::<C::UInt>::into(*scalar).to_le_byte_array())
+                .collect::<Vec<_>>();
+
+            pippenger_vartime(&points, &scalars, Scalar::<C>::NUM_BITS as usize)
+        } else if Scalar::<C>::NUM_BITS as usize + MAX_WINDOW > MAX_DIGITS {
+            points
+                .iter()
+                .zip(scalars.iter())
+                .map(|(point, scalar)| point.mul_windowed(scalar))
+                .sum()
+        } else {
+            let tables = points.iter().map(OddMultiples::new).collect::<Vec<_>>();
+            let nafs = tables
+                .iter()
+                .zip(scalars.iter())
+                .map(|(table, scalar)| Wnaf::new::<C>(scalar, table.window()))
+                .collect::<Vec<_>>();
+
+            wnaf::straus(&tables, &nafs)
+        }
+    }
+}
+
+/// Calculates `sum(points[i] * scalars[i])` using the bucket method
+/// (Pippenger) with signed digits.
+///
+/// Each scalar is given by its little-endian byte encoding, of which only the
+/// lowest `num_bits` bits are used. This lets curves with an endomorphism
+/// pass the shorter scalars of a decomposition.
+///
+/// This is **not** constant time, and must only be used with public scalars.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn pippenger_vartime<G, S>(points: &[G], scalars: &[S], num_bits: usize) -> G
+where
+    G: Group,
+    S: AsRef<[u8]>,
+{
+    debug_assert_eq!(points.len(), scalars.len());
+
+    if points.is_empty() {
+        return G::identity();
+    }
+
+    // The window size grows with the logarithm of the number of terms
+    let log2_n = (usize::BITS - 1 - points.len().leading_zeros()) as usize;
+    let c = log2_n.saturating_sub(2).max(4);
+    // Leaving room for two extra bits ensures the last window never carries
+    let windows = (num_bits + c + 1) / c;
+
+    // Decompose each scalar into signed radix-`2^c` digits in `[-2^(c-1), 2^(c-1))`
+    let mut digits = vec![0i32; points.len() * windows];
+
+    for (scalar, scalar_digits) in scalars.iter().zip(digits.chunks_mut(windows)) {
+        let bytes = scalar.as_ref();
+        let bit = |i: usize| -> i32 {
+            if i < num_bits {
+                bytes
+                    .get(i / 8)
+                    .map(|byte| ((byte >> (i % 8)) & 1) as i32)
+                    .unwrap_or(0)
+            } else {
+                0
+            }
+        };
+
+        let mut carry = 0;
+
+        for (w, digit) in scalar_digits.iter_mut().enumerate() {
+            let window = carry + (0..c).fold(0, |acc, j| acc | (bit(w * c + j) << j));
+            carry = (window + (1 << (c - 1))) >> c;
+            *digit = window - (carry << c);
+        }
+    }
+
+    let mut buckets = vec![G::identity(); 1 << (c - 1)];
+    let mut acc = G::identity();
+
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = G::identity();
+        }
+
+        for (point, scalar_digits) in points.iter().zip(digits.chunks(windows)) {
+            let digit = scalar_digits[w];
+
+            if digit > 0 {
+                buckets[digit as usize - 1] += point;
+            } else if digit < 0 {
+                buckets[(-digit) as usize - 1] -= point;
+            }
+        }
+
+        // Compute `sum(j * buckets[j - 1])` using a running sum
+        let mut running_sum = G::identity();
+        let mut window_sum = G::identity();
+
+        for bucket in buckets.iter().rev() {
+            running_sum += bucket;
+            window_sum += &running_sum;
+        }
+
+        acc += &window_sum;
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PIPPENGER_THRESHOLD;
+    use crate::{
+        dev::{brainpoolp384r1::BrainpoolP384r1, p256::NistP256, secp256k1::Secp256k1},
+        ProjectivePoint, WeierstrassCurve,
+    };
+    use alloc::vec::Vec;
+    use elliptic_curve::{ff::Field, group::Group, Scalar};
+    use rand_core::OsRng;
+
+    fn multiscalar_mul_vartime<C: WeierstrassCurve>() {
+        let pairs = (0..PIPPENGER_THRESHOLD + 3)
+            .map(|_| {
+                (
+                    ProjectivePoint::<C>::random(&mut OsRng),
+                    Scalar::<C>::random(&mut OsRng),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = Vec::with_capacity(pairs.len());
+        let mut acc = ProjectivePoint::<C>::IDENTITY;
+        for (point, scalar) in &pairs {
+            expected.push(acc);
+            acc += point * scalar;
+        }
+        expected.push(acc);
+
+        // Test the shortest inputs and every length around the switch from
+        // Straus to Pippenger
+        for n in (0..=4).chain(PIPPENGER_THRESHOLD - 2..=PIPPENGER_THRESHOLD + 2) {
+            let result = ProjectivePoint::multiscalar_mul_vartime(&pairs[..n]);
+            assert_eq!(result, expected[n], "{} terms", n);
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_vartime_a_zero() {
+        multiscalar_mul_vartime::<Secp256k1>();
+    }
+
+    #[test]
+    fn multiscalar_mul_vartime_minus_three() {
+        multiscalar_mul_vartime::<NistP256>();
+    }
+
+    #[test]
+    fn multiscalar_mul_vartime_generic() {
+        multiscalar_mul_vartime::<BrainpoolP384r1>();
+    }
+}
@@ -2,7 +2,7 @@
 
 #![allow(clippy::needless_range_loop, clippy::op_ref)]
 
-use crate::{AffinePoint, Field, WeierstrassCurve};
+use crate::{AffinePoint, EquationAShape, Field, WeierstrassCurve};
 use core::{
     borrow::Borrow,
     iter::Sum,
@@ -10,6 +10,7 @@ use core::{
 };
 use elliptic_curve::{
     bigint::{ArrayEncoding, Encoding},
+    ff::BatchInverter,
     generic_array::ArrayLength,
     group::{
         self,
@@ -28,9 +29,9 @@ use elliptic_curve::{
 /// Point on a Weierstrass curve in projective coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct ProjectivePoint<C: WeierstrassCurve> {
-    x: C::FieldElement,
-    y: C::FieldElement,
-    z: C::FieldElement,
+    pub(crate) x: C::FieldElement,
+    pub(crate) y: C::FieldElement,
+    pub(crate) z: C::FieldElement,
 }
 
 impl<C> ProjectivePoint<C>
@@ -74,10 +75,129 @@ where
 
     /// Returns `self + other`.
     pub fn add(&self, other: &Self) -> Self {
+        match C::EQUATION_A_SHAPE {
+            EquationAShape::Generic => self.add_generic(other),
+            EquationAShape::MinusThree => self.add_a_minus_three(other),
+            EquationAShape::Zero => self.add_a_zero(other),
+        }
+    }
+
+    /// Returns `self + other` for curves with an arbitrary `a` coefficient.
+    fn add_generic(&self, other: &Self) -> Self {
         // We implement the complete addition formula from Renes-Costello-Batina 2015
-        // (https://eprint.iacr.org/2015/1060 Algorithm 4). The comments after each line
+        // (https://eprint.iacr.org/2015/1060 Algorithm 1) for curves with an arbitrary
+        // `a` coefficient. The comments after each line indicate which algorithm
+        // steps are being performed.
+
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let xx = self.x * &other.x; // 1
+        let yy = self.y * &other.y; // 2
+        let zz = self.z * &other.z; // 3
+        let xy_pairs = ((self.x + &self.y) * &(other.x + &other.y)) - &(xx + &yy); // 4, 5, 6, 7, 8
+        let xz_pairs = ((self.x + &self.z) * &(other.x + &other.z)) - &(xx + &zz); // 9, 10, 11, 12, 13
+        let yz_pairs = ((self.y + &self.z) * &(other.y + &other.z)) - &(yy + &zz); // 14, 15, 16, 17, 18
+
+        let axz_bzz3 = (C::EQUATION_A * &xz_pairs) + &(b3 * &zz); // 19, 20, 21
+        let yy_m_axz_bzz3 = yy - &axz_bzz3; // 22
+        let yy_p_axz_bzz3 = yy + &axz_bzz3; // 23
+
+        let xx3_p_azz = xx.double() + &xx + &(C::EQUATION_A * &zz); // 25, 26, 27, 29
+        let bxz3_p_a = (b3 * &xz_pairs) + &(C::EQUATION_A * &(xx - &(C::EQUATION_A * &zz))); // 28, 30, 31, 32
+
+        Self {
+            x: (xy_pairs * &yy_m_axz_bzz3) - &(yz_pairs * &bxz3_p_a), // 35, 36, 37
+            y: (yy_m_axz_bzz3 * &yy_p_axz_bzz3) + &(xx3_p_azz * &bxz3_p_a), // 24, 33, 34
+            z: (yz_pairs * &yy_p_axz_bzz3) + &(xy_pairs * &xx3_p_azz), // 38, 39, 40
+        }
+    }
+
+    /// Returns `self + other`.
+    fn add_mixed(&self, other: &AffinePoint<C>) -> Self {
+        match C::EQUATION_A_SHAPE {
+            EquationAShape::Generic => self.add_mixed_generic(other),
+            EquationAShape::MinusThree => self.add_mixed_a_minus_three(other),
+            EquationAShape::Zero => self.add_mixed_a_zero(other),
+        }
+    }
+
+    /// Returns `self + other` for curves with an arbitrary `a` coefficient.
+    fn add_mixed_generic(&self, other: &AffinePoint<C>) -> Self {
+        // We implement the complete mixed addition formula from Renes-Costello-Batina
+        // 2015 (Algorithm 2). The comments after each line indicate which algorithm
+        // steps are being performed.
+
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let xx = self.x * &other.x; // 1
+        let yy = self.y * &other.y; // 2
+        let xy_pairs = ((self.x + &self.y) * &(other.x + &other.y)) - &(xx + &yy); // 3, 4, 5, 6, 7
+        let xz_pairs = (other.x * &self.z) + &self.x; // 8, 9 (t4)
+        let yz_pairs = (other.y * &self.z) + &self.y; // 10, 11 (t5)
+
+        let axz_bz3 = (C::EQUATION_A * &xz_pairs) + &(b3 * &self.z); // 12, 13, 14
+        let yy_m_axz_bz3 = yy - &axz_bz3; // 15
+        let yy_p_axz_bz3 = yy + &axz_bz3; // 16
+
+        let az = C::EQUATION_A * &self.z; // 20
+        let xx3_p_az = xx.double() + &xx + &az; // 18, 19, 22
+        let bxz3_p_a = (b3 * &xz_pairs) + &(C::EQUATION_A * &(xx - &az)); // 21, 23, 24, 25
+
+        let mut ret = Self {
+            x: (xy_pairs * &yy_m_axz_bz3) - &(yz_pairs * &bxz3_p_a), // 28, 29, 30
+            y: (yy_m_axz_bz3 * &yy_p_axz_bz3) + &(xx3_p_az * &bxz3_p_a), // 17, 26, 27
+            z: (yz_pairs * &yy_p_axz_bz3) + &(xy_pairs * &xx3_p_az), // 31, 32, 33
+        };
+        ret.conditional_assign(self, other.is_identity());
+        ret
+    }
+
+    /// Doubles this point.
+    pub fn double(&self) -> Self {
+        match C::EQUATION_A_SHAPE {
+            EquationAShape::Generic => self.double_generic(),
+            EquationAShape::MinusThree => self.double_a_minus_three(),
+            EquationAShape::Zero => self.double_a_zero(),
+        }
+    }
+
+    /// Doubles this point for curves with an arbitrary `a` coefficient.
+    fn double_generic(&self) -> Self {
+        // We implement the exception-free point doubling formula from
+        // Renes-Costello-Batina 2015 (Algorithm 3). The comments after each line
         // indicate which algorithm steps are being performed.
 
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let xx = self.x.square(); // 1
+        let yy = self.y.square(); // 2
+        let zz = self.z.square(); // 3
+        let xy2 = (self.x * &self.y).double(); // 4, 5
+        let xz2 = (self.x * &self.z).double(); // 6, 7
+
+        let axz2_bzz3 = (C::EQUATION_A * &xz2) + &(b3 * &zz); // 8, 9, 10
+        let yy_m_axz2_bzz3 = yy - &axz2_bzz3; // 11
+        let y_frag = yy_m_axz2_bzz3 * &(yy + &axz2_bzz3); // 12, 13
+        let x_frag = xy2 * &yy_m_axz2_bzz3; // 14
+
+        let azz = C::EQUATION_A * &zz; // 16
+        let bxz6_p_a = (b3 * &xz2) + &(C::EQUATION_A * &(xx - &azz)); // 15, 17, 18, 19
+        let xx3_p_azz = xx.double() + &xx + &azz; // 20, 21, 22
+
+        let y = y_frag + &(xx3_p_azz * &bxz6_p_a); // 23, 24
+        let yz2 = (self.y * &self.z).double(); // 25, 26
+        let x = x_frag - &(yz2 * &bxz6_p_a); // 27, 28
+        let z = (yz2 * &yy).double().double(); // 29, 30, 31
+
+        Self { x, y, z }
+    }
+
+    /// Returns `self + other` for curves with `a = -3`.
+    fn add_a_minus_three(&self, other: &Self) -> Self {
+        // We implement the complete addition formula from Renes-Costello-Batina 2015
+        // (Algorithm 4). The comments after each line indicate which algorithm steps
+        // are being performed.
+
         let xx = self.x * &other.x; // 1
         let yy = self.y * &other.y; // 2
         let zz = self.z * &other.z; // 3
@@ -102,8 +222,8 @@ where
         }
     }
 
-    /// Returns `self + other`.
-    fn add_mixed(&self, other: &AffinePoint<C>) -> Self {
+    /// Returns `self + other` for curves with `a = -3`.
+    fn add_mixed_a_minus_three(&self, other: &AffinePoint<C>) -> Self {
         // We implement the complete mixed addition formula from Renes-Costello-Batina
         // 2015 (Algorithm 5). The comments after each line indicate which algorithm
         // steps are being performed.
@@ -112,29 +232,29 @@ where
         let yy = self.y * &other.y; // 2
         let xy_pairs = ((self.x + &self.y) * &(other.x + &other.y)) - &(xx + &yy); // 3, 4, 5, 6, 7
         let yz_pairs = (other.y * &self.z) + &self.y; // 8, 9 (t4)
-        let xz_pairs = (other.x * &self.z) + &self.x; // 10, 11 (y3)
+        let xz_pairs = (other.x * &self.z) + &self.x; // 10, 11 (Y3)
 
         let bz_part = xz_pairs - &(C::EQUATION_B * &self.z); // 12, 13
         let bz3_part = bz_part.double() + &bz_part; // 14, 15
-        let yy_m_bzz3 = yy - &bz3_part; // 16
-        let yy_p_bzz3 = yy + &bz3_part; // 17
+        let yy_m_bz3 = yy - &bz3_part; // 16
+        let yy_p_bz3 = yy + &bz3_part; // 17
 
         let z3 = self.z.double() + &self.z; // 19, 20
         let bxz_part = (C::EQUATION_B * &xz_pairs) - &(z3 + &xx); // 18, 21, 22
         let bxz3_part = bxz_part.double() + &bxz_part; // 23, 24
-        let xx3_m_zz3 = xx.double() + &xx - &z3; // 25, 26, 27
+        let xx3_m_z3 = xx.double() + &xx - &z3; // 25, 26, 27
 
         let mut ret = Self {
-            x: (yy_p_bzz3 * &xy_pairs) - &(yz_pairs * &bxz3_part), // 28, 32, 33
-            y: (yy_p_bzz3 * &yy_m_bzz3) + &(xx3_m_zz3 * &bxz3_part), // 29, 30, 31
-            z: (yy_m_bzz3 * &yz_pairs) + &(xy_pairs * &xx3_m_zz3), // 34, 35, 36
+            x: (yy_p_bz3 * &xy_pairs) - &(yz_pairs * &bxz3_part), // 28, 32, 33
+            y: (yy_p_bz3 * &yy_m_bz3) + &(xx3_m_z3 * &bxz3_part), // 29, 30, 31
+            z: (yy_m_bz3 * &yz_pairs) + &(xy_pairs * &xx3_m_z3),  // 34, 35, 36
         };
         ret.conditional_assign(self, other.is_identity());
         ret
     }
 
-    /// Doubles this point.
-    pub fn double(&self) -> Self {
+    /// Doubles this point for curves with `a = -3`.
+    fn double_a_minus_three(&self) -> Self {
         // We implement the exception-free point doubling formula from
         // Renes-Costello-Batina 2015 (Algorithm 6). The comments after each line
         // indicate which algorithm steps are being performed.
@@ -165,6 +285,89 @@ where
         Self { x, y, z }
     }
 
+    /// Returns `self + other` for curves with `a = 0`.
+    fn add_a_zero(&self, other: &Self) -> Self {
+        // We implement the complete addition formula from Renes-Costello-Batina 2015
+        // (Algorithm 7). The comments after each line indicate which algorithm steps
+        // are being performed.
+
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let xx = self.x * &other.x; // 1
+        let yy = self.y * &other.y; // 2
+        let zz = self.z * &other.z; // 3
+        let xy_pairs = ((self.x + &self.y) * &(other.x + &other.y)) - &(xx + &yy); // 4, 5, 6, 7, 8
+        let yz_pairs = ((self.y + &self.z) * &(other.y + &other.z)) - &(yy + &zz); // 9, 10, 11, 12, 13
+        let xz_pairs = ((self.x + &self.z) * &(other.x + &other.z)) - &(xx + &zz); // 14, 15, 16, 17, 18
+
+        let bzz3 = b3 * &zz; // 21
+        let yy_m_bzz3 = yy - &bzz3; // 23
+        let yy_p_bzz3 = yy + &bzz3; // 22
+
+        let xx3 = xx.double() + &xx; // 19, 20
+        let bxz3 = b3 * &xz_pairs; // 24
+
+        Self {
+            x: (xy_pairs * &yy_m_bzz3) - &(yz_pairs * &bxz3), // 25, 26, 27
+            y: (yy_p_bzz3 * &yy_m_bzz3) + &(xx3 * &bxz3),     // 28, 29, 30
+            z: (yz_pairs * &yy_p_bzz3) + &(xy_pairs * &xx3),  // 31, 32, 33
+        }
+    }
+
+    /// Returns `self + other` for curves with `a = 0`.
+    fn add_mixed_a_zero(&self, other: &AffinePoint<C>) -> Self {
+        // We implement the complete mixed addition formula from Renes-Costello-Batina
+        // 2015 (Algorithm 8). The comments after each line indicate which algorithm
+        // steps are being performed.
+
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let xx = self.x * &other.x; // 1
+        let yy = self.y * &other.y; // 2
+        let xy_pairs = ((self.x + &self.y) * &(other.x + &other.y)) - &(xx + &yy); // 3, 4, 5, 6, 7
+        let yz_pairs = (other.y * &self.z) + &self.y; // 8, 9 (t4)
+        let xz_pairs = (other.x * &self.z) + &self.x; // 10, 11 (Y3)
+
+        let bz3 = b3 * &self.z; // 14
+        let yy_m_bz3 = yy - &bz3; // 16
+        let yy_p_bz3 = yy + &bz3; // 15
+
+        let xx3 = xx.double() + &xx; // 12, 13
+        let bxz3 = b3 * &xz_pairs; // 17
+
+        let mut ret = Self {
+            x: (xy_pairs * &yy_m_bz3) - &(yz_pairs * &bxz3), // 18, 19, 20
+            y: (yy_p_bz3 * &yy_m_bz3) + &(xx3 * &bxz3),      // 21, 22, 23
+            z: (yz_pairs * &yy_p_bz3) + &(xy_pairs * &xx3),  // 24, 25, 26
+        };
+        ret.conditional_assign(self, other.is_identity());
+        ret
+    }
+
+    /// Doubles this point for curves with `a = 0`.
+    fn double_a_zero(&self) -> Self {
+        // We implement the exception-free point doubling formula from
+        // Renes-Costello-Batina 2015 (Algorithm 9). The comments after each line
+        // indicate which algorithm steps are being performed.
+
+        let b3 = C::EQUATION_B.double() + &C::EQUATION_B;
+
+        let yy = self.y.square(); // 1
+        let yy8 = yy.double().double().double(); // 2, 3, 4
+        let yz = self.y * &self.z; // 5
+        let bzz3 = b3 * &self.z.square(); // 6, 7
+        let yy_p_bzz3 = yy + &bzz3; // 9
+        let bzz9 = bzz3.double() + &bzz3; // 11, 12
+        let yy_m_bzz9 = yy - &bzz9; // 13
+        let xy2 = (self.x * &self.y).double(); // 16, 18
+
+        Self {
+            x: xy2 * &yy_m_bzz9,                          // 17
+            y: (yy_m_bzz9 * &yy_p_bzz3) + &(bzz3 * &yy8), // 8, 14, 15
+            z: yy8 * &yz,                                 // 10
+        }
+    }
+
     /// Returns `self - other`.
     pub fn sub(&self, other: &Self) -> Self {
         self.add(&other.neg())
@@ -176,7 +379,26 @@ where
     }
 
     /// Returns `[k] self`.
+    ///
+    /// Multiplications of the generator are dispatched to
+    /// [`WeierstrassCurve::mul_by_generator`]. This check is not constant
+    /// time, and only reveals whether `self` is the generator.
     fn mul(&self, k: &Scalar<C>) -> Self {
+        if self.is_generator().into() {
+            C::mul_by_generator(k)
+        } else {
+            self.mul_windowed(k)
+        }
+    }
+
+    /// Returns whether this point is [`ProjectivePoint::GENERATOR`], in any
+    /// projective representation.
+    pub(crate) fn is_generator(&self) -> Choice {
+        self.x.ct_eq(&(C::GENERATOR.0 * self.z)) & self.y.ct_eq(&(C::GENERATOR.1 * self.z))
+    }
+
+    /// Returns `[k] self` using a 4-bit window.
+    pub(crate) fn mul_windowed(&self, k: &Scalar<C>) -> Self {
         let k = Into::<C::UInt>::into(*k).to_le_byte_array();
 
         let mut pc = [Self::default(); 16];
@@ -380,9 +602,45 @@ where
     fn to_affine(&self) -> AffinePoint<C> {
         ProjectivePoint::to_affine(self)
     }
+
+    /// Converts a batch of projective points into affine points using a single
+    /// field inversion (Montgomery's trick).
+    fn batch_normalize(p: &[Self], q: &mut [AffinePoint<C>]) {
+        assert_eq!(p.len(), q.len());
+
+        // Invert the `z`-coordinates in place in `q[i].y`, using `q[i].x` as
+        // scratch space. Identity points have `z = 0` and are left as zero.
+        for (p, q) in p.iter().zip(q.iter_mut()) {
+            q.y = p.z;
+        }
+
+        BatchInverter::invert_with_internal_scratch(q, |q| &mut q.y, |q| &mut q.x);
+
+        for (p, q) in p.iter().zip(q.iter_mut()) {
+            let zinv = q.y;
+            let affine = AffinePoint {
+                x: p.x * &zinv,
+                y: p.y * &zinv,
+                infinity: 0,
+            };
+
+            *q = AffinePoint::conditional_select(&affine, &AffinePoint::IDENTITY, zinv.is_zero());
+        }
+    }
 }
 
-impl<C> LinearCombination for ProjectivePoint<C> where C: WeierstrassCurve {}
+impl<C> LinearCombination for ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Calculates `x * k + y * l` using interleaved wNAF.
+    ///
+    /// This is **not** constant time, and must only be used with public
+    /// scalars, e.g. for signature verification.
+    fn lincomb(x: &Self, k: &Scalar<C>, y: &Self, l: &Scalar<C>) -> Self {
+        crate::wnaf::lincomb(x, k, y, l)
+    }
+}
 
 impl<C> PrimeGroup for ProjectivePoint<C>
 where
@@ -716,3 +974,124 @@ where
         ProjectivePoint::neg(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dev::secp256k1::{AffinePoint, FieldElement, ProjectivePoint, Scalar, Secp256k1},
+        EquationAShape, WeierstrassCurve,
+    };
+    use hex_literal::hex;
+
+    /// Multiples `[1, 2, 3, 4, 5] G` of the secp256k1 generator.
+    const MULTIPLES: [([u8; 32], [u8; 32]); 5] = [
+        (
+            hex!("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+            hex!("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"),
+        ),
+        (
+            hex!("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"),
+            hex!("1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"),
+        ),
+        (
+            hex!("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            hex!("388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672"),
+        ),
+        (
+            hex!("E493DBF1C10D80F3581E4904930B1404CC6C13900EE0758474FA94ABE8C4CD13"),
+            hex!("51ED993EA0D455B75642E2098EA51448D967AE33BFBDFE40CFE97BDC47739922"),
+        ),
+        (
+            hex!("2F8BDE4D1A07209355B4A7250A5C5128E88B84BDDC619AB7CBA8D569B240EFE4"),
+            hex!("D8AC222636E5E3D6D4DBA9DDA6C9C426F788271BAB0D6840DCA87D3AA6AC62D6"),
+        ),
+    ];
+
+    fn multiple(k: usize) -> AffinePoint {
+        let (x, y) = MULTIPLES[k - 1];
+
+        AffinePoint {
+            x: FieldElement::from_be_bytes(x.into()).unwrap(),
+            y: FieldElement::from_be_bytes(y.into()).unwrap(),
+            infinity: 0,
+        }
+    }
+
+    /// Points with a mix of `z = 0`, `z = 1` and other `z` coordinates, and
+    /// pairs of equal and opposite points.
+    fn points() -> [ProjectivePoint; 6] {
+        let g = ProjectivePoint::GENERATOR;
+        [
+            ProjectivePoint::IDENTITY,
+            g,
+            -g,
+            g.double(),
+            g.double() + g,
+            -(g.double() + g),
+        ]
+    }
+
+    #[test]
+    fn a_zero_shape() {
+        assert_eq!(Secp256k1::EQUATION_A_SHAPE, EquationAShape::Zero);
+    }
+
+    #[test]
+    fn a_zero_formulas_match_generic() {
+        for p in points() {
+            assert_eq!(p.double_a_zero(), p.double_generic());
+
+            for q in points() {
+                assert_eq!(p.add_a_zero(&q), p.add_generic(&q));
+
+                let q = q.to_affine();
+                assert_eq!(p.add_mixed_a_zero(&q), p.add_mixed_generic(&q));
+            }
+        }
+    }
+
+    #[test]
+    fn a_zero_add_vs_double() {
+        for p in points() {
+            assert_eq!(p + p, p.double());
+            assert_eq!(p + p.to_affine(), p.double());
+        }
+    }
+
+    #[test]
+    fn a_zero_add_mixed() {
+        for p in points() {
+            for q in points() {
+                assert_eq!(p + q.to_affine(), p + q);
+            }
+        }
+    }
+
+    #[test]
+    fn a_zero_multiples_of_generator() {
+        let g = ProjectivePoint::GENERATOR;
+        let mut p = ProjectivePoint::IDENTITY;
+
+        for k in 1..=MULTIPLES.len() {
+            p += AffinePoint::GENERATOR;
+            assert_eq!(p.to_affine(), multiple(k));
+            assert_eq!((g * Scalar::from(k as u64)).to_affine(), multiple(k));
+        }
+
+        assert_eq!(g.double().to_affine(), multiple(2));
+        assert_eq!(g.double().double().to_affine(), multiple(4));
+        assert_eq!((g.double() + g).to_affine(), multiple(3));
+    }
+
+    #[test]
+    fn is_generator() {
+        let g = ProjectivePoint::GENERATOR;
+        let g_scaled = g.double() - g;
+        assert_ne!(g_scaled.z, Secp256k1::ONE);
+        assert!(bool::from(g_scaled.is_generator()));
+
+        for p in [ProjectivePoint::IDENTITY, -g, g.double()] {
+            assert!(!bool::from(p.is_generator()));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Precomputed tables for fixed-base scalar multiplication.
+
+use crate::{AffinePoint, ProjectivePoint, WeierstrassCurve};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::{boxed::Box, vec::Vec},
+    elliptic_curve::{
+        bigint::{ArrayEncoding, Encoding},
+        Scalar,
+    },
+};
+
+/// Lookup table containing the multiples `[1P, 2P, ..., 8P]` of a point `P`
+/// in affine coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct LookupTable<C: WeierstrassCurve>([AffinePoint<C>; 8]);
+
+impl<C> LookupTable<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Compute the lookup table for the given point.
+    pub fn new(p: &ProjectivePoint<C>) -> Self {
+        let mut points = [*p; 8];
+
+        for j in 1..8 {
+            points[j] = points[j - 1] + p;
+        }
+
+        let mut ret = [AffinePoint::IDENTITY; 8];
+
+        for (affine, projective) in ret.iter_mut().zip(points.iter()) {
+            *affine = projective.to_affine();
+        }
+
+        Self(ret)
+    }
+
+    /// Given `-8 <= x <= 8`, returns `[x]P` in constant time.
+    pub fn select(&self, x: i8) -> AffinePoint<C> {
+        debug_assert!((-8..=8).contains(&x));
+
+        // Compute xabs = |x|
+        let xmask = x >> 7;
+        let xabs = (x + xmask) ^ xmask;
+
+        // Get an array element in constant time
+        let mut t = AffinePoint::IDENTITY;
+
+        for (j, point) in (1u8..).zip(self.0.iter()) {
+            let c = (xabs as u8).ct_eq(&j);
+            t.conditional_assign(point, c);
+        }
+
+        // Now t == |x| * p.
+        let neg_mask = Choice::from((xmask & 1) as u8);
+        t.conditional_assign(&-t, neg_mask);
+
+        // Now t == x * p.
+        t
+    }
+}
+
+impl<C> Default for LookupTable<C>
+where
+    C: WeierstrassCurve,
+{
+    fn default() -> Self {
+        Self([AffinePoint::IDENTITY; 8])
+    }
+}
+
+/// Precomputed multiples of the generator for fixed-base scalar
+/// multiplication.
+///
+/// Table `i` contains `[1, 8] * 16^i * G`, so a scalar decomposed into `N`
+/// signed radix-16 digits can be multiplied by the generator using `N` mixed
+/// additions and no doublings. `N` must be twice the byte size of the curve's
+/// `UInt` plus one to hold the final carry, e.g. 65 for a 256-bit curve.
+///
+/// It also holds the odd multiples `[1, 3, 5, ..., 127] G` used by the
+/// variable-time wNAF implementation of [`LinearCombination`] with a window
+/// size of 8.
+///
+/// The multiples are stored on the heap and computed there one lookup table
+/// at a time, as the whole table is too large for the stack of many embedded
+/// targets (about 40 KiB for a 256-bit curve).
+///
+/// [`LinearCombination`]: elliptic_curve::ops::LinearCombination
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct BasepointTable<C: WeierstrassCurve, const N: usize> {
+    tables: Box<[LookupTable<C>; N]>,
+    odd_multiples: Box<[AffinePoint<C>; 64]>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C, const N: usize> BasepointTable<C, N>
+where
+    C: WeierstrassCurve,
+{
+    /// Compute the basepoint table for the curve's generator.
+    pub fn new() -> Self {
+        assert_eq!(
+            N,
+            C::UInt::BYTE_SIZE * 2 + 1,
+            "invalid basepoint table size"
+        );
+
+        let mut tables = Vec::with_capacity(N);
+        let mut base = ProjectivePoint::<C>::GENERATOR;
+
+        for i in 0..N {
+            tables.push(LookupTable::new(&base));
+
+            if i + 1 < N {
+                base = base.double().double().double().double();
+            }
+        }
+
+        let mut odd_multiples = Vec::with_capacity(64);
+        let generator2 = ProjectivePoint::<C>::GENERATOR.double();
+        let mut point = ProjectivePoint::<C>::GENERATOR;
+
+        for _ in 0..64 {
+            odd_multiples.push(point.to_affine());
+            point += &generator2;
+        }
+
+        Self {
+            tables: tables.into_boxed_slice().try_into().unwrap(),
+            odd_multiples: odd_multiples.into_boxed_slice().try_into().unwrap(),
+        }
+    }
+
+    /// Returns the odd multiples `[1, 3, 5, ..., 127] G` of the generator.
+    pub fn odd_multiples(&self) -> &[AffinePoint<C>] {
+        &self.odd_multiples[..]
+    }
+
+    /// Returns `[k] G` where `G` is the curve's generator.
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        let k = Into::<C::UInt>::into(*k).to_le_byte_array();
+        let mut digits = [0i8; N];
+
+        // Decompose `k` into unsigned radix-16 digits...
+        for (i, byte) in k.iter().enumerate() {
+            digits[2 * i] = (byte & 0xf) as i8;
+            digits[2 * i + 1] = ((byte >> 4) & 0xf) as i8;
+        }
+
+        // ...then recenter them into the range `[-8, 8)`
+        for i in 0..(N - 1) {
+            let carry = (digits[i] + 8) >> 4;
+            digits[i] -= carry << 4;
+            digits[i + 1] += carry;
+        }
+
+        let mut acc = ProjectivePoint::IDENTITY;
+
+        for (table, digit) in self.tables.iter().zip(digits.iter()) {
+            acc += table.select(*digit);
+        }
+
+        acc
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, const N: usize> Default for BasepointTable<C, N>
+where
+    C: WeierstrassCurve,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
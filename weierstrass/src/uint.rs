@@ -0,0 +1,513 @@
+//! Macro for defining unsigned integers narrower than their limbs.
+
+/// Define an unsigned integer type which is backed by a wider
+/// [`crypto-bigint`] integer, but whose serialized form is only `size` bytes.
+///
+/// `elliptic-curve` sizes [`FieldBytes`] and SEC1 [`EncodedPoint`]s by
+/// [`Curve::UInt`], so curves whose modulus is not a whole number of limbs,
+/// e.g. P-224 on 64-bit platforms, need such a type as their `uint` in
+/// [`define_curve!`].
+///
+/// Values are always less than `2^(8 * size)`. The generated type provides
+/// the traits required by [`Curve::UInt`] along with the `const fn`s used by
+/// [`define_curve!`], which discard any bits above the `8 * size`th.
+///
+/// # Example
+///
+/// ```
+/// use weierstrass::elliptic_curve::{bigint::{Encoding, U256}, consts::U28};
+///
+/// weierstrass::define_uint! {
+///     /// 224-bit unsigned integer.
+///     pub struct U224(U256, U28);
+/// }
+///
+/// let n = U224::from_be_hex("ffffffffffffffffffffffffffffffff000000000000000000000001");
+/// assert_eq!(U224::BYTE_SIZE, 28);
+/// assert_eq!(n.to_be_bytes()[27], 1);
+/// assert_eq!(n.wrapping_add(&U224::MAX), n.wrapping_sub(&U224::ONE));
+/// ```
+///
+/// [`crypto-bigint`]: elliptic_curve::bigint
+/// [`FieldBytes`]: elliptic_curve::FieldBytes
+/// [`EncodedPoint`]: elliptic_curve::sec1::EncodedPoint
+/// [`Curve::UInt`]: elliptic_curve::Curve::UInt
+#[macro_export]
+macro_rules! define_uint {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($inner:ty, $size:ty);
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        $vis struct $name($inner);
+
+        impl $name {
+            /// The value `0`.
+            pub const ZERO: Self = Self(<$inner>::ZERO);
+
+            /// The value `1`.
+            pub const ONE: Self = Self(<$inner>::ONE);
+
+            /// Maximum value this integer can express.
+            pub const MAX: Self = Self(<$inner>::MAX.shr_vartime(
+                <$inner as $crate::elliptic_curve::bigint::Encoding>::BIT_SIZE - Self::BIT_SIZE,
+            ));
+
+            /// Size of this integer in bits.
+            pub const BIT_SIZE: usize = Self::BYTE_SIZE * 8;
+
+            /// Size of this integer in bytes.
+            pub const BYTE_SIZE: usize =
+                <$size as $crate::elliptic_curve::generic_array::typenum::Unsigned>::USIZE;
+
+            /// Number of limbs in the inner integer.
+            pub const LIMBS: usize = <$inner>::LIMBS;
+
+            /// Create a new integer from the provided inner integer, returning
+            /// `None` if it exceeds [`Self::MAX`].
+            pub fn new(uint: $inner) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                use $crate::elliptic_curve::subtle::ConstantTimeGreater as _;
+                let is_too_large = uint.ct_gt(&Self::MAX.0);
+                $crate::elliptic_curve::subtle::CtOption::new(Self(uint), !is_too_large)
+            }
+
+            /// Create a new integer from a `u8`.
+            pub const fn from_u8(n: u8) -> Self {
+                Self(<$inner>::from_u8(n))
+            }
+
+            /// Create a new integer from a `u64`.
+            pub const fn from_u64(n: u64) -> Self {
+                Self(<$inner>::from_u64(n))
+            }
+
+            /// Create a new integer from an array of limb-sized words,
+            /// discarding any bits above [`Self::BIT_SIZE`].
+            pub const fn from_words(
+                words: [$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS],
+            ) -> Self {
+                Self(<$inner>::from_words(words).wrapping_and(&Self::MAX.0))
+            }
+
+            /// Borrow the inner limbs as an array of words.
+            pub const fn as_words(&self) -> &[$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS] {
+                self.0.as_words()
+            }
+
+            /// Create an array of words from this integer.
+            pub const fn to_words(self) -> [$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS] {
+                self.0.to_words()
+            }
+
+            /// Convert this integer into the inner integer.
+            pub const fn to_uint(self) -> $inner {
+                self.0
+            }
+
+            /// Create a new integer from the provided big endian hex string.
+            ///
+            /// Panics if the string is not exactly `2 * BYTE_SIZE` hex digits.
+            pub const fn from_be_hex(hex: &str) -> Self {
+                let bytes = hex.as_bytes();
+                assert!(
+                    bytes.len() == Self::BYTE_SIZE * 2,
+                    "hex string is not the expected size"
+                );
+
+                let mut words = [0; <$inner>::LIMBS];
+                let mut i = 0;
+
+                while i < bytes.len() {
+                    let digit = $crate::uint::decode_hex_digit(bytes[bytes.len() - i - 1]);
+                    words[i / $crate::uint::LIMB_HEX_DIGITS] |= (digit as $crate::elliptic_curve::bigint::Word)
+                        << ((i % $crate::uint::LIMB_HEX_DIGITS) * 4);
+                    i += 1;
+                }
+
+                Self(<$inner>::from_words(words))
+            }
+
+            /// Create a new integer from the provided little endian hex string.
+            ///
+            /// Panics if the string is not exactly `2 * BYTE_SIZE` hex digits.
+            pub const fn from_le_hex(hex: &str) -> Self {
+                let bytes = hex.as_bytes();
+                assert!(
+                    bytes.len() == Self::BYTE_SIZE * 2,
+                    "hex string is not the expected size"
+                );
+
+                let mut words = [0; <$inner>::LIMBS];
+                let mut i = 0;
+
+                while i < Self::BYTE_SIZE {
+                    let byte = ($crate::uint::decode_hex_digit(bytes[2 * i]) << 4)
+                        | $crate::uint::decode_hex_digit(bytes[2 * i + 1]);
+                    words[i / $crate::elliptic_curve::bigint::Limb::BYTE_SIZE] |= (byte as $crate::elliptic_curve::bigint::Word)
+                        << ((i % $crate::elliptic_curve::bigint::Limb::BYTE_SIZE) * 8);
+                    i += 1;
+                }
+
+                Self(<$inner>::from_words(words))
+            }
+
+            /// Serialize this integer as little endian bytes.
+            pub const fn to_le_bytes(self) -> [u8; $name::BYTE_SIZE] {
+                let words = self.0.as_words();
+                let mut bytes = [0u8; $name::BYTE_SIZE];
+                let mut i = 0;
+
+                while i < Self::BYTE_SIZE {
+                    let word = words[i / $crate::elliptic_curve::bigint::Limb::BYTE_SIZE];
+                    bytes[i] = (word >> ((i % $crate::elliptic_curve::bigint::Limb::BYTE_SIZE) * 8)) as u8;
+                    i += 1;
+                }
+
+                bytes
+            }
+
+            /// Serialize this integer as big endian bytes.
+            pub const fn to_be_bytes(self) -> [u8; $name::BYTE_SIZE] {
+                let le_bytes = self.to_le_bytes();
+                let mut bytes = [0u8; $name::BYTE_SIZE];
+                let mut i = 0;
+
+                while i < Self::BYTE_SIZE {
+                    bytes[i] = le_bytes[Self::BYTE_SIZE - i - 1];
+                    i += 1;
+                }
+
+                bytes
+            }
+
+            /// Decode an integer from little endian bytes.
+            pub const fn from_le_bytes(bytes: [u8; $name::BYTE_SIZE]) -> Self {
+                let mut words = [0; <$inner>::LIMBS];
+                let mut i = 0;
+
+                while i < Self::BYTE_SIZE {
+                    words[i / $crate::elliptic_curve::bigint::Limb::BYTE_SIZE] |= (bytes[i] as $crate::elliptic_curve::bigint::Word)
+                        << ((i % $crate::elliptic_curve::bigint::Limb::BYTE_SIZE) * 8);
+                    i += 1;
+                }
+
+                Self(<$inner>::from_words(words))
+            }
+
+            /// Decode an integer from big endian bytes.
+            pub const fn from_be_bytes(bytes: [u8; $name::BYTE_SIZE]) -> Self {
+                let mut le_bytes = [0u8; $name::BYTE_SIZE];
+                let mut i = 0;
+
+                while i < Self::BYTE_SIZE {
+                    le_bytes[i] = bytes[Self::BYTE_SIZE - i - 1];
+                    i += 1;
+                }
+
+                Self::from_le_bytes(le_bytes)
+            }
+
+            /// Computes `self + rhs mod 2^BIT_SIZE`.
+            pub const fn wrapping_add(&self, rhs: &Self) -> Self {
+                Self(self.0.wrapping_add(&rhs.0).wrapping_and(&Self::MAX.0))
+            }
+
+            /// Computes `self - rhs mod 2^BIT_SIZE`.
+            pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
+                Self(self.0.wrapping_sub(&rhs.0).wrapping_and(&Self::MAX.0))
+            }
+
+            /// Computes `self >> shift`.
+            ///
+            /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+            pub const fn shr_vartime(&self, shift: usize) -> Self {
+                Self(self.0.shr_vartime(shift))
+            }
+
+            /// Calculate the number of bits needed to represent this number.
+            pub const fn bits_vartime(self) -> usize {
+                self.0.bits_vartime()
+            }
+        }
+
+        impl AsRef<[$crate::elliptic_curve::bigint::Limb]> for $name {
+            fn as_ref(&self) -> &[$crate::elliptic_curve::bigint::Limb] {
+                self.0.as_ref()
+            }
+        }
+
+        impl AsRef<[$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS]> for $name {
+            fn as_ref(&self) -> &[$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS] {
+                self.as_words()
+            }
+        }
+
+        impl From<[$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS]> for $name {
+            fn from(words: [$crate::elliptic_curve::bigint::Word; <$inner>::LIMBS]) -> Self {
+                Self::from_words(words)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(n: u64) -> Self {
+                Self::from_u64(n)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(n: $name) -> $inner {
+                n.0
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0.bitand(&rhs.0))
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0.bitor(&rhs.0))
+            }
+        }
+
+        impl core::ops::BitXor for $name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(self.0.bitxor(&rhs.0))
+            }
+        }
+
+        impl core::ops::Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self(self.0.not().bitand(&Self::MAX.0))
+            }
+        }
+
+        impl core::ops::Shl<usize> for $name {
+            type Output = Self;
+
+            fn shl(self, rhs: usize) -> Self {
+                Self((self.0 << rhs).bitand(&Self::MAX.0))
+            }
+        }
+
+        impl core::ops::Shr<usize> for $name {
+            type Output = Self;
+
+            fn shr(self, rhs: usize) -> Self {
+                Self(self.0 >> rhs)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::CheckedAdd<&$name> for $name {
+            type Output = Self;
+
+            fn checked_add(&self, rhs: &Self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                self.0.checked_add(&rhs.0).and_then(Self::new)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::CheckedSub<&$name> for $name {
+            type Output = Self;
+
+            fn checked_sub(&self, rhs: &Self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                self.0.checked_sub(&rhs.0).map(Self)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::CheckedMul<&$name> for $name {
+            type Output = Self;
+
+            fn checked_mul(&self, rhs: &Self) -> $crate::elliptic_curve::subtle::CtOption<Self> {
+                self.0.checked_mul(&rhs.0).and_then(Self::new)
+            }
+        }
+
+        impl core::ops::Div<$crate::elliptic_curve::bigint::NonZero<$name>> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: $crate::elliptic_curve::bigint::NonZero<$name>) -> Self {
+                let rhs: &Self = &rhs;
+                Self(self.0.wrapping_div(&rhs.0))
+            }
+        }
+
+        impl core::ops::Rem<$crate::elliptic_curve::bigint::NonZero<$name>> for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: $crate::elliptic_curve::bigint::NonZero<$name>) -> Self {
+                let rhs: &Self = &rhs;
+                Self(self.0.wrapping_rem(&rhs.0))
+            }
+        }
+
+        impl $crate::elliptic_curve::subtle::ConditionallySelectable for $name {
+            fn conditional_select(
+                a: &Self,
+                b: &Self,
+                choice: $crate::elliptic_curve::subtle::Choice,
+            ) -> Self {
+                Self(<$inner>::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        impl $crate::elliptic_curve::subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> $crate::elliptic_curve::subtle::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl $crate::elliptic_curve::subtle::ConstantTimeGreater for $name {
+            fn ct_gt(&self, other: &Self) -> $crate::elliptic_curve::subtle::Choice {
+                self.0.ct_gt(&other.0)
+            }
+        }
+
+        impl $crate::elliptic_curve::subtle::ConstantTimeLess for $name {
+            fn ct_lt(&self, other: &Self) -> $crate::elliptic_curve::subtle::Choice {
+                self.0.ct_lt(&other.0)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::Zero for $name {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl $crate::elliptic_curve::bigint::Integer for $name {
+            const ONE: Self = Self::ONE;
+            const MAX: Self = Self::MAX;
+
+            fn is_odd(&self) -> $crate::elliptic_curve::subtle::Choice {
+                self.0.is_odd()
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::Encoding for $name {
+            const BIT_SIZE: usize = Self::BIT_SIZE;
+            const BYTE_SIZE: usize = Self::BYTE_SIZE;
+
+            type Repr = [u8; $name::BYTE_SIZE];
+
+            fn from_be_bytes(bytes: Self::Repr) -> Self {
+                $name::from_be_bytes(bytes)
+            }
+
+            fn from_le_bytes(bytes: Self::Repr) -> Self {
+                $name::from_le_bytes(bytes)
+            }
+
+            fn to_be_bytes(&self) -> Self::Repr {
+                $name::to_be_bytes(*self)
+            }
+
+            fn to_le_bytes(&self) -> Self::Repr {
+                $name::to_le_bytes(*self)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::ArrayEncoding for $name {
+            type ByteSize = $size;
+
+            fn from_be_byte_array(bytes: $crate::elliptic_curve::bigint::ByteArray<Self>) -> Self {
+                let mut repr = [0u8; $name::BYTE_SIZE];
+                repr.copy_from_slice(&bytes);
+                $name::from_be_bytes(repr)
+            }
+
+            fn from_le_byte_array(bytes: $crate::elliptic_curve::bigint::ByteArray<Self>) -> Self {
+                let mut repr = [0u8; $name::BYTE_SIZE];
+                repr.copy_from_slice(&bytes);
+                $name::from_le_bytes(repr)
+            }
+
+            fn to_be_byte_array(&self) -> $crate::elliptic_curve::bigint::ByteArray<Self> {
+                $crate::elliptic_curve::bigint::ByteArray::<Self>::clone_from_slice(
+                    &$name::to_be_bytes(*self),
+                )
+            }
+
+            fn to_le_byte_array(&self) -> $crate::elliptic_curve::bigint::ByteArray<Self> {
+                $crate::elliptic_curve::bigint::ByteArray::<Self>::clone_from_slice(
+                    &$name::to_le_bytes(*self),
+                )
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::AddMod for $name {
+            type Output = Self;
+
+            fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+                Self(self.0.add_mod(&rhs.0, &p.0))
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::SubMod for $name {
+            type Output = Self;
+
+            fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+                Self(self.0.sub_mod(&rhs.0, &p.0))
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::NegMod for $name {
+            type Output = Self;
+
+            fn neg_mod(&self, p: &Self) -> Self {
+                Self(self.0.neg_mod(&p.0))
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::Random for $name {
+            fn random(
+                mut rng: impl $crate::elliptic_curve::rand_core::CryptoRng
+                    + $crate::elliptic_curve::rand_core::RngCore,
+            ) -> Self {
+                let mut bytes = [0u8; $name::BYTE_SIZE];
+                rng.fill_bytes(&mut bytes);
+                $name::from_le_bytes(bytes)
+            }
+        }
+
+        impl $crate::elliptic_curve::bigint::RandomMod for $name {
+            fn random_mod(
+                rng: impl $crate::elliptic_curve::rand_core::CryptoRng
+                    + $crate::elliptic_curve::rand_core::RngCore,
+                modulus: &$crate::elliptic_curve::bigint::NonZero<Self>,
+            ) -> Self {
+                let modulus = $crate::elliptic_curve::bigint::NonZero::new(modulus.0).unwrap();
+                Self(<$inner>::random_mod(rng, &modulus))
+            }
+        }
+
+        impl $crate::elliptic_curve::zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+    };
+}
+
+/// Number of hex digits which fit in a single limb.
+#[doc(hidden)]
+pub const LIMB_HEX_DIGITS: usize = elliptic_curve::bigint::Limb::BIT_SIZE / 4;
+
+/// Decode a single hex digit in a `const fn`.
+#[doc(hidden)]
+pub const fn decode_hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex digit"),
+    }
+}
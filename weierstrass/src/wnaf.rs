@@ -0,0 +1,168 @@
+//! Variable-time multi-scalar multiplication using interleaved wNAF (Straus).
+
+use crate::{AffinePoint, ProjectivePoint, WeierstrassCurve};
+use elliptic_curve::{bigint::ArrayEncoding, ff::PrimeField, Scalar};
+
+/// wNAF window size used for points without precomputed tables.
+const WINDOW: usize = 5;
+
+/// Maximum supported wNAF window size, limited by the `i8` digits.
+pub(crate) const MAX_WINDOW: usize = 8;
+
+/// Maximum number of wNAF digits, large enough for 521-bit scalars with an
+/// 8-bit window.
+pub(crate) const MAX_DIGITS: usize = 521 + MAX_WINDOW;
+
+const _: () = assert!(WINDOW >= 2 && WINDOW <= MAX_WINDOW);
+
+/// Width-`w` non-adjacent form of a scalar.
+pub(crate) struct Wnaf {
+    digits: [i8; MAX_DIGITS],
+    len: usize,
+}
+
+impl Wnaf {
+    /// Compute the width-`w` NAF of `k`.
+    ///
+    /// Every nonzero digit is odd and lies in `(-2^(w-1), 2^(w-1))`, and any `w`
+    /// consecutive digits contain at most one nonzero digit.
+    pub(crate) fn new<C: WeierstrassCurve>(k: &Scalar<C>, w: usize) -> Self {
+        debug_assert!((2..=MAX_WINDOW).contains(&w));
+        debug_assert!(Scalar::<C>::NUM_BITS as usize + w <= MAX_DIGITS);
+
+        let bytes = Into::<C::UInt>::into(*k).to_le_byte_array();
+        let bit = |i: usize| -> u32 {
+            bytes
+                .get(i / 8)
+                .map(|byte| ((byte >> (i % 8)) & 1) as u32)
+                .unwrap_or(0)
+        };
+
+        let width = 1u32 << w;
+        let mut digits = [0i8; MAX_DIGITS];
+        let mut len = 0;
+        let mut carry = 0;
+        let mut pos = 0;
+
+        while pos < Scalar::<C>::NUM_BITS as usize || carry != 0 {
+            let window = carry + (0..w).fold(0, |acc, j| acc | (bit(pos + j) << j));
+
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                digits[pos] = window as i8;
+            } else {
+                carry = 1;
+                digits[pos] = (window as i32 - width as i32) as i8;
+            }
+
+            len = pos + 1;
+            pos += w;
+        }
+
+        Self { digits, len }
+    }
+}
+
+/// Odd multiples `[1, 3, 5, ..., 2^(w-1) - 1] P` of a point.
+pub(crate) enum OddMultiples<'a, C: WeierstrassCurve> {
+    /// Precomputed table in affine coordinates.
+    Affine(&'a [AffinePoint<C>]),
+
+    /// Table computed on the fly in projective coordinates.
+    Projective([ProjectivePoint<C>; 1 << (WINDOW - 2)]),
+}
+
+impl<'a, C> OddMultiples<'a, C>
+where
+    C: WeierstrassCurve,
+{
+    /// Compute the odd multiples of `p`, using the curve's precomputed table if
+    /// `p` is the generator.
+    pub(crate) fn new(p: &ProjectivePoint<C>) -> Self {
+        if p.is_generator().into() {
+            if let Some(table) = C::generator_odd_multiples() {
+                assert!(table.len().is_power_of_two());
+                assert!(table.len() <= 1 << (MAX_WINDOW - 2));
+                return OddMultiples::Affine(table);
+            }
+        }
+
+        let p2 = p.double();
+        let mut table = [*p; 1 << (WINDOW - 2)];
+
+        for i in 1..table.len() {
+            table[i] = table[i - 1] + p2;
+        }
+
+        OddMultiples::Projective(table)
+    }
+
+    /// wNAF window size for this table.
+    pub(crate) fn window(&self) -> usize {
+        match self {
+            OddMultiples::Affine(table) => table.len().trailing_zeros() as usize + 2,
+            OddMultiples::Projective(_) => WINDOW,
+        }
+    }
+
+    /// Add `[digit] P` to `acc`.
+    fn add_to(&self, acc: &mut ProjectivePoint<C>, digit: i8) {
+        let index = (digit.unsigned_abs() / 2) as usize;
+
+        match (self, digit > 0) {
+            (OddMultiples::Affine(table), true) => *acc += &table[index],
+            (OddMultiples::Affine(table), false) => *acc -= &table[index],
+            (OddMultiples::Projective(table), true) => *acc += &table[index],
+            (OddMultiples::Projective(table), false) => *acc -= &table[index],
+        }
+    }
+}
+
+/// Compute `x * k + y * l` in variable time.
+pub(crate) fn lincomb<C>(
+    x: &ProjectivePoint<C>,
+    k: &Scalar<C>,
+    y: &ProjectivePoint<C>,
+    l: &Scalar<C>,
+) -> ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    if Scalar::<C>::NUM_BITS as usize + MAX_WINDOW > MAX_DIGITS {
+        return x.mul_windowed(k) + y.mul_windowed(l);
+    }
+
+    let x_table = OddMultiples::new(x);
+    let y_table = OddMultiples::new(y);
+    let k_naf = Wnaf::new::<C>(k, x_table.window());
+    let l_naf = Wnaf::new::<C>(l, y_table.window());
+
+    straus(&[x_table, y_table], &[k_naf, l_naf])
+}
+
+/// Compute `sum(tables[i] * nafs[i])`, sharing the doublings between all
+/// terms.
+pub(crate) fn straus<C>(tables: &[OddMultiples<'_, C>], nafs: &[Wnaf]) -> ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    let len = nafs.iter().map(|naf| naf.len).max().unwrap_or(0);
+    let mut acc = ProjectivePoint::IDENTITY;
+
+    for i in (0..len).rev() {
+        acc = acc.double();
+
+        for (table, naf) in tables.iter().zip(nafs.iter()) {
+            if naf.digits[i] != 0 {
+                table.add_to(&mut acc, naf.digits[i]);
+            }
+        }
+    }
+
+    acc
+}